@@ -52,6 +52,12 @@ build_and_test_nonwasm() {
   TZ=UTC0 channel test -v --features serde --lib
   channel build -v --features serde,rustc-serialize
   TZ=Asia/Katmandu channel test -v --features serde,rustc-serialize
+  channel build -v --features relative
+  TZ=ACST-9:30 channel test -v --features relative --lib
+  channel build -v --features cron
+  TZ=EST4 channel test -v --features cron --lib
+  channel build -v --features serde-lenient
+  TZ=UTC0 channel test -v --features serde-lenient --lib
 
   # without default "clock" feature
   channel build -v --no-default-features
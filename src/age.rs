@@ -0,0 +1,32 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! The age as calendar years, months and days.
+
+/// The time elapsed between two dates (e.g. someone's age),
+/// as calendar years, months and days.
+///
+/// This is returned by [`NaiveDate::age_at`](./naive/struct.NaiveDate.html#method.age_at)
+/// and [`Date::age_at`](./struct.Date.html#method.age_at).
+/// The whole years and months are counted first and the remaining days last,
+/// so for the `Age` between dates `from` and `to` the following always holds:
+///
+/// ~~~~
+/// # use chrono::{Days, Months, NaiveDate};
+/// # let (from, to) = (NaiveDate::from_ymd(2000, 1, 31), NaiveDate::from_ymd(2019, 12, 25));
+/// let age = from.age_at(to).unwrap();
+/// assert_eq!(from + Months(age.years * 12 + age.months) + Days(age.days as u64), to);
+/// ~~~~
+///
+/// Same to [`Months`](./struct.Months.html), a month anniversary falling on a day
+/// missing from the month (e.g. February 30) is clamped to the last day of the month.
+/// In particular, the anniversary of February 29 is February 28 in common years.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Age {
+    /// The number of whole years.
+    pub years: u32,
+    /// The number of whole months after the whole years, from 0 to 11.
+    pub months: u32,
+    /// The number of days after the whole months, from 0 to 30.
+    pub days: u32,
+}
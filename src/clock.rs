@@ -0,0 +1,69 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! The source of the current time.
+
+use offset::Utc;
+use DateTime;
+
+/// A source of the current instant.
+///
+/// Code taking a `Clock` instead of calling [`Utc::now`](./offset/struct.Utc.html#method.now)
+/// directly can be tested with a [`FixedClock`](./struct.FixedClock.html)
+/// or any other implementation, while [`SystemClock`](./struct.SystemClock.html)
+/// is used otherwise.
+/// [`TimeZone::now_with_clock`](./offset/trait.TimeZone.html#method.now_with_clock)
+/// gets the current time in any time zone from a `Clock`.
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::{Clock, FixedClock, TimeZone, Utc};
+///
+/// fn greeting<C: Clock>(clock: &C) -> &'static str {
+///     if clock.now().date() == Utc.ymd(2019, 12, 25) {
+///         "Merry Christmas!"
+///     } else {
+///         "Hello!"
+///     }
+/// }
+///
+/// let clock = FixedClock(Utc.ymd(2019, 12, 25).and_hms(9, 0, 0));
+/// assert_eq!(greeting(&clock), "Merry Christmas!");
+/// ~~~~
+pub trait Clock {
+    /// Returns the current instant.
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// The clock reading the system time, same to
+/// [`Utc::now`](./offset/struct.Utc.html#method.now).
+#[cfg(feature = "clock")]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct SystemClock;
+
+#[cfg(feature = "clock")]
+impl Clock for SystemClock {
+    #[inline]
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// The clock always returning the given instant, mainly for tests.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct FixedClock(pub DateTime<Utc>);
+
+impl Clock for FixedClock {
+    #[inline]
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
+
+impl<'a, C: Clock + ?Sized> Clock for &'a C {
+    #[inline]
+    fn now(&self) -> DateTime<Utc> {
+        (**self).now()
+    }
+}
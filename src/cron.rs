@@ -0,0 +1,540 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Evaluation of five-field cron expressions.
+//!
+//! This module is only available with the `cron` feature.
+//!
+//! ## Syntax
+//!
+//! A [`Cron`](./struct.Cron.html) expression consists of five whitespace-separated fields:
+//!
+//! | Field        | Values   | Names           |
+//! |--------------|----------|-----------------|
+//! | minute       | 0--59    |                 |
+//! | hour         | 0--23    |                 |
+//! | day of month | 1--31    |                 |
+//! | month        | 1--12    | `JAN` to `DEC`  |
+//! | day of week  | 0--7     | `SUN` to `SAT`  |
+//!
+//! Both 0 and 7 represent Sunday in the day of week field, and names are case-insensitive.
+//! Each field is a comma-separated list of the following items:
+//!
+//! * `*` for every value of the field,
+//! * a single value like `5`,
+//! * an inclusive range like `1-5` or `MON-FRI`,
+//! * any of the above followed by a step like `*/15` or `0-30/10`.
+//!   A single value with a step (`5/15`) means the range from the value to the maximum.
+//!
+//! As in the traditional cron, when both the day of month and the day of week are restricted
+//! (i.e. neither field starts with `*`), a day matches if *either* of them matches.
+//!
+//! ## Time Zones
+//!
+//! Expressions are matched against the local date and time of the given time zone.
+//! When the local time does not exist (e.g. in the gap of the daylight saving time transition),
+//! that occurrence is skipped.
+//! When the local time happens twice (e.g. in the overlap of the transition),
+//! it fires only once at the earliest instant.
+
+use std::error::Error;
+use std::{fmt, str};
+
+use naive::{NaiveDate, NaiveDateTime};
+use offset::{LocalResult, TimeZone};
+use oldtime::Duration as OldDuration;
+use {DateTime, Datelike, Timelike};
+
+/// The number of days in 400 years, after which the Gregorian calendar repeats itself
+/// (including the days of week). Used to bound the search for expressions that never match.
+const DAYS_IN_400_YEARS: u32 = 146_097;
+
+static MONTH_NAMES: [&'static str; 12] = [
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+static WEEKDAY_NAMES: [&'static str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+/// A five-field cron expression.
+///
+/// See the [module documentation](./index.html) for the syntax.
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::{Utc, TimeZone};
+/// use chrono::cron::Cron;
+///
+/// let cron: Cron = "*/15 9-17 * * MON-FRI".parse().unwrap();
+///
+/// // Friday afternoon
+/// let dt = Utc.ymd(2019, 10, 18).and_hms(17, 50, 0);
+/// assert_eq!(cron.next_after(&dt), Some(Utc.ymd(2019, 10, 21).and_hms(9, 0, 0)));
+/// assert_eq!(cron.prev_before(&dt), Some(Utc.ymd(2019, 10, 18).and_hms(17, 45, 0)));
+/// ~~~~
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct Cron {
+    /// Bit `i` is set when the minute `i` matches.
+    minutes: u64,
+    /// Bit `i` is set when the hour `i` matches.
+    hours: u32,
+    /// Bit `i` is set when the day of month `i` matches.
+    days: u32,
+    /// Bit `i` is set when the month `i` matches.
+    months: u16,
+    /// Bit `i` is set when the `i`-th day of week since Sunday matches.
+    weekdays: u8,
+    /// True when the day of month field starts with `*`.
+    days_star: bool,
+    /// True when the day of week field starts with `*`.
+    weekdays_star: bool,
+}
+
+impl Cron {
+    /// Returns the earliest occurrence strictly after given date and time,
+    /// or `None` if the expression never matches again.
+    ///
+    /// Occurrences in the non-existent local times are skipped,
+    /// and those in the ambiguous local times are only returned once at the earliest instant.
+    pub fn next_after<Tz: TimeZone>(&self, dt: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        let tz = dt.timezone();
+        let mut from = try_opt!(
+            truncate_to_minute(dt.naive_local()).checked_add_signed(OldDuration::minutes(1))
+        );
+        loop {
+            let local = try_opt!(self.next_local_from(from));
+            match tz.from_local_datetime(&local) {
+                LocalResult::Single(t) | LocalResult::Ambiguous(t, _) if t > *dt => return Some(t),
+                _ => {}
+            }
+            from = try_opt!(local.checked_add_signed(OldDuration::minutes(1)));
+        }
+    }
+
+    /// Returns the latest occurrence strictly before given date and time,
+    /// or `None` if the expression never matched before.
+    ///
+    /// Occurrences in the non-existent local times are skipped,
+    /// and those in the ambiguous local times are only returned once at the earliest instant.
+    pub fn prev_before<Tz: TimeZone>(&self, dt: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        let tz = dt.timezone();
+        let mut from = truncate_to_minute(dt.naive_local());
+        loop {
+            let local = try_opt!(self.prev_local_from(from));
+            match tz.from_local_datetime(&local) {
+                LocalResult::Single(t) | LocalResult::Ambiguous(t, _) if t < *dt => return Some(t),
+                _ => {}
+            }
+            from = try_opt!(local.checked_sub_signed(OldDuration::minutes(1)));
+        }
+    }
+
+    /// Returns true when the expression matches given local date.
+    fn matches_date(&self, date: NaiveDate) -> bool {
+        if self.months & (1 << date.month()) == 0 {
+            return false;
+        }
+        let day = self.days & (1 << date.day()) != 0;
+        let weekday = self.weekdays & (1 << date.weekday().num_days_from_sunday()) != 0;
+        if self.days_star || self.weekdays_star {
+            day && weekday
+        } else {
+            day || weekday
+        }
+    }
+
+    /// Returns the earliest matching local time on or after given local time (in minutes).
+    fn next_local_from(&self, from: NaiveDateTime) -> Option<NaiveDateTime> {
+        let mut date = from.date();
+        let mut start = (from.hour(), from.minute());
+        for _ in 0..DAYS_IN_400_YEARS + 1 {
+            if self.matches_date(date) {
+                for hour in start.0..24 {
+                    if self.hours & (1 << hour) == 0 {
+                        continue;
+                    }
+                    let first = if hour == start.0 { start.1 } else { 0 };
+                    for minute in first..60 {
+                        if self.minutes & (1 << minute) != 0 {
+                            return date.and_hms_opt(hour, minute, 0);
+                        }
+                    }
+                }
+            }
+            date = try_opt!(date.succ_opt());
+            start = (0, 0);
+        }
+        None
+    }
+
+    /// Returns the latest matching local time on or before given local time (in minutes).
+    fn prev_local_from(&self, from: NaiveDateTime) -> Option<NaiveDateTime> {
+        let mut date = from.date();
+        let mut end = (from.hour(), from.minute());
+        for _ in 0..DAYS_IN_400_YEARS + 1 {
+            if self.matches_date(date) {
+                for hour in (0..end.0 + 1).rev() {
+                    if self.hours & (1 << hour) == 0 {
+                        continue;
+                    }
+                    let last = if hour == end.0 { end.1 } else { 59 };
+                    for minute in (0..last + 1).rev() {
+                        if self.minutes & (1 << minute) != 0 {
+                            return date.and_hms_opt(hour, minute, 0);
+                        }
+                    }
+                }
+            }
+            date = try_opt!(date.pred_opt());
+            end = (23, 59);
+        }
+        None
+    }
+}
+
+/// Truncates given local date and time to the whole minute.
+fn truncate_to_minute(dt: NaiveDateTime) -> NaiveDateTime {
+    dt.date().and_hms(dt.hour(), dt.minute(), 0)
+}
+
+/// Parses a five-field cron expression.
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::cron::Cron;
+///
+/// assert!("0 9 * * mon-fri".parse::<Cron>().is_ok());
+/// assert!("0 9 * *".parse::<Cron>().is_err());
+/// assert!("0 24 * * *".parse::<Cron>().is_err());
+/// ~~~~
+impl str::FromStr for Cron {
+    type Err = ParseCronError;
+
+    fn from_str(s: &str) -> Result<Cron, ParseCronError> {
+        let fields: Vec<&str> = s.split_whitespace().collect();
+        if fields.len() != 5 {
+            return Err(ParseCronError(CronErrorKind::FieldCount));
+        }
+
+        let weekdays = try!(parse_field(fields[4], 0, 7, &WEEKDAY_NAMES));
+        // both 0 and 7 are Sunday
+        let weekdays = (weekdays | (weekdays >> 7)) & 0x7f;
+        Ok(Cron {
+            minutes: try!(parse_field(fields[0], 0, 59, &[])),
+            hours: try!(parse_field(fields[1], 0, 23, &[])) as u32,
+            days: try!(parse_field(fields[2], 1, 31, &[])) as u32,
+            months: try!(parse_field(fields[3], 1, 12, &MONTH_NAMES)) as u16,
+            weekdays: weekdays as u8,
+            days_star: fields[2].starts_with('*'),
+            weekdays_star: fields[4].starts_with('*'),
+        })
+    }
+}
+
+/// Parses a comma-separated list of values, ranges and steps into a bit mask.
+/// `names`, if any, are lowercased names for the values starting from `min`.
+fn parse_field(s: &str, min: u32, max: u32, names: &[&str]) -> Result<u64, ParseCronError> {
+    let mut mask = 0;
+    for item in s.split(',') {
+        let (range, step) = match item.find('/') {
+            Some(i) => (&item[..i], Some(try!(parse_number(&item[i + 1..])))),
+            None => (item, None),
+        };
+        let (lo, hi) = if range == "*" {
+            (min, max)
+        } else if let Some(i) = range.find('-') {
+            let lo = try!(parse_value(&range[..i], min, max, names));
+            let hi = try!(parse_value(&range[i + 1..], min, max, names));
+            (lo, hi)
+        } else {
+            let lo = try!(parse_value(range, min, max, names));
+            (lo, if step.is_some() { max } else { lo })
+        };
+        if lo > hi || step == Some(0) {
+            return Err(ParseCronError(CronErrorKind::OutOfRange));
+        }
+
+        let step = step.unwrap_or(1);
+        let mut v = lo;
+        while v <= hi {
+            mask |= 1 << v;
+            v = match v.checked_add(step) {
+                Some(v) => v,
+                None => break,
+            };
+        }
+    }
+    Ok(mask)
+}
+
+/// Parses a single value in the given range, either as a number or a name.
+fn parse_value(s: &str, min: u32, max: u32, names: &[&str]) -> Result<u32, ParseCronError> {
+    let eq =
+        |name: &str| s.len() == name.len() && s.bytes().zip(name.bytes()).all(|(a, b)| a | 32 == b);
+    if let Some(i) = names.iter().position(|&name| eq(name)) {
+        return Ok(min + i as u32);
+    }
+    let v = try!(parse_number(s));
+    if v < min || v > max {
+        return Err(ParseCronError(CronErrorKind::OutOfRange));
+    }
+    Ok(v)
+}
+
+/// Parses a non-negative decimal number.
+fn parse_number(s: &str) -> Result<u32, ParseCronError> {
+    if s.is_empty() || !s.chars().all(|c| c.is_digit(10)) {
+        return Err(ParseCronError(CronErrorKind::Invalid));
+    }
+    s.parse()
+        .map_err(|_| ParseCronError(CronErrorKind::OutOfRange))
+}
+
+/// An error from parsing a [`Cron`](./struct.Cron.html) expression.
+#[derive(Debug, Clone, PartialEq, Eq, Copy)]
+pub struct ParseCronError(CronErrorKind);
+
+#[derive(Debug, Clone, PartialEq, Eq, Copy)]
+enum CronErrorKind {
+    /// The expression does not have exactly five fields.
+    FieldCount,
+
+    /// Given value or step is out of permitted range, or a range is reversed.
+    OutOfRange,
+
+    /// The expression has some invalid character sequence.
+    Invalid,
+}
+
+impl ParseCronError {
+    fn as_str(&self) -> &'static str {
+        match self.0 {
+            CronErrorKind::FieldCount => "cron expression should have exactly five fields",
+            CronErrorKind::OutOfRange => "cron field is out of range",
+            CronErrorKind::Invalid => "cron field contains invalid characters",
+        }
+    }
+}
+
+impl fmt::Display for ParseCronError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl Error for ParseCronError {
+    fn description(&self) -> &str {
+        self.as_str()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Cron, CronErrorKind, ParseCronError};
+    use offset::{FixedOffset, TimeZone, Utc};
+    use oldtime::Duration;
+    use test_zones::Dst;
+    use DateTime;
+
+    fn cron(s: &str) -> Cron {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_cron_next_after() {
+        // a Wednesday
+        let base = Utc.ymd(2019, 10, 16).and_hms(15, 30, 0);
+        let ymdhm = |y, m, d, h, n| Some(Utc.ymd(y, m, d).and_hms(h, n, 0));
+
+        let cases = [
+            ("* * * * *", ymdhm(2019, 10, 16, 15, 31)),
+            ("0 * * * *", ymdhm(2019, 10, 16, 16, 0)),
+            ("30 15 * * *", ymdhm(2019, 10, 17, 15, 30)),
+            ("*/15 9-17 * * MON-FRI", ymdhm(2019, 10, 16, 15, 45)),
+            ("23 0-20/2 * * *", ymdhm(2019, 10, 16, 16, 23)),
+            ("0 22 * * 1-5", ymdhm(2019, 10, 16, 22, 0)),
+            ("0 9 * * 1-5", ymdhm(2019, 10, 17, 9, 0)),
+            ("5,10 4 * * sun", ymdhm(2019, 10, 20, 4, 5)),
+            ("0 0 * * 7", ymdhm(2019, 10, 20, 0, 0)),
+            ("0 0 * * 0", ymdhm(2019, 10, 20, 0, 0)),
+            ("0 12 13 * fri", ymdhm(2019, 10, 18, 12, 0)),
+            ("0 12 13 * *", ymdhm(2019, 11, 13, 12, 0)),
+            ("0 0 31 * *", ymdhm(2019, 10, 31, 0, 0)),
+            ("15 14 1 * *", ymdhm(2019, 11, 1, 14, 15)),
+            ("0 0 1 jan,Jul *", ymdhm(2020, 1, 1, 0, 0)),
+            ("0 0 1 1 *", ymdhm(2020, 1, 1, 0, 0)),
+            ("0 0 29 2 *", ymdhm(2020, 2, 29, 0, 0)),
+            ("0 0 29 feb mon", ymdhm(2020, 2, 3, 0, 0)),
+            ("0 0 */10 * *", ymdhm(2019, 10, 21, 0, 0)),
+            ("45/5 * * * *", ymdhm(2019, 10, 16, 15, 45)),
+            ("0 0 30 2 *", None),
+        ];
+        for &(expr, expected) in cases.iter() {
+            assert_eq!(
+                cron(expr).next_after(&base),
+                expected,
+                "next_after for {}",
+                expr
+            );
+        }
+
+        assert_eq!(
+            cron("0 0 31 * *").next_after(&Utc.ymd(2019, 10, 31).and_hms(0, 0, 0)),
+            ymdhm(2019, 12, 31, 0, 0)
+        );
+        assert_eq!(
+            cron("* * * * *").next_after(&Utc.ymd(2019, 12, 31).and_hms_nano(23, 59, 59, 1)),
+            ymdhm(2020, 1, 1, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_cron_prev_before() {
+        let base = Utc.ymd(2019, 10, 16).and_hms(15, 30, 0);
+        let ymdhm = |y, m, d, h, n| Some(Utc.ymd(y, m, d).and_hms(h, n, 0));
+
+        let cases = [
+            ("* * * * *", ymdhm(2019, 10, 16, 15, 29)),
+            ("30 15 * * *", ymdhm(2019, 10, 15, 15, 30)),
+            ("0 * * * *", ymdhm(2019, 10, 16, 15, 0)),
+            ("0 9 * * 1-5", ymdhm(2019, 10, 16, 9, 0)),
+            ("0 22 * * 1-5", ymdhm(2019, 10, 15, 22, 0)),
+            ("5,10 4 * * sun", ymdhm(2019, 10, 13, 4, 10)),
+            ("0 0 1 1 *", ymdhm(2019, 1, 1, 0, 0)),
+            ("0 0 29 2 *", ymdhm(2016, 2, 29, 0, 0)),
+            ("0 0 30 2 *", None),
+        ];
+        for &(expr, expected) in cases.iter() {
+            assert_eq!(
+                cron(expr).prev_before(&base),
+                expected,
+                "prev_before for {}",
+                expr
+            );
+        }
+
+        let base = Utc.ymd(2019, 10, 16).and_hms(15, 30, 20);
+        assert_eq!(
+            cron("* * * * *").prev_before(&base),
+            ymdhm(2019, 10, 16, 15, 30)
+        );
+    }
+
+    #[test]
+    fn test_cron_fixed_offset() {
+        let tz = FixedOffset::west(5 * 3600);
+        let base = tz.ymd(2019, 10, 16).and_hms(23, 30, 0);
+        assert_eq!(
+            cron("0 0 * * *").next_after(&base),
+            Some(tz.ymd(2019, 10, 17).and_hms(0, 0, 0))
+        );
+        assert_eq!(
+            cron("0 0 * * *").prev_before(&base),
+            Some(tz.ymd(2019, 10, 16).and_hms(0, 0, 0))
+        );
+    }
+
+    #[test]
+    fn test_cron_dst_gap() {
+        // 02:00 to 03:00 does not exist on 2019-03-31
+        let base = Dst.ymd(2019, 3, 30).and_hms(12, 0, 0);
+        let expected: DateTime<Dst> = Dst.ymd(2019, 4, 1).and_hms(2, 30, 0);
+        assert_eq!(cron("30 2 * * *").next_after(&base), Some(expected));
+        assert_eq!(
+            cron("30 2 * * *").prev_before(&expected),
+            Some(Dst.ymd(2019, 3, 30).and_hms(2, 30, 0))
+        );
+
+        let base = Dst.ymd(2019, 3, 31).and_hms(1, 50, 0);
+        assert_eq!(
+            cron("*/15 * * * *").next_after(&base),
+            Some(Dst.ymd(2019, 3, 31).and_hms(3, 0, 0))
+        );
+    }
+
+    #[test]
+    fn test_cron_dst_overlap() {
+        // 02:00 to 03:00 happens twice on 2019-10-27: first at UTC+02:00, then at UTC+01:00
+        let utc = |h, n| Utc.ymd(2019, 10, 27).and_hms(h, n, 0).with_timezone(&Dst);
+
+        let base = Dst.ymd(2019, 10, 26).and_hms(12, 0, 0);
+        let first = cron("30 2 * * *").next_after(&base).unwrap();
+        assert_eq!(first, utc(0, 30));
+        assert_eq!(
+            cron("30 2 * * *").next_after(&first),
+            Some(Dst.ymd(2019, 10, 28).and_hms(2, 30, 0))
+        );
+        // even during the second 02:00 to 03:00
+        assert_eq!(
+            cron("30 2 * * *").next_after(&utc(1, 10)),
+            Some(Dst.ymd(2019, 10, 28).and_hms(2, 30, 0))
+        );
+        assert_eq!(
+            cron("30 2 * * *").prev_before(&utc(1, 40)),
+            Some(utc(0, 30))
+        );
+
+        let expr = cron("*/30 * * * *");
+        let mut fired = vec![];
+        let mut dt = utc(0, 0) - Duration::minutes(1);
+        for _ in 0..4 {
+            dt = expr.next_after(&dt).unwrap();
+            fired.push(dt);
+        }
+        assert_eq!(fired, vec![utc(0, 0), utc(0, 30), utc(2, 0), utc(2, 30)]);
+
+        let mut fired = vec![];
+        let mut dt = utc(2, 30);
+        for _ in 0..3 {
+            dt = expr.prev_before(&dt).unwrap();
+            fired.push(dt);
+        }
+        assert_eq!(fired, vec![utc(2, 0), utc(0, 30), utc(0, 0)]);
+    }
+
+    #[test]
+    fn test_cron_parse() {
+        assert_eq!(cron("*/20 * * * *"), cron("0,20,40 * * * *"));
+        assert_eq!(cron("0 0 * * 0"), cron("0 0 * * 7"));
+        assert_eq!(cron("0 0 * * sun"), cron("0 0 * * SUN"));
+        assert_eq!(cron("0 0 * Jan-Mar *"), cron("0 0 * 1-3 *"));
+        assert_eq!(cron("5/20 * * * *"), cron("5,25,45 * * * *"));
+        assert_eq!(cron("5/4294967295 * * * *"), cron("5 * * * *"));
+        assert_eq!(cron("  0   0  *  *  * "), cron("0 0 * * *"));
+
+        let err = |kind| Err(ParseCronError(kind));
+        assert_eq!("".parse::<Cron>(), err(CronErrorKind::FieldCount));
+        assert_eq!("* * * *".parse::<Cron>(), err(CronErrorKind::FieldCount));
+        assert_eq!(
+            "* * * * * *".parse::<Cron>(),
+            err(CronErrorKind::FieldCount)
+        );
+        assert_eq!("60 * * * *".parse::<Cron>(), err(CronErrorKind::OutOfRange));
+        assert_eq!("* 24 * * *".parse::<Cron>(), err(CronErrorKind::OutOfRange));
+        assert_eq!("* * 0 * *".parse::<Cron>(), err(CronErrorKind::OutOfRange));
+        assert_eq!("* * 32 * *".parse::<Cron>(), err(CronErrorKind::OutOfRange));
+        assert_eq!("* * * 13 *".parse::<Cron>(), err(CronErrorKind::OutOfRange));
+        assert_eq!("* * * * 8".parse::<Cron>(), err(CronErrorKind::OutOfRange));
+        assert_eq!(
+            "5-1 * * * *".parse::<Cron>(),
+            err(CronErrorKind::OutOfRange)
+        );
+        assert_eq!(
+            "*/0 * * * *".parse::<Cron>(),
+            err(CronErrorKind::OutOfRange)
+        );
+        assert_eq!(
+            "99999999999 * * * *".parse::<Cron>(),
+            err(CronErrorKind::OutOfRange)
+        );
+        assert_eq!("a * * * *".parse::<Cron>(), err(CronErrorKind::Invalid));
+        assert_eq!("1,,2 * * * *".parse::<Cron>(), err(CronErrorKind::Invalid));
+        assert_eq!("-1 * * * *".parse::<Cron>(), err(CronErrorKind::Invalid));
+        assert_eq!(
+            "* * * * monday".parse::<Cron>(),
+            err(CronErrorKind::Invalid)
+        );
+        assert!("* * * jan *".parse::<Cron>().is_ok());
+        assert_eq!("* * * * jan".parse::<Cron>(), err(CronErrorKind::Invalid));
+    }
+}
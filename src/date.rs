@@ -5,14 +5,15 @@
 
 use oldtime::Duration as OldDuration;
 use std::cmp::Ordering;
-use std::ops::{Add, Sub};
+use std::error::Error;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
 use std::{fmt, hash};
 
 use format::{DelayedFormat, Item, StrftimeItems};
 use naive::{self, IsoWeek, NaiveDate, NaiveTime};
-use offset::{TimeZone, Utc};
+use offset::{Offset, TimeZone, Utc};
 use DateTime;
-use {Datelike, Weekday};
+use {Age, Datelike, Days, Months, Weekday};
 
 /// ISO 8601 calendar date with time zone.
 ///
@@ -96,6 +97,44 @@ impl<Tz: TimeZone> Date<Tz> {
         NaiveTime::from_hms_opt(hour, min, sec).and_then(|time| self.and_time(time))
     }
 
+    /// Makes a new `DateTime` from the current date, hour, minute and second.
+    /// The offset in the current date is preserved.
+    ///
+    /// Unlike [`and_hms_opt`](#method.and_hms_opt), this reports which component was invalid.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{TimeComponent, TimeZone, Timelike, Utc};
+    ///
+    /// let d = Utc.ymd(2015, 6, 3);
+    /// assert_eq!(d.try_and_hms(12, 34, 56).map(|dt| dt.minute()), Ok(34));
+    ///
+    /// let err = d.try_and_hms(12, 60, 56).unwrap_err();
+    /// assert_eq!(err.component(), Some(TimeComponent::Minute));
+    /// assert_eq!(err.to_string(), "minute is out of range");
+    /// ~~~~
+    pub fn try_and_hms(
+        &self,
+        hour: u32,
+        min: u32,
+        sec: u32,
+    ) -> Result<DateTime<Tz>, ComponentRangeError> {
+        let invalid = if hour >= 24 {
+            TimeComponent::Hour
+        } else if min >= 60 {
+            TimeComponent::Minute
+        } else if sec >= 60 {
+            TimeComponent::Second
+        } else {
+            // all components are valid, so only the time zone can reject the time
+            return self
+                .and_hms_opt(hour, min, sec)
+                .ok_or(ComponentRangeError(None));
+        };
+        Err(ComponentRangeError(Some(invalid)))
+    }
+
     /// Makes a new `DateTime` from the current date, hour, minute, second and millisecond.
     /// The millisecond part can exceed 1,000 in order to represent the leap second.
     /// The offset in the current date is preserved.
@@ -213,6 +252,26 @@ impl<Tz: TimeZone> Date<Tz> {
             .map(|date| Date::from_utc(date, self.offset.clone()))
     }
 
+    /// Makes a new `Date` with the year number changed,
+    /// clamping the day of month to the last valid day of the resulting month.
+    ///
+    /// Returns `None` when the resulting date does not exist.
+    /// See also [`NaiveDate::with_year_clamped`](./naive/struct.NaiveDate.html#method.with_year_clamped).
+    #[inline]
+    pub fn with_year_clamped(&self, year: i32) -> Option<Date<Tz>> {
+        map_local(self, |date| date.with_year_clamped(year))
+    }
+
+    /// Makes a new `Date` with the month number (starting from 1) changed,
+    /// clamping the day of month to the last valid day of the resulting month.
+    ///
+    /// Returns `None` when the resulting date does not exist.
+    /// See also [`NaiveDate::with_month_clamped`](./naive/struct.NaiveDate.html#method.with_month_clamped).
+    #[inline]
+    pub fn with_month_clamped(&self, month: u32) -> Option<Date<Tz>> {
+        map_local(self, |date| date.with_month_clamped(month))
+    }
+
     /// Retrieves an associated offset from UTC.
     #[inline]
     pub fn offset(&self) -> &Tz::Offset {
@@ -256,11 +315,89 @@ impl<Tz: TimeZone> Date<Tz> {
         })
     }
 
+    /// Adds given number of calendar months to the current date,
+    /// clamping the day of month to the last day of the resulting month.
+    ///
+    /// Returns `None` when the resulting date would be out of range
+    /// or does not exist in the time zone.
+    #[inline]
+    pub fn checked_add_months(self, months: Months) -> Option<Date<Tz>> {
+        map_local(&self, |date| date.checked_add_months(months))
+    }
+
+    /// Subtracts given number of calendar months from the current date,
+    /// clamping the day of month to the last day of the resulting month.
+    ///
+    /// Returns `None` when the resulting date would be out of range
+    /// or does not exist in the time zone.
+    #[inline]
+    pub fn checked_sub_months(self, months: Months) -> Option<Date<Tz>> {
+        map_local(&self, |date| date.checked_sub_months(months))
+    }
+
+    /// Adds given number of days to the current date.
+    ///
+    /// Returns `None` when the resulting date would be out of range
+    /// or does not exist in the time zone.
+    #[inline]
+    pub fn checked_add_days(self, days: Days) -> Option<Date<Tz>> {
+        map_local(&self, |date| date.checked_add_days(days))
+    }
+
+    /// Subtracts given number of days from the current date.
+    ///
+    /// Returns `None` when the resulting date would be out of range
+    /// or does not exist in the time zone.
+    #[inline]
+    pub fn checked_sub_days(self, days: Days) -> Option<Date<Tz>> {
+        map_local(&self, |date| date.checked_sub_days(days))
+    }
+
+    /// Returns the calendar years, months and days elapsed from the current date to `at`,
+    /// e.g. the age at `at` of someone born on the current date.
+    /// Both dates are taken as local dates in their own time zones.
+    ///
+    /// Returns `None` when `at` is earlier than the current date.
+    /// See [`NaiveDate::age_at`](./naive/struct.NaiveDate.html#method.age_at) for details.
+    #[cfg_attr(feature = "cargo-clippy", allow(needless_pass_by_value))]
+    #[inline]
+    pub fn age_at<Tz2: TimeZone>(&self, at: Date<Tz2>) -> Option<Age> {
+        self.naive_local().age_at(at.naive_local())
+    }
+
+    /// Returns the number of whole years elapsed from `since` to the current date,
+    /// e.g. the age today of someone born on `since`.
+    /// Both dates are taken as local dates in their own time zones.
+    ///
+    /// Returns `None` when `since` is later than the current date.
+    /// See [`NaiveDate::years_since`](./naive/struct.NaiveDate.html#method.years_since) for details.
+    #[cfg_attr(feature = "cargo-clippy", allow(needless_pass_by_value))]
+    #[inline]
+    pub fn years_since<Tz2: TimeZone>(&self, since: Date<Tz2>) -> Option<u32> {
+        self.naive_local().years_since(since.naive_local())
+    }
+
     /// Subtracts another `Date` from the current date.
     /// Returns a `Duration` of integral numbers.
     ///
     /// This does not overflow or underflow at all,
     /// as all possible output fits in the range of `Duration`.
+    ///
+    /// The result is negative when the current date is earlier than `rhs`.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// # extern crate chrono; extern crate time; fn main() {
+    /// use chrono::{TimeZone, Utc};
+    /// use time::Duration;
+    ///
+    /// let d = Utc.ymd(2020, 3, 1);
+    /// assert_eq!(d.signed_duration_since(Utc.ymd(2020, 2, 1)), Duration::days(29));
+    /// assert_eq!(Utc.ymd(2020, 2, 1).signed_duration_since(d), Duration::days(-29));
+    /// assert_eq!(d - Utc.ymd(2019, 3, 1), Duration::days(366));
+    /// # }
+    /// ~~~~
     #[cfg_attr(feature = "cargo-clippy", allow(needless_pass_by_value))]
     #[inline]
     pub fn signed_duration_since<Tz2: TimeZone>(self, rhs: Date<Tz2>) -> OldDuration {
@@ -282,6 +419,29 @@ impl<Tz: TimeZone> Date<Tz> {
     pub fn naive_local(&self) -> NaiveDate {
         self.date
     }
+
+    /// Returns `true` when both the date and the numeric offset from UTC are equal.
+    ///
+    /// `==` only compares the dates, so the same date in different offsets
+    /// compares equal; this is stricter and useful for e.g. snapshot tests.
+    /// Note that `Hash` is consistent with `==` and not with `exact_eq`.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{FixedOffset, TimeZone, Utc};
+    ///
+    /// let utc = Utc.ymd(2019, 12, 25);
+    /// let cet = FixedOffset::east(3600).ymd(2019, 12, 25);
+    /// assert_eq!(utc, cet);
+    /// assert!(!utc.exact_eq(&cet));
+    /// assert!(utc.exact_eq(&FixedOffset::east(0).ymd(2019, 12, 25)));
+    /// ~~~~
+    #[inline]
+    pub fn exact_eq<Tz2: TimeZone>(&self, other: &Date<Tz2>) -> bool {
+        self.date == other.date
+            && self.offset.fix().local_minus_utc() == other.offset.fix().local_minus_utc()
+    }
 }
 
 /// Maps the local date to other date with given conversion function.
@@ -297,6 +457,31 @@ where
     Tz::Offset: fmt::Display,
 {
     /// Formats the date with the specified formatting items.
+    /// Otherwise it is same to the ordinary `format` method.
+    ///
+    /// The `Iterator` of items should be `Clone`able,
+    /// since the resulting `DelayedFormat` value may be formatted multiple times.
+    /// A slice of items prepared in advance can be passed as `items.iter().cloned()`,
+    /// which avoids parsing the format string on every call.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{TimeZone, Utc};
+    /// use chrono::format::{Fixed, Item, Numeric, Pad};
+    ///
+    /// const ITEMS: &'static [Item<'static>] = &[
+    ///     Item::Numeric(Numeric::Year, Pad::Zero),
+    ///     Item::Literal("/"),
+    ///     Item::Numeric(Numeric::Month, Pad::Zero),
+    ///     Item::Space(" "),
+    ///     Item::Fixed(Fixed::TimezoneName),
+    /// ];
+    ///
+    /// let d = Utc.ymd(2015, 9, 5);
+    /// assert_eq!(d.format_with_items(ITEMS.iter().cloned()).to_string(), "2015/09 UTC");
+    /// assert_eq!(d.format("%Y/%m %Z").to_string(), "2015/09 UTC");
+    /// ~~~~
     #[inline]
     pub fn format_with_items<'a, I>(&self, items: I) -> DelayedFormat<I>
     where
@@ -412,6 +597,46 @@ impl<Tz: TimeZone> Ord for Date<Tz> {
     }
 }
 
+/// Compares a `Date<Utc>` with a `NaiveDate`, **treating the naive date as UTC**.
+///
+/// Only `Date<Utc>` can be compared this way; convert other dates with
+/// [`with_timezone`](#method.with_timezone) or compare their
+/// [`naive_local`](#method.naive_local) dates instead.
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::{NaiveDate, TimeZone, Utc};
+///
+/// let naive = NaiveDate::from_ymd(2019, 12, 31);
+/// assert_eq!(Utc.ymd(2019, 12, 31), naive);
+/// assert!(Utc.ymd(2019, 12, 30) < naive);
+/// assert!(naive > Utc.ymd(2019, 12, 30));
+/// ~~~~
+impl PartialEq<NaiveDate> for Date<Utc> {
+    fn eq(&self, other: &NaiveDate) -> bool {
+        self.date == *other
+    }
+}
+
+impl PartialEq<Date<Utc>> for NaiveDate {
+    fn eq(&self, other: &Date<Utc>) -> bool {
+        *self == other.date
+    }
+}
+
+impl PartialOrd<NaiveDate> for Date<Utc> {
+    fn partial_cmp(&self, other: &NaiveDate) -> Option<Ordering> {
+        self.date.partial_cmp(other)
+    }
+}
+
+impl PartialOrd<Date<Utc>> for NaiveDate {
+    fn partial_cmp(&self, other: &Date<Utc>) -> Option<Ordering> {
+        self.partial_cmp(&other.date)
+    }
+}
+
 impl<Tz: TimeZone> hash::Hash for Date<Tz> {
     fn hash<H: hash::Hasher>(&self, state: &mut H) {
         self.date.hash(state)
@@ -428,6 +653,13 @@ impl<Tz: TimeZone> Add<OldDuration> for Date<Tz> {
     }
 }
 
+impl<Tz: TimeZone> AddAssign<OldDuration> for Date<Tz> {
+    #[inline]
+    fn add_assign(&mut self, rhs: OldDuration) {
+        *self = self.clone().add(rhs);
+    }
+}
+
 impl<Tz: TimeZone> Sub<OldDuration> for Date<Tz> {
     type Output = Date<Tz>;
 
@@ -438,6 +670,53 @@ impl<Tz: TimeZone> Sub<OldDuration> for Date<Tz> {
     }
 }
 
+impl<Tz: TimeZone> SubAssign<OldDuration> for Date<Tz> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: OldDuration) {
+        *self = self.clone().sub(rhs);
+    }
+}
+
+impl<Tz: TimeZone> Add<Months> for Date<Tz> {
+    type Output = Date<Tz>;
+
+    #[inline]
+    fn add(self, rhs: Months) -> Date<Tz> {
+        self.checked_add_months(rhs)
+            .expect("`Date + Months` out of range")
+    }
+}
+
+impl<Tz: TimeZone> Sub<Months> for Date<Tz> {
+    type Output = Date<Tz>;
+
+    #[inline]
+    fn sub(self, rhs: Months) -> Date<Tz> {
+        self.checked_sub_months(rhs)
+            .expect("`Date - Months` out of range")
+    }
+}
+
+impl<Tz: TimeZone> Add<Days> for Date<Tz> {
+    type Output = Date<Tz>;
+
+    #[inline]
+    fn add(self, rhs: Days) -> Date<Tz> {
+        self.checked_add_days(rhs)
+            .expect("`Date + Days` out of range")
+    }
+}
+
+impl<Tz: TimeZone> Sub<Days> for Date<Tz> {
+    type Output = Date<Tz>;
+
+    #[inline]
+    fn sub(self, rhs: Days) -> Date<Tz> {
+        self.checked_sub_days(rhs)
+            .expect("`Date - Days` out of range")
+    }
+}
+
 impl<Tz: TimeZone> Sub<Date<Tz>> for Date<Tz> {
     type Output = OldDuration;
 
@@ -447,6 +726,41 @@ impl<Tz: TimeZone> Sub<Date<Tz>> for Date<Tz> {
     }
 }
 
+impl Date<Utc> {
+    /// Returns the earliest representable date in UTC, the same as [`MIN_DATE`](./constant.MIN_DATE.html).
+    #[inline]
+    pub fn min_value() -> Date<Utc> {
+        MIN_DATE
+    }
+
+    /// Returns the latest representable date in UTC, the same as [`MAX_DATE`](./constant.MAX_DATE.html).
+    #[inline]
+    pub fn max_value() -> Date<Utc> {
+        MAX_DATE
+    }
+}
+
+/// Convert a `Date<Utc>` instance into a `NaiveDate` instance.
+impl From<Date<Utc>> for NaiveDate {
+    /// Convert this `Date<Utc>` instance into a `NaiveDate` instance.
+    ///
+    /// The result is the UTC date as in [`Date::naive_utc`](./struct.Date.html#method.naive_utc).
+    fn from(src: Date<Utc>) -> Self {
+        src.naive_utc()
+    }
+}
+
+/// Convert a `NaiveDate` instance into a `Date<Utc>` instance.
+impl From<NaiveDate> for Date<Utc> {
+    /// Convert this `NaiveDate` instance into a `Date<Utc>` instance.
+    ///
+    /// The naive date is taken as UTC, as in
+    /// [`TimeZone::from_utc_date`](./offset/trait.TimeZone.html#method.from_utc_date).
+    fn from(src: NaiveDate) -> Self {
+        Utc.from_utc_date(&src)
+    }
+}
+
 impl<Tz: TimeZone> fmt::Debug for Date<Tz> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{:?}{:?}", self.naive_local(), self.offset)
@@ -461,3 +775,112 @@ where
         write!(f, "{}{}", self.naive_local(), self.offset)
     }
 }
+
+/// A component of the time of day, as reported by [`ComponentRangeError`](./struct.ComponentRangeError.html).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum TimeComponent {
+    /// The hour, from 0 to 23.
+    Hour,
+    /// The minute, from 0 to 59.
+    Minute,
+    /// The second, from 0 to 59.
+    Second,
+}
+
+/// An error from [`Date::try_and_hms`](./struct.Date.html#method.try_and_hms),
+/// telling which component of the time was out of range.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ComponentRangeError(Option<TimeComponent>);
+
+impl ComponentRangeError {
+    /// Returns the component that was out of range.
+    ///
+    /// Returns `None` when every component was in range
+    /// but the resulting local time is ambiguous or does not exist in the time zone.
+    pub fn component(&self) -> Option<TimeComponent> {
+        self.0
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self.0 {
+            Some(TimeComponent::Hour) => "hour is out of range",
+            Some(TimeComponent::Minute) => "minute is out of range",
+            Some(TimeComponent::Second) => "second is out of range",
+            None => "local time is ambiguous or does not exist",
+        }
+    }
+}
+
+impl fmt::Display for ComponentRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl Error for ComponentRangeError {
+    fn description(&self) -> &str {
+        self.as_str()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ComponentRangeError, TimeComponent};
+    use format::{Fixed, Item, Numeric, Pad, StrftimeItems};
+    use offset::{FixedOffset, TimeZone, Utc};
+    use test_zones::Dst;
+    use Timelike;
+
+    #[test]
+    fn test_date_try_and_hms() {
+        let d = Utc.ymd(2014, 5, 6);
+        assert_eq!(d.try_and_hms(7, 8, 9), Ok(d.and_hms(7, 8, 9)));
+        assert_eq!(d.try_and_hms(23, 59, 59), Ok(d.and_hms(23, 59, 59)));
+
+        let component = |h, m, s| d.try_and_hms(h, m, s).unwrap_err().component();
+        assert_eq!(component(24, 0, 0), Some(TimeComponent::Hour));
+        assert_eq!(component(0, 60, 0), Some(TimeComponent::Minute));
+        assert_eq!(component(0, 0, 60), Some(TimeComponent::Second));
+        assert_eq!(component(24, 60, 60), Some(TimeComponent::Hour));
+        assert_eq!(component(0, 60, 60), Some(TimeComponent::Minute));
+
+        // 02:00 to 03:00 does not exist on 2019-03-31
+        let d = Dst.ymd(2019, 3, 31);
+        assert_eq!(d.try_and_hms(2, 30, 0), Err(ComponentRangeError(None)));
+        assert_eq!(d.try_and_hms(3, 30, 0).map(|dt| dt.hour()), Ok(3));
+        assert_eq!(
+            d.try_and_hms(2, 60, 0).unwrap_err().component(),
+            Some(TimeComponent::Minute)
+        );
+    }
+
+    #[test]
+    fn test_date_format_with_items() {
+        let items = [
+            Item::Fixed(Fixed::ShortWeekdayName),
+            Item::Literal(", "),
+            Item::Numeric(Numeric::Day, Pad::None),
+            Item::Space(" "),
+            Item::Fixed(Fixed::ShortMonthName),
+            Item::Space(" "),
+            Item::Numeric(Numeric::Year, Pad::Zero),
+            Item::Space(" "),
+            Item::Fixed(Fixed::TimezoneOffsetColon),
+        ];
+        let d = FixedOffset::east(9 * 3600).ymd(2015, 9, 5);
+        let formatted = d.format_with_items(items.iter().cloned());
+        assert_eq!(formatted.to_string(), "Sat, 5 Sep 2015 +09:00");
+        assert_eq!(
+            formatted.to_string(),
+            d.format("%a, %-d %b %Y %:z").to_string()
+        );
+
+        let items = StrftimeItems::new("%F").collect::<Vec<_>>();
+        assert_eq!(
+            Utc.ymd(-12, 1, 2)
+                .format_with_items(items.iter().cloned())
+                .to_string(),
+            "-0012-01-02"
+        );
+    }
+}
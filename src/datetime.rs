@@ -5,18 +5,21 @@
 
 use oldtime::Duration as OldDuration;
 use std::cmp::Ordering;
-use std::ops::{Add, Sub};
+use std::ops::{Add, AddAssign, Sub, SubAssign};
 use std::time::{SystemTime, UNIX_EPOCH};
-use std::{fmt, hash, str};
+use std::{fmt, hash, i64, str};
 
+use div::mod_floor;
 use format::{parse, DelayedFormat, ParseError, ParseResult, Parsed, StrftimeItems};
 use format::{Fixed, Item, Numeric, Pad};
 use naive::{IsoWeek, NaiveDateTime, NaiveTime};
 #[cfg(feature = "clock")]
 use offset::Local;
-use offset::{FixedOffset, Offset, TimeZone, Utc};
-use Date;
-use {Datelike, Timelike, Weekday};
+use offset::{AnyOffset, AnyTimeZone, FixedOffset, Offset, TimeZone, Utc};
+#[cfg(feature = "clock")]
+use SystemClock;
+use {Clock, Date};
+use {Datelike, Days, DurationExt, Months, Timelike, Weekday};
 
 /// Specific formatting options for seconds. This may be extended in the
 /// future, so exhaustive matching in external code is not recommended.
@@ -49,11 +52,75 @@ pub enum SecondsFormat {
     __NonExhaustive,
 }
 
+/// Specific formatting options for the offset from UTC. This may be extended in the
+/// future, so exhaustive matching in external code is not recommended.
+///
+/// See the `DateTime::to_string_with_offset_style` and
+/// `DateTime::to_rfc3339_with_offset_style` functions for usage.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OffsetStyle {
+    /// Use `Z` for the zero offset and `+hh:mm` otherwise. This corresponds to
+    /// [Fixed::TimezoneOffsetColonZ](format/enum.Fixed.html#variant.TimezoneOffsetColonZ).
+    Z,
+
+    /// Use `+hh:mm` even for the zero offset. This corresponds to
+    /// [Fixed::TimezoneOffsetColon](format/enum.Fixed.html#variant.TimezoneOffsetColon).
+    Colon,
+
+    /// Use `+hhmm` even for the zero offset. This corresponds to
+    /// [Fixed::TimezoneOffset](format/enum.Fixed.html#variant.TimezoneOffset).
+    NoColon,
+
+    /// Use `+hh:mm:ss`, including the seconds that the other styles discard.
+    Seconds,
+
+    // Do not match against this.
+    #[doc(hidden)]
+    __NonExhaustive,
+}
+
+impl OffsetStyle {
+    /// Returns the formatting item for this style,
+    /// or `None` for `Seconds` which has no corresponding `Fixed` item.
+    fn item(self) -> Option<Item<'static>> {
+        match self {
+            OffsetStyle::Z => Some(Item::Fixed(Fixed::TimezoneOffsetColonZ)),
+            OffsetStyle::Colon => Some(Item::Fixed(Fixed::TimezoneOffsetColon)),
+            OffsetStyle::NoColon => Some(Item::Fixed(Fixed::TimezoneOffset)),
+            OffsetStyle::Seconds => None,
+            OffsetStyle::__NonExhaustive => unreachable!(),
+        }
+    }
+}
+
+/// Formats the offset as `+hh:mm:ss` for `OffsetStyle::Seconds`.
+fn offset_with_seconds(offset: FixedOffset) -> String {
+    let off = offset.local_minus_utc();
+    let (sign, off) = if off < 0 { ('-', -off) } else { ('+', off) };
+    format!(
+        "{}{:02}:{:02}:{:02}",
+        sign,
+        off / 3600,
+        off / 60 % 60,
+        off % 60
+    )
+}
+
 /// ISO 8601 combined date and time with time zone.
 ///
 /// There are some constructors implemented here (the `from_*` methods), but
 /// the general-purpose constructors are all via the methods on the
 /// [`TimeZone`](./offset/trait.TimeZone.html) implementations.
+///
+/// Internally a `DateTime` is a pair of the date and time *in UTC*
+/// ([`naive_utc`](#method.naive_utc)) and the offset resolved for that instant
+/// ([`offset`](#method.offset)), exactly like [`Date`](./struct.Date.html).
+/// The UTC part alone determines the instant, so comparisons and hashing ignore the offset;
+/// the local date and time ([`naive_local`](#method.naive_local)) is always derived
+/// by applying the offset to the UTC part.
+/// Therefore `DateTime::from_utc(dt.naive_utc(), dt.offset().clone())` reconstructs `dt`,
+/// which is how a custom `TimeZone` implementation can build values
+/// without resolving the local date and time again.
 #[derive(Clone)]
 pub struct DateTime<Tz: TimeZone> {
     datetime: NaiveDateTime,
@@ -64,6 +131,8 @@ impl<Tz: TimeZone> DateTime<Tz> {
     /// Makes a new `DateTime` with given *UTC* datetime and offset.
     /// The local datetime should be constructed via the `TimeZone` trait.
     ///
+    /// This is same to [`from_naive_utc_and_offset`](#method.from_naive_utc_and_offset).
+    ///
     /// # Example
     ///
     /// ~~~~
@@ -76,6 +145,30 @@ impl<Tz: TimeZone> DateTime<Tz> {
     // note: this constructor is purposedly not named to `new` to discourage the direct usage.
     #[inline]
     pub fn from_utc(datetime: NaiveDateTime, offset: Tz::Offset) -> DateTime<Tz> {
+        DateTime::from_naive_utc_and_offset(datetime, offset)
+    }
+
+    /// Makes a new `DateTime` from its components:
+    /// a `NaiveDateTime` in *UTC* and the offset used for displaying it.
+    ///
+    /// The offset is not applied to `datetime`, which is taken as the UTC instant as is.
+    /// Use the [`TimeZone`](./offset/trait.TimeZone.html) methods like `from_local_datetime`
+    /// when you have a local date and time instead.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{DateTime, FixedOffset, NaiveDate, TimeZone};
+    ///
+    /// let utc = NaiveDate::from_ymd(2019, 12, 1).and_hms(3, 0, 0);
+    /// let offset = FixedOffset::east(9 * 3600);
+    /// let dt = DateTime::<FixedOffset>::from_naive_utc_and_offset(utc, offset);
+    /// assert_eq!(dt.naive_utc(), utc);
+    /// assert_eq!(dt.to_string(), "2019-12-01 12:00:00 +09:00");
+    /// assert_eq!(dt, offset.ymd(2019, 12, 1).and_hms(12, 0, 0));
+    /// ~~~~
+    #[inline]
+    pub fn from_naive_utc_and_offset(datetime: NaiveDateTime, offset: Tz::Offset) -> DateTime<Tz> {
         DateTime {
             datetime: datetime,
             offset: offset,
@@ -150,6 +243,28 @@ impl<Tz: TimeZone> DateTime<Tz> {
         self.datetime.timestamp_nanos()
     }
 
+    /// Returns the number of non-leap-nanoseconds since January 1, 1970 UTC,
+    /// or `None` when it doesn't fit in `i64`.
+    ///
+    /// The representable date and times are between 1677-09-21T00:12:43.145224192Z
+    /// and 2262-04-11T23:47:16.854775807Z (both inclusive).
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let dt = Utc.ymd(2001, 9, 9).and_hms_nano(1, 46, 40, 555);
+    /// assert_eq!(dt.timestamp_nanos_opt(), Some(1_000_000_000_000_000_555));
+    ///
+    /// assert_eq!(Utc.ymd(1677, 9, 21).and_hms(0, 12, 43).timestamp_nanos_opt(), None);
+    /// assert_eq!(Utc.ymd(2262, 4, 12).and_hms(0, 0, 0).timestamp_nanos_opt(), None);
+    /// ~~~~
+    #[inline]
+    pub fn timestamp_nanos_opt(&self) -> Option<i64> {
+        self.datetime.timestamp_nanos_opt()
+    }
+
     /// Returns the number of milliseconds since the last second boundary
     ///
     /// warning: in event of a leap second, this may exceed 999
@@ -199,6 +314,62 @@ impl<Tz: TimeZone> DateTime<Tz> {
         tz.from_utc_datetime(&self.datetime)
     }
 
+    /// Changes the associated time zone of all given `DateTime`s at once.
+    ///
+    /// This is the same as calling [`with_timezone`](#method.with_timezone) on each element.
+    /// For the time zones with a constant offset (`Utc` and `FixedOffset`)
+    /// each conversion only copies the UTC date and time along with the offset.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{DateTime, FixedOffset, TimeZone, Utc};
+    ///
+    /// let utc = [Utc.ymd(2019, 10, 3).and_hms(0, 0, 0), Utc.ymd(2019, 10, 3).and_hms(12, 0, 0)];
+    /// let kst = DateTime::convert_all(&utc, &FixedOffset::east(9 * 3600));
+    /// assert_eq!(kst[0].to_string(), "2019-10-03 09:00:00 +09:00");
+    /// assert_eq!(kst[1].to_string(), "2019-10-03 21:00:00 +09:00");
+    /// ~~~~
+    pub fn convert_all<Tz2: TimeZone>(datetimes: &[DateTime<Tz>], tz: &Tz2) -> Vec<DateTime<Tz2>> {
+        datetimes.iter().map(|dt| dt.with_timezone(tz)).collect()
+    }
+
+    /// Changes the associated time zone to a fixed offset
+    /// with given number of seconds east of UTC (negative for west).
+    /// This is a shortcut for `with_timezone(&FixedOffset::east(secs))`,
+    /// useful when the offset comes as a plain integer.
+    ///
+    /// Panics on the out-of-bound `secs`, see [`FixedOffset::east`].
+    ///
+    /// [`FixedOffset::east`]: ./offset/struct.FixedOffset.html#method.east
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{FixedOffset, TimeZone, Utc};
+    ///
+    /// let dt = Utc.ymd(2014, 11, 28).and_hms(12, 0, 9);
+    /// let fixed = dt.with_fixed_offset(-5 * 3600);
+    /// assert_eq!(fixed.offset(), &FixedOffset::west(5 * 3600));
+    /// assert_eq!(fixed.to_rfc3339(), "2014-11-28T07:00:09-05:00");
+    /// assert_eq!(fixed, dt);
+    /// ~~~~
+    #[inline]
+    pub fn with_fixed_offset(&self, secs: i32) -> DateTime<FixedOffset> {
+        self.with_timezone(&FixedOffset::east(secs))
+    }
+
+    /// Changes the associated time zone to a fixed offset
+    /// with given number of seconds east of UTC (negative for west).
+    ///
+    /// Returns `None` on the out-of-bound `secs`, see [`FixedOffset::east_opt`].
+    ///
+    /// [`FixedOffset::east_opt`]: ./offset/struct.FixedOffset.html#method.east_opt
+    #[inline]
+    pub fn with_fixed_offset_opt(&self, secs: i32) -> Option<DateTime<FixedOffset>> {
+        FixedOffset::east_opt(secs).map(|offset| self.with_timezone(&offset))
+    }
+
     /// Adds given `Duration` to the current date and time.
     ///
     /// Returns `None` when it will result in overflow.
@@ -219,28 +390,600 @@ impl<Tz: TimeZone> DateTime<Tz> {
         Some(tz.from_utc_datetime(&datetime))
     }
 
+    /// Makes a new `DateTime` with the year number changed, keeping the local time
+    /// and clamping the day of month to the last valid day of the resulting month.
+    ///
+    /// Unlike [`Datelike::with_year`](./trait.Datelike.html#tymethod.with_year)
+    /// this maps February 29 to February 28 in a common year.
+    /// Returns `None` when the resulting date would be out of range
+    /// or the resulting local date and time does not exist or is ambiguous.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{Datelike, FixedOffset, TimeZone};
+    ///
+    /// let dt = FixedOffset::east(9 * 3600).ymd(2016, 2, 29).and_hms(9, 10, 11);
+    /// assert_eq!(dt.with_year(2015), None);
+    /// assert_eq!(dt.with_year_clamped(2015).unwrap().to_rfc3339(), "2015-02-28T09:10:11+09:00");
+    /// ~~~~
+    #[inline]
+    pub fn with_year_clamped(&self, year: i32) -> Option<DateTime<Tz>> {
+        map_local(self, |datetime| {
+            datetime
+                .date()
+                .with_year_clamped(year)
+                .map(|date| date.and_time(datetime.time()))
+        })
+    }
+
+    /// Makes a new `DateTime` with the month number (starting from 1) changed, keeping the local time
+    /// and clamping the day of month to the last valid day of the resulting month.
+    ///
+    /// Returns `None` when `month` is invalid
+    /// or the resulting local date and time does not exist or is ambiguous.
+    /// See also [`NaiveDate::with_month_clamped`](./naive/struct.NaiveDate.html#method.with_month_clamped).
+    #[inline]
+    pub fn with_month_clamped(&self, month: u32) -> Option<DateTime<Tz>> {
+        map_local(self, |datetime| {
+            datetime
+                .date()
+                .with_month_clamped(month)
+                .map(|date| date.and_time(datetime.time()))
+        })
+    }
+
+    /// Adds given number of calendar months to the current local date,
+    /// keeping the local time and clamping the day of month to the last day of the resulting month.
+    ///
+    /// Returns `None` when the resulting date would be out of range
+    /// or the resulting local date and time does not exist or is ambiguous.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{FixedOffset, Months, TimeZone};
+    ///
+    /// let tz = FixedOffset::east(9 * 3600);
+    /// assert_eq!(tz.ymd(2020, 1, 31).and_hms(0, 30, 0).checked_add_months(Months(1)),
+    ///            Some(tz.ymd(2020, 2, 29).and_hms(0, 30, 0)));
+    /// ~~~~
+    #[inline]
+    pub fn checked_add_months(self, months: Months) -> Option<DateTime<Tz>> {
+        map_local(&self, |datetime| datetime.checked_add_months(months))
+    }
+
+    /// Subtracts given number of calendar months from the current local date,
+    /// keeping the local time and clamping the day of month to the last day of the resulting month.
+    ///
+    /// Returns `None` when the resulting date would be out of range
+    /// or the resulting local date and time does not exist or is ambiguous.
+    #[inline]
+    pub fn checked_sub_months(self, months: Months) -> Option<DateTime<Tz>> {
+        map_local(&self, |datetime| datetime.checked_sub_months(months))
+    }
+
+    /// Adds given number of days to the current local date, keeping the local time.
+    ///
+    /// This can differ from adding `Duration::days` when the offset changes in between.
+    /// Returns `None` when the resulting date would be out of range
+    /// or the resulting local date and time does not exist or is ambiguous.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{Days, FixedOffset, TimeZone};
+    ///
+    /// let tz = FixedOffset::east(9 * 3600);
+    /// assert_eq!(tz.ymd(2019, 12, 25).and_hms(0, 30, 0).checked_add_days(Days(10)),
+    ///            Some(tz.ymd(2020, 1, 4).and_hms(0, 30, 0)));
+    /// ~~~~
+    #[inline]
+    pub fn checked_add_days(self, days: Days) -> Option<DateTime<Tz>> {
+        map_local(&self, |datetime| datetime.checked_add_days(days))
+    }
+
+    /// Subtracts given number of days from the current local date, keeping the local time.
+    ///
+    /// This can differ from subtracting `Duration::days` when the offset changes in between.
+    /// Returns `None` when the resulting date would be out of range
+    /// or the resulting local date and time does not exist or is ambiguous.
+    #[inline]
+    pub fn checked_sub_days(self, days: Days) -> Option<DateTime<Tz>> {
+        map_local(&self, |datetime| datetime.checked_sub_days(days))
+    }
+
+    /// Moves the local time by given number of hours (which can be negative),
+    /// rolling the local date over midnight as needed and keeping the minute, second and nanosecond.
+    ///
+    /// This is a separate rolling operation from
+    /// [`Timelike::with_hour`](./trait.Timelike.html#tymethod.with_hour),
+    /// which only accepts an hour in the same day.
+    /// Like [`checked_add_days`](#method.checked_add_days) it moves the local time,
+    /// so it can differ from adding `Duration::hours` when the offset changes in between.
+    /// Returns `None` when the resulting date would be out of range
+    /// or the resulting local date and time does not exist or is ambiguous.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{FixedOffset, TimeZone, Timelike};
+    ///
+    /// let tz = FixedOffset::east(9 * 3600);
+    /// let dt = tz.ymd(2019, 12, 31).and_hms(22, 30, 0);
+    /// assert_eq!(dt.with_hour(25), None);
+    /// assert_eq!(dt.add_hours_wrapping(3), Some(tz.ymd(2020, 1, 1).and_hms(1, 30, 0)));
+    /// assert_eq!(dt.add_hours_wrapping(-23), Some(tz.ymd(2019, 12, 30).and_hms(23, 30, 0)));
+    /// ~~~~
+    #[inline]
+    pub fn add_hours_wrapping(&self, hours: i64) -> Option<DateTime<Tz>> {
+        map_local(self, |datetime| {
+            OldDuration::hours(1)
+                .checked_mul(hours)
+                .and_then(|d| datetime.checked_add_signed(d))
+        })
+    }
+
+    /// Moves the local time by given number of minutes (which can be negative),
+    /// rolling the local hour and date as needed and keeping the second and nanosecond.
+    ///
+    /// See [`add_hours_wrapping`](#method.add_hours_wrapping) for details.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{FixedOffset, TimeZone};
+    ///
+    /// let tz = FixedOffset::east(9 * 3600);
+    /// let dt = tz.ymd(2019, 12, 31).and_hms(23, 50, 10);
+    /// assert_eq!(dt.add_minutes_wrapping(15), Some(tz.ymd(2020, 1, 1).and_hms(0, 5, 10)));
+    /// ~~~~
+    #[inline]
+    pub fn add_minutes_wrapping(&self, minutes: i64) -> Option<DateTime<Tz>> {
+        map_local(self, |datetime| {
+            OldDuration::minutes(1)
+                .checked_mul(minutes)
+                .and_then(|d| datetime.checked_add_signed(d))
+        })
+    }
+
+    /// Rounds the instant down to a multiple of `period` since the UNIX epoch
+    /// (1970-01-01 00:00:00 UTC), keeping the offset.
+    ///
+    /// The rounding is done on the UTC instant, so for example rounding to a day
+    /// gives the midnight in UTC rather than in the local time.
+    /// A leap second is treated as if it coincides with the preceding second.
+    ///
+    /// Panics when `period` is zero or negative, or the result would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// # extern crate chrono; extern crate time; fn main() {
+    /// use chrono::{FixedOffset, TimeZone};
+    /// use time::Duration;
+    ///
+    /// let tz = FixedOffset::east(5 * 3600 + 30 * 60);
+    /// let dt = tz.ymd(2019, 12, 1).and_hms_milli(12, 34, 56, 789);
+    /// assert_eq!(dt.floor_to(Duration::minutes(15)), tz.ymd(2019, 12, 1).and_hms(12, 30, 0));
+    /// assert_eq!(dt.floor_to(Duration::hours(1)), tz.ymd(2019, 12, 1).and_hms(12, 30, 0));
+    /// assert_eq!(dt.floor_to(Duration::seconds(1)), tz.ymd(2019, 12, 1).and_hms(12, 34, 56));
+    /// # }
+    /// ~~~~
+    pub fn floor_to(&self, period: OldDuration) -> DateTime<Tz> {
+        let (dt, rem) = self.rem_of_period(period);
+        dt - rem
+    }
+
+    /// Rounds the instant up to a multiple of `period` since the UNIX epoch
+    /// (1970-01-01 00:00:00 UTC), keeping the offset.
+    ///
+    /// The rounding is done on the UTC instant, as in [`floor_to`](#method.floor_to).
+    /// A leap second is treated as if it coincides with the preceding second.
+    ///
+    /// Panics when `period` is zero or negative, or the result would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// # extern crate chrono; extern crate time; fn main() {
+    /// use chrono::{TimeZone, Utc};
+    /// use time::Duration;
+    ///
+    /// let dt = Utc.ymd(2019, 12, 1).and_hms(12, 34, 56);
+    /// assert_eq!(dt.ceil_to(Duration::minutes(15)), Utc.ymd(2019, 12, 1).and_hms(12, 45, 0));
+    /// assert_eq!(dt.ceil_to(Duration::seconds(1)), dt);
+    /// # }
+    /// ~~~~
+    pub fn ceil_to(&self, period: OldDuration) -> DateTime<Tz> {
+        let (dt, rem) = self.rem_of_period(period);
+        if rem.is_zero() {
+            dt
+        } else {
+            dt + (period - rem)
+        }
+    }
+
+    /// Rounds the instant to the nearest multiple of `period` since the UNIX epoch
+    /// (1970-01-01 00:00:00 UTC), keeping the offset.
+    /// Halfway values are rounded up (to the later instant).
+    ///
+    /// The rounding is done on the UTC instant, as in [`floor_to`](#method.floor_to).
+    /// A leap second is treated as if it coincides with the preceding second.
+    ///
+    /// Panics when `period` is zero or negative, or the result would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// # extern crate chrono; extern crate time; fn main() {
+    /// use chrono::{TimeZone, Utc};
+    /// use time::Duration;
+    ///
+    /// let hms = |h, m, s| Utc.ymd(2019, 12, 1).and_hms(h, m, s);
+    /// assert_eq!(hms(12, 7, 29).round_to(Duration::minutes(15)), hms(12, 0, 0));
+    /// assert_eq!(hms(12, 7, 30).round_to(Duration::minutes(15)), hms(12, 15, 0));
+    /// assert_eq!(hms(12, 52, 30).round_to(Duration::minutes(15)), hms(13, 0, 0));
+    /// # }
+    /// ~~~~
+    pub fn round_to(&self, period: OldDuration) -> DateTime<Tz> {
+        let (dt, rem) = self.rem_of_period(period);
+        let up = period - rem;
+        if rem.is_zero() {
+            dt
+        } else if up <= rem {
+            dt + up
+        } else {
+            dt - rem
+        }
+    }
+
+    /// Returns the instant with any leap second folded into the preceding second,
+    /// and how far it is past the last multiple of `period` since the UNIX epoch.
+    fn rem_of_period(&self, period: OldDuration) -> (DateTime<Tz>, OldDuration) {
+        assert!(
+            period > OldDuration::zero(),
+            "rounding `DateTime` to non-positive duration"
+        );
+        let secs = self.datetime.timestamp();
+        let nanos = self.datetime.timestamp_subsec_nanos() % 1_000_000_000;
+        let folded = self.datetime.with_nanosecond(nanos).unwrap();
+        let nanos = i64::from(nanos);
+        let rem = match period.num_nanoseconds() {
+            Some(period) => {
+                // `secs * 10^9 + nanos` may overflow, so work in the modular arithmetic
+                let secs = mod_floor(secs, period) as u64;
+                let rem = mul_mod(secs, 1_000_000_000, period as u64) + nanos as u64;
+                OldDuration::nanoseconds((rem % period as u64) as i64)
+            }
+            None => {
+                // longer than about 292 years; any fraction of a second in `period` is ignored
+                let secs = mod_floor(secs, period.num_seconds());
+                OldDuration::seconds(secs) + OldDuration::nanoseconds(nanos)
+            }
+        };
+        (DateTime::from_utc(folded, self.offset.clone()), rem)
+    }
+
+    /// Returns the number of whole years elapsed from `since` to the current date and time,
+    /// e.g. the age now of someone born at `since`.
+    /// Both are taken as local dates and times in their own time zones,
+    /// so the anniversary happens at the same local time in the time zone of the observer.
+    ///
+    /// The time of day is taken into account:
+    /// a year is not complete until the local time of `since` on the anniversary.
+    /// Use [`years_since_date_only`](#method.years_since_date_only) to count from
+    /// the start of the anniversary instead.
+    ///
+    /// Returns `None` when `since` is later than the current date and time.
+    /// Same to [`NaiveDate::years_since`](./naive/struct.NaiveDate.html#method.years_since),
+    /// the anniversary of February 29 is February 28 in common years.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{FixedOffset, TimeZone, Utc};
+    ///
+    /// let birth = Utc.ymd(1980, 12, 25).and_hms(18, 0, 0);
+    /// let tz = FixedOffset::east(9 * 3600);
+    /// assert_eq!(tz.ymd(2019, 12, 25).and_hms(17, 59, 59).years_since(birth), Some(38));
+    /// assert_eq!(tz.ymd(2019, 12, 25).and_hms(18, 0, 0).years_since(birth), Some(39));
+    /// ~~~~
+    #[inline]
+    pub fn years_since<Tz2: TimeZone>(&self, since: DateTime<Tz2>) -> Option<u32> {
+        self.months_since(since).map(|months| months / 12)
+    }
+
+    /// Returns the number of whole months elapsed from `since` to the current date and time.
+    /// Both are taken as local dates and times in their own time zones.
+    ///
+    /// The time of day is taken into account:
+    /// a month is not complete until the local time of `since` on the same day of month.
+    /// Use [`months_since_date_only`](#method.months_since_date_only) to count from
+    /// the start of that day instead.
+    ///
+    /// Returns `None` when `since` is later than the current date and time.
+    /// When the day of month of `since` does not exist in a shorter month,
+    /// the month is complete on the last day of that month
+    /// (see [`Months`](./struct.Months.html)).
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let hired = Utc.ymd(2019, 1, 31).and_hms(9, 0, 0);
+    /// assert_eq!(Utc.ymd(2019, 2, 28).and_hms(8, 59, 59).months_since(hired), Some(0));
+    /// assert_eq!(Utc.ymd(2019, 2, 28).and_hms(9, 0, 0).months_since(hired), Some(1));
+    /// assert_eq!(Utc.ymd(2020, 3, 31).and_hms(9, 0, 0).months_since(hired), Some(14));
+    /// assert_eq!(Utc.ymd(2019, 1, 31).and_hms(8, 0, 0).months_since(hired), None);
+    /// ~~~~
+    #[cfg_attr(feature = "cargo-clippy", allow(needless_pass_by_value))]
+    pub fn months_since<Tz2: TimeZone>(&self, since: DateTime<Tz2>) -> Option<u32> {
+        let from = since.naive_local();
+        let to = self.naive_local();
+        if to < from {
+            return None;
+        }
+        let age = try_opt!(from.date().age_at(to.date()));
+        let months = age.years * 12 + age.months;
+        // `months` is never zero here, since `to` is not earlier than `from`
+        if age.days == 0 && to.time() < from.time() {
+            Some(months - 1)
+        } else {
+            Some(months)
+        }
+    }
+
+    /// Returns the number of whole years elapsed from the date of `since`
+    /// to the current date, ignoring the time of day.
+    /// Both are taken as local dates in their own time zones.
+    ///
+    /// Unlike [`years_since`](#method.years_since), a year is complete
+    /// at the start of the anniversary.
+    /// Returns `None` when the date of `since` is later than the current date.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let birth = Utc.ymd(1980, 12, 25).and_hms(18, 0, 0);
+    /// let now = Utc.ymd(2019, 12, 25).and_hms(0, 0, 0);
+    /// assert_eq!(now.years_since(birth), Some(38));
+    /// assert_eq!(now.years_since_date_only(birth), Some(39));
+    /// ~~~~
+    #[cfg_attr(feature = "cargo-clippy", allow(needless_pass_by_value))]
+    #[inline]
+    pub fn years_since_date_only<Tz2: TimeZone>(&self, since: DateTime<Tz2>) -> Option<u32> {
+        self.naive_local()
+            .date()
+            .years_since(since.naive_local().date())
+    }
+
+    /// Returns the number of whole months elapsed from the date of `since`
+    /// to the current date, ignoring the time of day.
+    /// Both are taken as local dates in their own time zones.
+    ///
+    /// Unlike [`months_since`](#method.months_since), a month is complete
+    /// at the start of the same day of month (or the last day of a shorter month).
+    /// Returns `None` when the date of `since` is later than the current date.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let hired = Utc.ymd(2019, 1, 31).and_hms(9, 0, 0);
+    /// let now = Utc.ymd(2019, 2, 28).and_hms(0, 0, 0);
+    /// assert_eq!(now.months_since(hired), Some(0));
+    /// assert_eq!(now.months_since_date_only(hired), Some(1));
+    /// ~~~~
+    #[cfg_attr(feature = "cargo-clippy", allow(needless_pass_by_value))]
+    pub fn months_since_date_only<Tz2: TimeZone>(&self, since: DateTime<Tz2>) -> Option<u32> {
+        let age = try_opt!(since.naive_local().date().age_at(self.naive_local().date()));
+        Some(age.years * 12 + age.months)
+    }
+
     /// Subtracts another `DateTime` from the current date and time.
     /// This does not overflow or underflow at all.
+    ///
+    /// The result is negative when the current date and time is earlier than `rhs`.
+    /// Both are compared as UTC instants, so they may be in different time zones.
+    /// Same to `self - rhs` when both are in the same time zone.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// # extern crate chrono; extern crate time; fn main() {
+    /// use chrono::{FixedOffset, TimeZone, Utc};
+    /// use time::Duration;
+    ///
+    /// let dt = Utc.ymd(2014, 5, 6).and_hms(7, 8, 9);
+    /// let kst = FixedOffset::east(9 * 3600).ymd(2014, 5, 6).and_hms(17, 8, 9);
+    /// assert_eq!(kst.signed_duration_since(dt), Duration::hours(1));
+    /// assert_eq!(dt.signed_duration_since(kst), Duration::hours(-1));
+    /// assert_eq!(dt - Utc.ymd(2014, 5, 6).and_hms(8, 8, 9), Duration::hours(-1));
+    /// # }
+    /// ~~~~
     #[cfg_attr(feature = "cargo-clippy", allow(needless_pass_by_value))]
     #[inline]
     pub fn signed_duration_since<Tz2: TimeZone>(self, rhs: DateTime<Tz2>) -> OldDuration {
         self.datetime.signed_duration_since(rhs.datetime)
     }
 
+    /// Returns the time elapsed from the current date and time until now.
+    ///
+    /// Returns `Err` with the remaining time instead
+    /// when the current date and time is in the future.
+    /// Both are compared as UTC instants, so the offset doesn't matter.
+    /// Use [`elapsed_with_clock`](#method.elapsed_with_clock) to make it testable.
+    #[cfg(feature = "clock")]
+    #[inline]
+    pub fn elapsed_since_now(&self) -> Result<OldDuration, OldDuration> {
+        self.elapsed_with_clock(&SystemClock)
+    }
+
+    /// Returns the time elapsed from the current date and time until now, as given by `clock`.
+    ///
+    /// Returns `Err` with the remaining time instead
+    /// when the current date and time is in the future.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// # extern crate chrono; extern crate time; fn main() {
+    /// use chrono::{FixedClock, FixedOffset, TimeZone, Utc};
+    /// use time::Duration;
+    ///
+    /// let clock = FixedClock(Utc.ymd(2019, 12, 25).and_hms(9, 0, 0));
+    /// let tz = FixedOffset::east(9 * 3600);
+    /// assert_eq!(tz.ymd(2019, 12, 25).and_hms(17, 30, 0).elapsed_with_clock(&clock),
+    ///            Ok(Duration::minutes(30)));
+    /// assert_eq!(tz.ymd(2019, 12, 25).and_hms(19, 0, 0).elapsed_with_clock(&clock),
+    ///            Err(Duration::hours(1)));
+    /// # }
+    /// ~~~~
+    pub fn elapsed_with_clock<C: Clock>(&self, clock: &C) -> Result<OldDuration, OldDuration> {
+        let elapsed = clock.now().naive_utc().signed_duration_since(self.datetime);
+        if elapsed < OldDuration::zero() {
+            Err(-elapsed)
+        } else {
+            Ok(elapsed)
+        }
+    }
+
+    /// Describes the current date and time relative to `now` in English,
+    /// like `3 minutes ago` or `in 2 hours`.
+    ///
+    /// The difference is truncated to the largest fitting unit:
+    ///
+    /// - less than 1 second: `now`
+    /// - less than 1 minute: in seconds
+    /// - less than 1 hour: in minutes
+    /// - less than 1 day (24 hours): in hours
+    /// - less than 1 week (7 days): in days
+    /// - less than 30 days: in weeks
+    /// - less than 365 days: in months of 30 days
+    /// - otherwise: in years of 365 days
+    ///
+    /// So 89 seconds is `1 minute` and 364 days is `12 months`.
+    /// The calendar and leap seconds are not considered.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let now = Utc.ymd(2019, 12, 1).and_hms(12, 0, 0);
+    /// assert_eq!(Utc.ymd(2019, 12, 1).and_hms(11, 57, 0).humanize_from(now), "3 minutes ago");
+    /// assert_eq!(Utc.ymd(2019, 12, 1).and_hms(14, 30, 0).humanize_from(now), "in 2 hours");
+    /// assert_eq!(Utc.ymd(2019, 11, 30).and_hms(12, 0, 0).humanize_from(now), "1 day ago");
+    /// assert_eq!(now.humanize_from(now), "now");
+    /// ~~~~
+    #[cfg_attr(feature = "cargo-clippy", allow(needless_pass_by_value))]
+    pub fn humanize_from<Tz2: TimeZone>(&self, now: DateTime<Tz2>) -> String {
+        const UNITS: [(i64, &'static str); 7] = [
+            (365 * 86_400, "year"),
+            (30 * 86_400, "month"),
+            (7 * 86_400, "week"),
+            (86_400, "day"),
+            (3_600, "hour"),
+            (60, "minute"),
+            (1, "second"),
+        ];
+
+        let secs = self
+            .datetime
+            .signed_duration_since(now.datetime)
+            .num_seconds();
+        let abs = secs.abs();
+        for &(unit_secs, unit) in &UNITS {
+            if abs >= unit_secs {
+                let n = abs / unit_secs;
+                let plural = if n == 1 { "" } else { "s" };
+                return if secs < 0 {
+                    format!("{} {}{} ago", n, unit, plural)
+                } else {
+                    format!("in {} {}{}", n, unit, plural)
+                };
+            }
+        }
+        "now".to_owned()
+    }
+
     /// Returns a view to the naive UTC datetime.
+    ///
+    /// This is the value stored in the `DateTime`,
+    /// and together with [`offset`](#method.offset) can be passed to
+    /// [`from_utc`](#method.from_utc) to reconstruct the same `DateTime`.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{DateTime, FixedOffset, NaiveDate, TimeZone};
+    ///
+    /// let dt = FixedOffset::east(9 * 3600).ymd(2015, 9, 5).and_hms(1, 2, 3);
+    /// assert_eq!(dt.naive_utc(), NaiveDate::from_ymd(2015, 9, 4).and_hms(16, 2, 3));
+    /// assert_eq!(dt.naive_local(), NaiveDate::from_ymd(2015, 9, 5).and_hms(1, 2, 3));
+    ///
+    /// let rebuilt = DateTime::<FixedOffset>::from_utc(dt.naive_utc(), *dt.offset());
+    /// assert_eq!(rebuilt, dt);
+    /// assert_eq!(rebuilt.naive_local(), dt.naive_local());
+    /// ~~~~
     #[inline]
     pub fn naive_utc(&self) -> NaiveDateTime {
         self.datetime
     }
 
     /// Returns a view to the naive local datetime.
+    ///
+    /// This is computed from [`naive_utc`](#method.naive_utc)
+    /// and the numeric value of [`offset`](#method.offset).
     #[inline]
     pub fn naive_local(&self) -> NaiveDateTime {
         self.datetime + self.offset.fix()
     }
+
+    /// Returns `true` when both the instant and the numeric offset from UTC are equal.
+    ///
+    /// `==` only compares the instants, so the same instant in different offsets
+    /// compares equal; this is stricter and useful for e.g. snapshot tests.
+    /// Note that `Hash` is consistent with `==` and not with `exact_eq`.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{FixedOffset, TimeZone, Utc};
+    ///
+    /// let utc = Utc.ymd(2019, 12, 25).and_hms(10, 0, 0);
+    /// let cet = FixedOffset::east(3600).ymd(2019, 12, 25).and_hms(11, 0, 0);
+    /// assert_eq!(utc, cet);
+    /// assert!(!utc.exact_eq(&cet));
+    /// assert!(utc.exact_eq(&utc.with_timezone(&FixedOffset::east(0))));
+    /// ~~~~
+    #[inline]
+    pub fn exact_eq<Tz2: TimeZone>(&self, other: &DateTime<Tz2>) -> bool {
+        self.datetime == other.datetime
+            && self.offset.fix().local_minus_utc() == other.offset.fix().local_minus_utc()
+    }
 }
 
 /// Convert a `DateTime<Utc>` instance into a `DateTime<FixedOffset>` instance.
+///
+/// This is a lossless promotion to the more general type with the offset `+00:00`,
+/// which is handy for storing timestamps uniformly as `DateTime<FixedOffset>`.
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::{DateTime, FixedOffset, TimeZone, Utc};
+///
+/// let utc = vec![Utc.ymd(2019, 10, 3).and_hms(1, 2, 3), Utc.timestamp(0, 0)];
+/// let fixed: Vec<DateTime<FixedOffset>> = utc.into_iter().map(DateTime::from).collect();
+/// assert_eq!(fixed[0].to_rfc3339(), "2019-10-03T01:02:03+00:00");
+/// assert_eq!(fixed[1].offset(), &FixedOffset::east(0));
+/// ~~~~
 impl From<DateTime<Utc>> for DateTime<FixedOffset> {
     /// Convert this `DateTime<Utc>` instance into a `DateTime<FixedOffset>` instance.
     ///
@@ -309,6 +1052,85 @@ impl From<DateTime<Local>> for DateTime<FixedOffset> {
     }
 }
 
+/// Convert a `DateTime<Utc>` instance into a `DateTime<AnyTimeZone>` instance.
+impl From<DateTime<Utc>> for DateTime<AnyTimeZone> {
+    /// Convert this `DateTime<Utc>` instance into a `DateTime<AnyTimeZone>` instance.
+    ///
+    /// The resulting time zone is [`AnyTimeZone::Utc`].
+    fn from(src: DateTime<Utc>) -> Self {
+        DateTime::from_utc(src.datetime, AnyOffset::Utc)
+    }
+}
+
+/// Convert a `DateTime<FixedOffset>` instance into a `DateTime<AnyTimeZone>` instance.
+impl From<DateTime<FixedOffset>> for DateTime<AnyTimeZone> {
+    /// Convert this `DateTime<FixedOffset>` instance into a `DateTime<AnyTimeZone>` instance.
+    ///
+    /// The resulting time zone is [`AnyTimeZone::Fixed`] with the same offset.
+    fn from(src: DateTime<FixedOffset>) -> Self {
+        DateTime::from_utc(src.datetime, AnyOffset::Fixed(src.offset))
+    }
+}
+
+/// Convert a `DateTime<Local>` instance into a `DateTime<AnyTimeZone>` instance.
+#[cfg(feature = "clock")]
+impl From<DateTime<Local>> for DateTime<AnyTimeZone> {
+    /// Convert this `DateTime<Local>` instance into a `DateTime<AnyTimeZone>` instance.
+    ///
+    /// The resulting time zone is [`AnyTimeZone::Local`].
+    fn from(src: DateTime<Local>) -> Self {
+        DateTime::from_utc(src.datetime, AnyOffset::Local(src.offset))
+    }
+}
+
+/// Convert a `DateTime<AnyTimeZone>` instance into a `DateTime<FixedOffset>` instance.
+impl From<DateTime<AnyTimeZone>> for DateTime<FixedOffset> {
+    /// Convert this `DateTime<AnyTimeZone>` instance into a `DateTime<FixedOffset>` instance.
+    ///
+    /// The current offset is kept, so the local date and time is unchanged.
+    fn from(src: DateTime<AnyTimeZone>) -> Self {
+        DateTime::from_utc(src.datetime, src.offset.fix())
+    }
+}
+
+/// Convert a `DateTime` instance into a `NaiveDateTime` instance.
+impl<Tz: TimeZone> From<DateTime<Tz>> for NaiveDateTime {
+    /// Convert this `DateTime` instance into a `NaiveDateTime` instance.
+    ///
+    /// The result is the UTC date and time as in
+    /// [`DateTime::naive_utc`](./struct.DateTime.html#method.naive_utc),
+    /// not the local date and time; use
+    /// [`DateTime::naive_local`](./struct.DateTime.html#method.naive_local) for the latter.
+    fn from(src: DateTime<Tz>) -> Self {
+        src.naive_utc()
+    }
+}
+
+/// Convert a `NaiveDateTime` instance into a `DateTime<Utc>` instance.
+impl From<NaiveDateTime> for DateTime<Utc> {
+    /// Convert this `NaiveDateTime` instance into a `DateTime<Utc>` instance.
+    ///
+    /// The naive date and time is taken as UTC, as in
+    /// [`TimeZone::from_utc_datetime`](./offset/trait.TimeZone.html#method.from_utc_datetime).
+    fn from(src: NaiveDateTime) -> Self {
+        Utc.from_utc_datetime(&src)
+    }
+}
+
+/// Returns `a * b % m` without overflow, given `a < m` and `m <= 2^63`.
+fn mul_mod(a: u64, mut b: u64, m: u64) -> u64 {
+    let mut a = a;
+    let mut result = 0;
+    while b > 0 {
+        if b & 1 == 1 {
+            result = (result + a) % m;
+        }
+        a = (a << 1) % m;
+        b >>= 1;
+    }
+    result
+}
+
 /// Maps the local datetime to other datetime with given conversion function.
 fn map_local<Tz: TimeZone, F>(dt: &DateTime<Tz>, mut f: F) -> Option<DateTime<Tz>>
 where
@@ -317,6 +1139,59 @@ where
     f(dt.naive_local()).and_then(|datetime| dt.timezone().from_local_datetime(&datetime).single())
 }
 
+impl DateTime<Utc> {
+    /// Returns the earliest representable date and time in UTC.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{DateTime, NaiveDateTime, Utc};
+    ///
+    /// assert_eq!(DateTime::<Utc>::min_value().naive_utc(), NaiveDateTime::min_value());
+    /// ~~~~
+    #[inline]
+    pub fn min_value() -> DateTime<Utc> {
+        DateTime::from_utc(NaiveDateTime::min_value(), Utc)
+    }
+
+    /// Returns the latest representable date and time in UTC.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{DateTime, NaiveDateTime, Utc};
+    ///
+    /// assert_eq!(DateTime::<Utc>::max_value().naive_utc(), NaiveDateTime::max_value());
+    /// ~~~~
+    #[inline]
+    pub fn max_value() -> DateTime<Utc> {
+        DateTime::from_utc(NaiveDateTime::max_value(), Utc)
+    }
+}
+
+#[cfg(feature = "clock")]
+impl DateTime<Utc> {
+    /// Returns the time elapsed from the current date and time until now.
+    ///
+    /// Returns `Err` with the remaining time instead
+    /// when the current date and time is in the future.
+    /// This is same to [`elapsed_since_now`](#method.elapsed_since_now),
+    /// which is available for any time zone.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::Utc;
+    ///
+    /// let start = Utc::now();
+    /// assert!(start.elapsed().is_ok());
+    /// ~~~~
+    #[inline]
+    pub fn elapsed(&self) -> Result<OldDuration, OldDuration> {
+        self.elapsed_since_now()
+    }
+}
+
 impl DateTime<FixedOffset> {
     /// Parses an RFC 2822 date and time string such as `Tue, 1 Jul 2003 10:52:37 +0200`,
     /// then returns a new `DateTime` with a parsed `FixedOffset`.
@@ -389,6 +1264,9 @@ where
     /// If passed `use_z` false, use
     /// [Fixed::TimezoneOffsetColon](format/enum.Fixed.html#variant.TimezoneOffsetColon).
     ///
+    /// This is same to [`to_rfc3339_with_offset_style`](#method.to_rfc3339_with_offset_style)
+    /// with `OffsetStyle::Z` or `OffsetStyle::Colon`.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -407,11 +1285,45 @@ where
     ///            "2018-01-26T10:30:09+08:00");
     /// ```
     pub fn to_rfc3339_opts(&self, secform: SecondsFormat, use_z: bool) -> String {
+        let style = if use_z {
+            OffsetStyle::Z
+        } else {
+            OffsetStyle::Colon
+        };
+        self.to_rfc3339_with_offset_style(secform, style)
+    }
+
+    /// Return an RFC 3339 and ISO 8601 date and time string with subseconds
+    /// formatted as per a `SecondsFormat` and the offset formatted in the given style.
+    ///
+    /// Note that `OffsetStyle::NoColon` and `OffsetStyle::Seconds` are valid in ISO 8601
+    /// but not in RFC 3339.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{FixedOffset, OffsetStyle, SecondsFormat, TimeZone};
+    ///
+    /// let dt = FixedOffset::east(5 * 3600 + 45 * 60).ymd(2018, 1, 26).and_hms_milli(18, 30, 9, 453);
+    /// assert_eq!(dt.to_rfc3339_with_offset_style(SecondsFormat::Millis, OffsetStyle::NoColon),
+    ///            "2018-01-26T18:30:09.453+0545");
+    /// assert_eq!(dt.to_rfc3339_with_offset_style(SecondsFormat::Secs, OffsetStyle::Seconds),
+    ///            "2018-01-26T18:30:09+05:45:00");
+    /// ~~~~
+    pub fn to_rfc3339_with_offset_style(
+        &self,
+        secform: SecondsFormat,
+        style: OffsetStyle,
+    ) -> String {
         use format::Numeric::*;
         use format::Pad::Zero;
         use SecondsFormat::*;
 
         debug_assert!(secform != __NonExhaustive, "Do not use __NonExhaustive!");
+        debug_assert!(
+            style != OffsetStyle::__NonExhaustive,
+            "Do not use __NonExhaustive!"
+        );
 
         const PREFIX: &'static [Item<'static>] = &[
             Item::Numeric(Year, Zero),
@@ -436,19 +1348,53 @@ where
             __NonExhaustive => unreachable!(),
         };
 
-        let tzitem = Item::Fixed(if use_z {
-            Fixed::TimezoneOffsetColonZ
-        } else {
-            Fixed::TimezoneOffsetColon
-        });
+        let tzitem = style.item();
+        let with_seconds = tzitem.is_none();
+        let items = PREFIX.iter().cloned().chain(ssitem).chain(tzitem);
+        let mut result = self.format_with_items(items).to_string();
+        if with_seconds {
+            result.push_str(&offset_with_seconds(self.offset.fix()));
+        }
+        result
+    }
 
-        match ssitem {
-            None => self
-                .format_with_items(PREFIX.iter().chain([tzitem].iter()).cloned())
-                .to_string(),
-            Some(s) => self
-                .format_with_items(PREFIX.iter().chain([s, tzitem].iter()).cloned())
-                .to_string(),
+    /// Returns the same string as the `Display` output,
+    /// but with the offset formatted in the given style for every time zone.
+    ///
+    /// This is useful when the consumer expects a particular form of the offset,
+    /// since the `Display` output prints `UTC` for [`Utc`](./offset/struct.Utc.html)
+    /// and `+00:00` for the zero [`FixedOffset`](./offset/struct.FixedOffset.html).
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{FixedOffset, OffsetStyle, TimeZone, Utc};
+    ///
+    /// let dt = Utc.ymd(2015, 6, 30).and_hms(23, 56, 4);
+    /// assert_eq!(dt.to_string(), "2015-06-30 23:56:04 UTC");
+    /// assert_eq!(dt.to_string_with_offset_style(OffsetStyle::Z), "2015-06-30 23:56:04 Z");
+    /// assert_eq!(dt.to_string_with_offset_style(OffsetStyle::Colon), "2015-06-30 23:56:04 +00:00");
+    ///
+    /// let dt = FixedOffset::west(5 * 3600 + 30 * 60).ymd(2015, 6, 30).and_hms(23, 56, 4);
+    /// assert_eq!(dt.to_string_with_offset_style(OffsetStyle::Z), "2015-06-30 23:56:04 -05:30");
+    /// assert_eq!(dt.to_string_with_offset_style(OffsetStyle::NoColon), "2015-06-30 23:56:04 -0530");
+    /// assert_eq!(dt.to_string_with_offset_style(OffsetStyle::Seconds), "2015-06-30 23:56:04 -05:30:00");
+    /// ~~~~
+    pub fn to_string_with_offset_style(&self, style: OffsetStyle) -> String {
+        debug_assert!(
+            style != OffsetStyle::__NonExhaustive,
+            "Do not use __NonExhaustive!"
+        );
+        match style.item() {
+            Some(item) => {
+                let offset = self.format_with_items(Some(item).into_iter());
+                format!("{} {}", self.naive_local(), offset)
+            }
+            None => format!(
+                "{} {}",
+                self.naive_local(),
+                offset_with_seconds(self.offset.fix())
+            ),
         }
     }
 
@@ -608,6 +1554,47 @@ impl<Tz: TimeZone> Ord for DateTime<Tz> {
     }
 }
 
+/// Compares a `DateTime<Utc>` with a `NaiveDateTime`,
+/// **treating the naive date and time as UTC**.
+///
+/// Only `DateTime<Utc>` can be compared this way; convert other date and times with
+/// [`with_timezone`](#method.with_timezone) or compare their
+/// [`naive_utc`](#method.naive_utc) values instead.
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::{NaiveDate, TimeZone, Utc};
+///
+/// let naive = NaiveDate::from_ymd(2019, 12, 31).and_hms(15, 0, 0);
+/// assert_eq!(Utc.ymd(2019, 12, 31).and_hms(15, 0, 0), naive);
+/// assert!(Utc.ymd(2019, 12, 31).and_hms(14, 0, 0) < naive);
+/// assert!(naive > Utc.ymd(2019, 12, 31).and_hms(14, 0, 0));
+/// ~~~~
+impl PartialEq<NaiveDateTime> for DateTime<Utc> {
+    fn eq(&self, other: &NaiveDateTime) -> bool {
+        self.datetime == *other
+    }
+}
+
+impl PartialEq<DateTime<Utc>> for NaiveDateTime {
+    fn eq(&self, other: &DateTime<Utc>) -> bool {
+        *self == other.datetime
+    }
+}
+
+impl PartialOrd<NaiveDateTime> for DateTime<Utc> {
+    fn partial_cmp(&self, other: &NaiveDateTime) -> Option<Ordering> {
+        self.datetime.partial_cmp(other)
+    }
+}
+
+impl PartialOrd<DateTime<Utc>> for NaiveDateTime {
+    fn partial_cmp(&self, other: &DateTime<Utc>) -> Option<Ordering> {
+        self.partial_cmp(&other.datetime)
+    }
+}
+
 impl<Tz: TimeZone> hash::Hash for DateTime<Tz> {
     fn hash<H: hash::Hasher>(&self, state: &mut H) {
         self.datetime.hash(state)
@@ -624,6 +1611,13 @@ impl<Tz: TimeZone> Add<OldDuration> for DateTime<Tz> {
     }
 }
 
+impl<Tz: TimeZone> AddAssign<OldDuration> for DateTime<Tz> {
+    #[inline]
+    fn add_assign(&mut self, rhs: OldDuration) {
+        *self = self.clone().add(rhs);
+    }
+}
+
 impl<Tz: TimeZone> Sub<OldDuration> for DateTime<Tz> {
     type Output = DateTime<Tz>;
 
@@ -634,6 +1628,53 @@ impl<Tz: TimeZone> Sub<OldDuration> for DateTime<Tz> {
     }
 }
 
+impl<Tz: TimeZone> SubAssign<OldDuration> for DateTime<Tz> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: OldDuration) {
+        *self = self.clone().sub(rhs);
+    }
+}
+
+impl<Tz: TimeZone> Add<Months> for DateTime<Tz> {
+    type Output = DateTime<Tz>;
+
+    #[inline]
+    fn add(self, rhs: Months) -> DateTime<Tz> {
+        self.checked_add_months(rhs)
+            .expect("`DateTime + Months` out of range")
+    }
+}
+
+impl<Tz: TimeZone> Sub<Months> for DateTime<Tz> {
+    type Output = DateTime<Tz>;
+
+    #[inline]
+    fn sub(self, rhs: Months) -> DateTime<Tz> {
+        self.checked_sub_months(rhs)
+            .expect("`DateTime - Months` out of range")
+    }
+}
+
+impl<Tz: TimeZone> Add<Days> for DateTime<Tz> {
+    type Output = DateTime<Tz>;
+
+    #[inline]
+    fn add(self, rhs: Days) -> DateTime<Tz> {
+        self.checked_add_days(rhs)
+            .expect("`DateTime + Days` out of range")
+    }
+}
+
+impl<Tz: TimeZone> Sub<Days> for DateTime<Tz> {
+    type Output = DateTime<Tz>;
+
+    #[inline]
+    fn sub(self, rhs: Days) -> DateTime<Tz> {
+        self.checked_sub_days(rhs)
+            .expect("`DateTime - Days` out of range")
+    }
+}
+
 impl<Tz: TimeZone> Sub<DateTime<Tz>> for DateTime<Tz> {
     type Output = OldDuration;
 
@@ -643,12 +1684,64 @@ impl<Tz: TimeZone> Sub<DateTime<Tz>> for DateTime<Tz> {
     }
 }
 
+/// The `Debug` output is the ISO 8601 representation of the local date and time
+/// followed by the offset, e.g. `2014-05-06T07:08:09+09:00`.
+///
+/// The alternate form (`{:#?}`) spells out the UTC date and time,
+/// the local date and time and the resolved numeric offset on separate lines,
+/// which is useful for checking which offset the time zone has actually chosen.
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::{TimeZone, Utc};
+///
+/// let dt = Utc.ymd(2014, 5, 6).and_hms(7, 8, 9);
+/// assert_eq!(format!("{:?}", dt), "2014-05-06T07:08:09Z");
+/// assert_eq!(format!("{:#?}", dt), "DateTime {
+///     utc: 2014-05-06T07:08:09,
+///     local: 2014-05-06T07:08:09,
+///     offset: +00:00,
+/// }");
+/// ~~~~
 impl<Tz: TimeZone> fmt::Debug for DateTime<Tz> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{:?}{:?}", self.naive_local(), self.offset)
+        if f.alternate() {
+            // written by hand so that the output doesn't depend on the `debug_struct` layout
+            write!(
+                f,
+                "DateTime {{\n    utc: {:?},\n    local: {:?},\n    offset: {:?},\n}}",
+                self.datetime,
+                self.naive_local(),
+                self.offset.fix()
+            )
+        } else {
+            write!(f, "{:?}{:?}", self.naive_local(), self.offset)
+        }
     }
 }
 
+/// The `Display` output prints the local date and time followed by the offset,
+/// with the fractional seconds omitted when zero and otherwise printed
+/// with 3, 6 or 9 digits as needed, like [`SecondsFormat::AutoSi`](./enum.SecondsFormat.html).
+///
+/// The offset is printed by its own `Display` implementation,
+/// so it is `UTC` for [`Utc`](./offset/struct.Utc.html) and `+hh:mm` for
+/// [`FixedOffset`](./offset/struct.FixedOffset.html) even when it is zero.
+/// Use [`to_string_with_offset_style`](#method.to_string_with_offset_style)
+/// to print the offset in the same form for every time zone,
+/// or [`to_rfc3339_opts`](#method.to_rfc3339_opts) for an explicit precision.
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::{TimeZone, Utc};
+///
+/// let dt = Utc.ymd(2015, 6, 30).and_hms(23, 56, 4);
+/// assert_eq!(dt.to_string(), "2015-06-30 23:56:04 UTC");
+/// let dt = Utc.ymd(2015, 6, 30).and_hms_micro(23, 56, 4, 120);
+/// assert_eq!(dt.to_string(), "2015-06-30 23:56:04.000120 UTC");
+/// ~~~~
 impl<Tz: TimeZone> fmt::Display for DateTime<Tz>
 where
     Tz::Offset: fmt::Display,
@@ -1501,6 +2594,172 @@ pub mod serde {
         }
     }
 
+    /// Ser/de to/from RFC 3339 strings, also accepting timestamps in seconds
+    ///
+    /// Serializes into an RFC 3339 string same to the default `Serialize` implementation,
+    /// but deserializes from either an RFC 3339 string or a number of seconds since the epoch.
+    /// The number can be an integer or a float, where the fractional part of the float
+    /// is rounded to nanoseconds (so the precision is limited to that of `f64`).
+    ///
+    /// This needs a self-describing data format like JSON,
+    /// since the type of the value is only known when it is deserialized.
+    /// Intended for use with `serde`'s `with` attribute.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # // We mark this ignored so that we can test on 1.13 (which does not
+    /// # // support custom derive), and run tests with --ignored on beta and
+    /// # // nightly to actually trigger these.
+    /// #
+    /// # #[macro_use] extern crate serde_derive;
+    /// # #[macro_use] extern crate serde_json;
+    /// # extern crate chrono;
+    /// # use chrono::{TimeZone, DateTime, Utc};
+    /// use chrono::serde::ts_or_rfc3339;
+    /// #[derive(Deserialize, Serialize)]
+    /// struct S {
+    ///     #[serde(with = "ts_or_rfc3339")]
+    ///     time: DateTime<Utc>
+    /// }
+    ///
+    /// # fn example() -> Result<S, serde_json::Error> {
+    /// let time = Utc.ymd(2015, 4, 1).and_hms(12, 0, 0);
+    ///
+    /// let my_s: S = serde_json::from_str(r#"{"time":"2015-04-01T12:00:00Z"}"#)?;
+    /// assert_eq!(my_s.time, time);
+    /// let my_s: S = serde_json::from_str(r#"{"time":1427889600}"#)?;
+    /// assert_eq!(my_s.time, time);
+    /// let my_s: S = serde_json::from_str(r#"{"time":1427889600.25}"#)?;
+    /// assert_eq!(my_s.time, Utc.ymd(2015, 4, 1).and_hms_milli(12, 0, 0, 250));
+    ///
+    /// let as_string = serde_json::to_string(&my_s)?;
+    /// assert_eq!(as_string, r#"{"time":"2015-04-01T12:00:00.250Z"}"#);
+    /// # Ok(my_s)
+    /// # }
+    /// # fn main() { example().unwrap(); }
+    /// ```
+    pub mod ts_or_rfc3339 {
+        use serdelib::{de, ser};
+        use std::{fmt, iter};
+
+        use offset::TimeZone;
+        use {DateTime, FixedOffset, Utc};
+
+        use super::serde_from;
+
+        /// Serialize a UTC datetime into an RFC 3339 string
+        ///
+        /// Intended for use with `serde`s `serialize_with` attribute.
+        pub fn serialize<S>(dt: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            ser::Serialize::serialize(dt, serializer)
+        }
+
+        /// Deserialize a `DateTime` from an RFC 3339 string or a seconds timestamp
+        ///
+        /// Intended for use with `serde`s `deserialize_with` attribute.
+        pub fn deserialize<'de, D>(d: D) -> Result<DateTime<Utc>, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            Ok(try!(d.deserialize_any(TimestampOrRfc3339Visitor)))
+        }
+
+        /// Splits fractional seconds into whole seconds and nanoseconds,
+        /// rounding the shortest decimal representation of `value` to nanoseconds
+        /// so that e.g. `0.1` gives exactly 100,000,000 nanoseconds.
+        fn split_secs(value: f64) -> Option<(i64, u32)> {
+            // `i64::max_value() as f64` rounds up to 2^63, which is out of range
+            if value.is_nan() || value.abs() >= i64::max_value() as f64 {
+                return None;
+            }
+            let formatted = format!("{}", value.abs());
+            let mut parts = formatted.splitn(2, '.');
+            let secs: i64 = try_opt!(parts.next().and_then(|s| s.parse().ok()));
+            let mut digits = parts.next().unwrap_or("").bytes().chain(iter::repeat(b'0'));
+            let mut nanos = 0;
+            for digit in digits.by_ref().take(9) {
+                nanos = nanos * 10 + u32::from(digit - b'0');
+            }
+            // round half up by the tenth digit
+            if digits.next() >= Some(b'5') {
+                nanos += 1;
+            }
+            let (secs, nanos) = if nanos == 1_000_000_000 {
+                (secs + 1, 0)
+            } else {
+                (secs, nanos)
+            };
+            if value >= 0.0 {
+                Some((secs, nanos))
+            } else if nanos == 0 {
+                Some((-secs, 0))
+            } else {
+                Some((-secs - 1, 1_000_000_000 - nanos))
+            }
+        }
+
+        struct TimestampOrRfc3339Visitor;
+
+        impl<'de> de::Visitor<'de> for TimestampOrRfc3339Visitor {
+            type Value = DateTime<Utc>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("an RFC 3339 string or a unix timestamp in seconds")
+            }
+
+            /// Deserialize a timestamp in seconds since the epoch
+            fn visit_i64<E>(self, value: i64) -> Result<DateTime<Utc>, E>
+            where
+                E: de::Error,
+            {
+                serde_from(Utc.timestamp_opt(value, 0), &value)
+            }
+
+            /// Deserialize a timestamp in seconds since the epoch
+            fn visit_u64<E>(self, value: u64) -> Result<DateTime<Utc>, E>
+            where
+                E: de::Error,
+            {
+                if value > i64::max_value() as u64 {
+                    return Err(E::custom(format!(
+                        "value is not a legal timestamp: {}",
+                        value
+                    )));
+                }
+                serde_from(Utc.timestamp_opt(value as i64, 0), &value)
+            }
+
+            /// Deserialize a timestamp in fractional seconds since the epoch
+            fn visit_f64<E>(self, value: f64) -> Result<DateTime<Utc>, E>
+            where
+                E: de::Error,
+            {
+                match split_secs(value) {
+                    Some((secs, nanos)) => serde_from(Utc.timestamp_opt(secs, nanos), &value),
+                    None => Err(E::custom(format!(
+                        "value is not a legal timestamp: {}",
+                        value
+                    ))),
+                }
+            }
+
+            /// Deserialize an RFC 3339 string
+            fn visit_str<E>(self, value: &str) -> Result<DateTime<Utc>, E>
+            where
+                E: de::Error,
+            {
+                value
+                    .parse::<DateTime<FixedOffset>>()
+                    .map(|dt| dt.with_timezone(&Utc))
+                    .map_err(|err| E::custom(format!("{}", err)))
+            }
+        }
+    }
+
     impl<Tz: TimeZone> ser::Serialize for DateTime<Tz> {
         /// Serialize into a rfc3339 time string
         ///
@@ -1564,6 +2823,10 @@ pub mod serde {
     /// Deserialize into a UTC value
     ///
     /// The value to be deserialized must be an rfc3339 string.
+    /// With the `serde-lenient` feature a unix timestamp in seconds is also accepted
+    /// from human-readable data formats like JSON,
+    /// same to [`serde::ts_or_rfc3339`](./serde/ts_or_rfc3339/index.html).
+    /// Compact formats like bincode are not affected by the feature.
     ///
     /// See [the `serde` module](./serde/index.html) for alternate
     /// deserialization formats.
@@ -1571,10 +2834,14 @@ pub mod serde {
         fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
         where
             D: de::Deserializer<'de>,
-        {
-            deserializer
-                .deserialize_str(DateTimeVisitor)
-                .map(|dt| dt.with_timezone(&Utc))
+        {
+            if cfg!(feature = "serde-lenient") && deserializer.is_human_readable() {
+                ts_or_rfc3339::deserialize(deserializer)
+            } else {
+                deserializer
+                    .deserialize_str(DateTimeVisitor)
+                    .map(|dt| dt.with_timezone(&Utc))
+            }
         }
     }
 
@@ -1617,14 +2884,64 @@ pub mod serde {
         );
     }
 
+    #[test]
+    fn test_serde_ts_or_rfc3339() {
+        use self::serde_json::{from_str, Deserializer, Error};
+
+        let from = |input: &str| -> Result<DateTime<Utc>, Error> {
+            ts_or_rfc3339::deserialize(&mut Deserializer::from_str(input))
+        };
+        let dt = Utc.ymd(2015, 4, 1).and_hms(12, 0, 0);
+
+        assert_eq!(from(r#""2015-04-01T12:00:00Z""#).ok(), Some(dt));
+        assert_eq!(from(r#""2015-04-01T21:00:00+09:00""#).ok(), Some(dt));
+        assert_eq!(from("1427889600").ok(), Some(dt));
+        assert_eq!(from("1427889600.0").ok(), Some(dt));
+        assert_eq!(
+            from("-1.5").ok(),
+            Some(Utc.ymd(1969, 12, 31).and_hms_milli(23, 59, 58, 500))
+        );
+        assert_eq!(from("0.000000001").ok(), Some(Utc.timestamp(0, 1)));
+        assert_eq!(from("0.9999999999").ok(), Some(Utc.timestamp(1, 0)));
+        assert_eq!(from("-0.1").ok(), Some(Utc.timestamp(-1, 900_000_000)));
+        assert_eq!(from("-3.0").ok(), Some(Utc.timestamp(-3, 0)));
+        assert_eq!(
+            from("1427889600.123456").ok(),
+            Some(Utc.ymd(2015, 4, 1).and_hms_micro(12, 0, 0, 123_456))
+        );
+        assert_eq!(from("18446744073709551615").ok(), None);
+        assert_eq!(from("1e300").ok(), None);
+        assert_eq!(from("-1e300").ok(), None);
+        assert_eq!(from(r#""2015-04-01""#).ok(), None);
+
+        let err = from("true").unwrap_err().to_string();
+        assert!(
+            err.contains("an RFC 3339 string or a unix timestamp in seconds"),
+            "{}",
+            err
+        );
+        let err = from("[1427889600]").unwrap_err().to_string();
+        assert!(
+            err.contains("an RFC 3339 string or a unix timestamp in seconds"),
+            "{}",
+            err
+        );
+
+        // the default implementation accepts timestamps only with the `serde-lenient` feature
+        let lenient: Result<DateTime<Utc>, Error> = from_str("1427889600");
+        assert_eq!(lenient.is_ok(), cfg!(feature = "serde-lenient"));
+        let strict: Result<DateTime<FixedOffset>, Error> = from_str("1427889600");
+        assert!(strict.is_err());
+    }
+
     #[test]
     fn test_serde_bincode() {
         // Bincode is relevant to test separately from JSON because
         // it is not self-describing.
-        use self::bincode::{deserialize, serialize, Infinite};
+        use self::bincode::{deserialize, serialize};
 
         let dt = Utc.ymd(2014, 7, 24).and_hms(12, 34, 6);
-        let encoded = serialize(&dt, Infinite).unwrap();
+        let encoded = serialize(&dt).unwrap();
         let decoded: DateTime<Utc> = deserialize(&encoded).unwrap();
         assert_eq!(dt, decoded);
         assert_eq!(dt.offset(), decoded.offset());
@@ -1633,15 +2950,56 @@ pub mod serde {
 
 #[cfg(test)]
 mod tests {
+    #[cfg(bench)]
+    extern crate test;
+
     use super::DateTime;
-    use naive::{NaiveDate, NaiveTime};
+    use naive::{NaiveDate, NaiveDateTime, NaiveTime};
     #[cfg(feature = "clock")]
     use offset::Local;
     use offset::{FixedOffset, TimeZone, Utc};
     use oldtime::Duration;
+    use std::i64;
     use std::time::{SystemTime, UNIX_EPOCH};
-    #[cfg(feature = "clock")]
+    use test_zones::Dst;
     use Datelike;
+    use {Age, Date, Days, FixedClock, Months};
+
+    #[test]
+    fn test_datetime_convert_all() {
+        let utc: Vec<DateTime<Utc>> = (0..100).map(|i| Utc.timestamp(i * 3_333_333, 0)).collect();
+        for &secs in &[0, 9 * 3600, -(3 * 3600 + 30 * 60)] {
+            let tz = FixedOffset::east(secs);
+            let converted = DateTime::convert_all(&utc, &tz);
+            assert_eq!(converted.len(), utc.len());
+            for (c, u) in converted.iter().zip(utc.iter()) {
+                assert_eq!(*c, u.with_timezone(&tz));
+                assert_eq!(c.offset(), &tz);
+            }
+            assert_eq!(DateTime::convert_all(&converted, &Utc), utc);
+        }
+        assert!(DateTime::convert_all(&utc[..0], &Utc).is_empty());
+    }
+
+    #[cfg(bench)]
+    #[bench]
+    fn bench_datetime_with_timezone_fixed(bh: &mut test::Bencher) {
+        let utc: Vec<DateTime<Utc>> = (0..1000).map(|i| Utc.timestamp(i * 3_333_333, 0)).collect();
+        let tz = FixedOffset::east(9 * 3600);
+        bh.iter(|| {
+            for dt in &utc {
+                test::black_box(dt.with_timezone(&tz));
+            }
+        });
+    }
+
+    #[cfg(bench)]
+    #[bench]
+    fn bench_datetime_convert_all_fixed(bh: &mut test::Bencher) {
+        let utc: Vec<DateTime<Utc>> = (0..1000).map(|i| Utc.timestamp(i * 3_333_333, 0)).collect();
+        let tz = FixedOffset::east(9 * 3600);
+        bh.iter(|| test::black_box(DateTime::convert_all(&utc, &tz)));
+    }
 
     #[test]
     #[allow(non_snake_case)]
@@ -1701,6 +3059,18 @@ mod tests {
             "2014-05-06T23:59:59+09:00"
         );
 
+        // alternate form
+        assert_eq!(
+            format!("{:#?}", Edt.ymd(2014, 5, 6).and_hms(23, 59, 59)),
+            "DateTime {\n    utc: 2014-05-07T03:59:59,\n    local: 2014-05-06T23:59:59,\n    \
+             offset: -04:00,\n}"
+        );
+        assert_eq!(
+            format!("{:#?}", Kst.ymd(2014, 5, 6).and_hms(7, 8, 9)),
+            "DateTime {\n    utc: 2014-05-05T22:08:09,\n    local: 2014-05-06T07:08:09,\n    \
+             offset: +09:00,\n}"
+        );
+
         let dt = Utc.ymd(2014, 5, 6).and_hms(7, 8, 9);
         assert_eq!(dt, Edt.ymd(2014, 5, 6).and_hms(3, 8, 9));
         assert_eq!(
@@ -1750,6 +3120,561 @@ mod tests {
         assert_eq!(local_now, local_now2);
     }
 
+    #[test]
+    fn test_datetime_from_naive_utc_and_offset() {
+        let utc = NaiveDate::from_ymd(2014, 5, 6).and_hms(7, 8, 9);
+        let dt = DateTime::<Utc>::from_naive_utc_and_offset(utc, Utc);
+        assert_eq!(dt, Utc.ymd(2014, 5, 6).and_hms(7, 8, 9));
+        assert_eq!(dt, DateTime::<Utc>::from_utc(utc, Utc));
+
+        let offset = FixedOffset::west(5 * 3600);
+        let dt = DateTime::<FixedOffset>::from_naive_utc_and_offset(utc, offset);
+        assert_eq!(dt.naive_utc(), utc);
+        assert_eq!(
+            dt.naive_local(),
+            NaiveDate::from_ymd(2014, 5, 6).and_hms(2, 8, 9)
+        );
+        assert_eq!(dt.offset(), &offset);
+        assert_eq!(format!("{:?}", dt), "2014-05-06T02:08:09-05:00");
+    }
+
+    #[test]
+    fn test_datetime_with_fixed_offset() {
+        let dt = Utc.ymd(2014, 5, 6).and_hms(7, 8, 9);
+        assert_eq!(
+            dt.with_fixed_offset(9 * 3600),
+            FixedOffset::east(9 * 3600)
+                .ymd(2014, 5, 6)
+                .and_hms(16, 8, 9)
+        );
+        assert_eq!(
+            dt.with_fixed_offset(-7 * 3600 - 30 * 60),
+            FixedOffset::west(7 * 3600 + 30 * 60)
+                .ymd(2014, 5, 5)
+                .and_hms(23, 38, 9)
+        );
+        assert_eq!(dt.with_fixed_offset(0).offset(), &FixedOffset::east(0));
+        assert_eq!(
+            dt.with_fixed_offset_opt(86_399),
+            Some(dt.with_timezone(&FixedOffset::east(86_399)))
+        );
+        assert_eq!(dt.with_fixed_offset_opt(86_400), None);
+        assert_eq!(dt.with_fixed_offset_opt(-86_400), None);
+    }
+
+    #[test]
+    fn test_datetime_floor_ceil_round_to() {
+        let edt = FixedOffset::west(4 * 3600);
+        let dt = edt.ymd(2019, 12, 31).and_hms_nano(22, 30, 15, 500_000_000);
+
+        assert_eq!(
+            dt.floor_to(Duration::days(1)),
+            edt.ymd(2019, 12, 31).and_hms(20, 0, 0)
+        );
+        assert_eq!(
+            dt.ceil_to(Duration::days(1)),
+            edt.ymd(2020, 1, 1).and_hms(20, 0, 0)
+        );
+        assert_eq!(
+            dt.round_to(Duration::days(1)),
+            edt.ymd(2019, 12, 31).and_hms(20, 0, 0)
+        );
+        assert_eq!(
+            dt.floor_to(Duration::seconds(1)),
+            edt.ymd(2019, 12, 31).and_hms(22, 30, 15)
+        );
+        assert_eq!(
+            dt.ceil_to(Duration::seconds(1)),
+            edt.ymd(2019, 12, 31).and_hms(22, 30, 16)
+        );
+        assert_eq!(
+            dt.round_to(Duration::seconds(1)),
+            edt.ymd(2019, 12, 31).and_hms(22, 30, 16)
+        );
+        assert_eq!(
+            dt.round_to(Duration::milliseconds(300)),
+            edt.ymd(2019, 12, 31).and_hms_milli(22, 30, 15, 600)
+        );
+        assert_eq!(dt.floor_to(Duration::nanoseconds(1)), dt);
+        assert_eq!(dt.ceil_to(Duration::nanoseconds(1)), dt);
+        assert_eq!(dt.round_to(Duration::nanoseconds(1)), dt);
+
+        // the offset is kept
+        assert_eq!(dt.floor_to(Duration::hours(1)).offset(), &edt);
+
+        // before the epoch
+        let dt = Utc.ymd(1969, 12, 31).and_hms_milli(23, 59, 59, 250);
+        assert_eq!(
+            dt.floor_to(Duration::seconds(1)),
+            Utc.ymd(1969, 12, 31).and_hms(23, 59, 59)
+        );
+        assert_eq!(
+            dt.ceil_to(Duration::seconds(1)),
+            Utc.ymd(1970, 1, 1).and_hms(0, 0, 0)
+        );
+        assert_eq!(
+            dt.round_to(Duration::seconds(1)),
+            Utc.ymd(1969, 12, 31).and_hms(23, 59, 59)
+        );
+
+        // leap seconds are treated as the preceding second
+        let dt = Utc.ymd(2016, 12, 31).and_hms_milli(23, 59, 59, 1_500);
+        assert_eq!(
+            dt.floor_to(Duration::seconds(1)),
+            Utc.ymd(2016, 12, 31).and_hms(23, 59, 59)
+        );
+        assert_eq!(
+            dt.ceil_to(Duration::seconds(1)),
+            Utc.ymd(2017, 1, 1).and_hms(0, 0, 0)
+        );
+
+        // periods not in whole seconds, or too long to fit in nanoseconds
+        let dt = Utc.ymd(2019, 12, 31).and_hms(22, 30, 15);
+        assert_eq!(
+            dt.floor_to(Duration::seconds(7) + Duration::nanoseconds(3)),
+            Utc.ymd(2019, 12, 31).and_hms_nano(22, 30, 9, 676_213_461)
+        );
+        assert_eq!(
+            dt.floor_to(Duration::days(365 * 1000)),
+            Utc.ymd(1970, 1, 1).and_hms(0, 0, 0)
+        );
+        assert_eq!(
+            Utc.ymd(1969, 12, 31)
+                .and_hms(0, 0, 0)
+                .floor_to(Duration::days(365 * 1000)),
+            Utc.ymd(970, 8, 31).and_hms(0, 0, 0)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_datetime_floor_to_zero() {
+        let _ = Utc
+            .ymd(2019, 12, 31)
+            .and_hms(22, 30, 15)
+            .floor_to(Duration::zero());
+    }
+
+    #[test]
+    fn test_datetime_from_and_into_naive() {
+        let naive = NaiveDate::from_ymd(2019, 12, 25).and_hms(22, 30, 0);
+        let kst = FixedOffset::east(9 * 3600).from_utc_datetime(&naive);
+        assert_eq!(
+            kst.naive_local(),
+            NaiveDate::from_ymd(2019, 12, 26).and_hms(7, 30, 0)
+        );
+
+        // always the UTC view, never the local one
+        assert_eq!(NaiveDateTime::from(kst), naive);
+        assert_eq!(NaiveDateTime::from(Utc.from_utc_datetime(&naive)), naive);
+        let utc: DateTime<Utc> = naive.into();
+        assert_eq!(utc, kst);
+        assert_eq!(utc.naive_utc(), naive);
+        let utc: DateTime<Utc> = kst.into();
+        assert_eq!(utc.naive_utc(), naive);
+        let fixed: DateTime<FixedOffset> = utc.into();
+        assert_eq!(fixed, utc);
+        assert_eq!(fixed.offset(), &FixedOffset::east(0));
+        assert_eq!(fixed.naive_local(), naive);
+
+        let date = NaiveDate::from_ymd(2019, 12, 25);
+        let utc: Date<Utc> = date.into();
+        assert_eq!(utc, Utc.ymd(2019, 12, 25));
+        assert_eq!(NaiveDate::from(utc), date);
+    }
+
+    #[test]
+    fn test_datetime_elapsed() {
+        let clock = FixedClock(Utc.ymd(2019, 12, 25).and_hms(9, 0, 0));
+        let edt = FixedOffset::west(4 * 3600);
+        assert_eq!(clock.0.elapsed_with_clock(&clock), Ok(Duration::zero()));
+        assert_eq!(
+            edt.ymd(2019, 12, 25)
+                .and_hms(4, 59, 59)
+                .elapsed_with_clock(&clock),
+            Ok(Duration::seconds(1))
+        );
+        assert_eq!(
+            edt.ymd(2019, 12, 25)
+                .and_hms_nano(5, 0, 0, 1)
+                .elapsed_with_clock(&clock),
+            Err(Duration::nanoseconds(1))
+        );
+        assert_eq!(
+            Utc.ymd(2018, 12, 25)
+                .and_hms(9, 0, 0)
+                .elapsed_with_clock(&clock),
+            Ok(Duration::days(365))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "clock")]
+    fn test_datetime_elapsed_since_now() {
+        let past = Utc::now() - Duration::hours(1);
+        assert!(past.elapsed().unwrap() >= Duration::hours(1));
+        let past = past.with_timezone(&FixedOffset::east(3600));
+        assert!(past.elapsed_since_now().unwrap() >= Duration::hours(1));
+        let future = Utc::now() + Duration::hours(1);
+        assert!(future.elapsed().unwrap_err() <= Duration::hours(1));
+    }
+
+    #[test]
+    fn test_datetime_add_assign() {
+        let kst = FixedOffset::east(9 * 3600);
+        let mut dt = kst.ymd(2019, 12, 31).and_hms(23, 0, 0);
+        dt += Duration::hours(2);
+        assert_eq!(dt, kst.ymd(2020, 1, 1).and_hms(1, 0, 0));
+        assert_eq!(dt.offset(), &kst);
+        dt -= Duration::days(1);
+        assert_eq!(dt, kst.ymd(2019, 12, 31).and_hms(1, 0, 0));
+        dt += Duration::zero();
+        assert_eq!(dt, kst.ymd(2019, 12, 31).and_hms(1, 0, 0));
+
+        let mut date = Utc.ymd(2019, 12, 31);
+        date += Duration::days(1);
+        assert_eq!(date, Utc.ymd(2020, 1, 1));
+        date -= Duration::weeks(1);
+        assert_eq!(date, Utc.ymd(2019, 12, 25));
+        // only the whole days count, as in `Date + Duration`
+        date += Duration::hours(47);
+        assert_eq!(date, Utc.ymd(2019, 12, 26));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_datetime_add_assign_overflow() {
+        let mut dt = Utc.ymd(2019, 12, 31).and_hms(23, 0, 0);
+        dt += Duration::max_value();
+    }
+
+    #[test]
+    fn test_datetime_cmp_naive() {
+        use std::cmp::Ordering;
+
+        let kst = FixedOffset::east(9 * 3600);
+        let naive = NaiveDate::from_ymd(2019, 12, 31).and_hms_milli(15, 0, 0, 500);
+        let dt = Utc.from_utc_datetime(&naive);
+
+        assert_eq!(dt, naive);
+        assert_eq!(naive, dt);
+        assert_eq!(dt.naive_local(), naive);
+        assert_ne!(dt.with_timezone(&kst).naive_local(), naive);
+        assert_eq!(dt.partial_cmp(&naive), Some(Ordering::Equal));
+        assert_eq!(naive.partial_cmp(&dt), Some(Ordering::Equal));
+
+        let later = dt + Duration::nanoseconds(1);
+        assert_ne!(later, naive);
+        assert_ne!(naive, later);
+        assert!(later > naive);
+        assert!(naive < later);
+
+        let leap = NaiveDate::from_ymd(2016, 12, 31).and_hms_milli(23, 59, 59, 1_500);
+        assert_eq!(Utc.from_utc_datetime(&leap), leap);
+        assert!(Utc.ymd(2017, 1, 1).and_hms(0, 0, 0) > leap);
+
+        assert_eq!(Utc.ymd(2019, 12, 31), naive.date());
+        assert_eq!(naive.date(), Utc.ymd(2019, 12, 31));
+        assert!(Utc.ymd(2020, 1, 1) > naive.date());
+        assert!(naive.date() < Utc.ymd(2020, 1, 1));
+    }
+
+    #[test]
+    fn test_datetime_exact_eq() {
+        let utc = Utc.ymd(2019, 12, 25).and_hms(10, 0, 0);
+        let plus2 = FixedOffset::east(2 * 3600)
+            .ymd(2019, 12, 25)
+            .and_hms(12, 0, 0);
+        let zero = FixedOffset::east(0).ymd(2019, 12, 25).and_hms(10, 0, 0);
+        assert_eq!(plus2, zero);
+        assert!(!plus2.exact_eq(&zero));
+        assert!(!zero.exact_eq(&plus2));
+        assert!(plus2.exact_eq(&plus2));
+        assert!(zero.exact_eq(&utc));
+        assert!(utc.exact_eq(&zero));
+        assert!(!utc.exact_eq(&(utc + Duration::nanoseconds(1))));
+        assert!(utc.date().exact_eq(&zero.date()));
+        assert!(!plus2.date().exact_eq(&zero.date()));
+    }
+
+    #[test]
+    fn test_datetime_months_since() {
+        let hired = Utc.ymd(2019, 1, 31).and_hms(9, 0, 0);
+        let kst = FixedOffset::east(9 * 3600);
+        assert_eq!(hired.months_since(hired), Some(0));
+        assert_eq!(hired.months_since_date_only(hired), Some(0));
+        assert_eq!(
+            Utc.ymd(2019, 2, 28).and_hms(8, 59, 59).months_since(hired),
+            Some(0)
+        );
+        assert_eq!(
+            Utc.ymd(2019, 2, 28)
+                .and_hms(8, 59, 59)
+                .months_since_date_only(hired),
+            Some(1)
+        );
+        assert_eq!(
+            Utc.ymd(2019, 3, 1).and_hms(0, 0, 0).months_since(hired),
+            Some(1)
+        );
+        assert_eq!(
+            kst.ymd(2021, 1, 31).and_hms(8, 59, 59).months_since(hired),
+            Some(23)
+        );
+        assert_eq!(
+            kst.ymd(2021, 1, 31).and_hms(9, 0, 0).months_since(hired),
+            Some(24)
+        );
+        assert_eq!(
+            kst.ymd(2021, 1, 31).and_hms(8, 59, 59).years_since(hired),
+            Some(1)
+        );
+        assert_eq!(
+            kst.ymd(2021, 1, 31)
+                .and_hms(8, 59, 59)
+                .years_since_date_only(hired),
+            Some(2)
+        );
+        assert_eq!(
+            Utc.ymd(2019, 1, 31).and_hms(8, 59, 59).months_since(hired),
+            None
+        );
+        assert_eq!(
+            Utc.ymd(2019, 1, 31)
+                .and_hms(8, 59, 59)
+                .months_since_date_only(hired),
+            Some(0)
+        );
+        assert_eq!(
+            Utc.ymd(2019, 1, 30)
+                .and_hms(23, 0, 0)
+                .months_since_date_only(hired),
+            None
+        );
+    }
+
+    #[test]
+    fn test_datetime_years_since() {
+        let birth = Utc.ymd(2000, 2, 29).and_hms(12, 0, 0);
+        let kst = FixedOffset::east(9 * 3600);
+        assert_eq!(birth.years_since(birth), Some(0));
+        assert_eq!(
+            Utc.ymd(2001, 2, 28).and_hms(11, 59, 59).years_since(birth),
+            Some(0)
+        );
+        assert_eq!(
+            Utc.ymd(2001, 2, 28).and_hms(12, 0, 0).years_since(birth),
+            Some(1)
+        );
+        assert_eq!(
+            kst.ymd(2004, 2, 29).and_hms(11, 59, 59).years_since(birth),
+            Some(3)
+        );
+        assert_eq!(
+            kst.ymd(2004, 2, 29).and_hms(12, 0, 0).years_since(birth),
+            Some(4)
+        );
+        assert_eq!(
+            Utc.ymd(2000, 2, 29).and_hms(11, 59, 59).years_since(birth),
+            None
+        );
+        assert_eq!(
+            Utc.ymd(2019, 12, 25).years_since(Utc.ymd(2000, 2, 29)),
+            Some(19)
+        );
+        assert_eq!(
+            Utc.ymd(2000, 2, 29).age_at(kst.ymd(2000, 3, 1)),
+            Some(Age {
+                years: 0,
+                months: 0,
+                days: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_datetime_add_days() {
+        let tz = FixedOffset::west(5 * 3600);
+        let dt = tz.ymd(2019, 12, 31).and_hms(22, 0, 0); // 2020-01-01 in UTC
+        assert_eq!(dt + Days(1), tz.ymd(2020, 1, 1).and_hms(22, 0, 0));
+        assert_eq!(dt - Days(365), tz.ymd(2018, 12, 31).and_hms(22, 0, 0));
+        assert_eq!(dt.checked_add_days(Days(u64::max_value())), None);
+        assert_eq!(tz.ymd(2020, 2, 28) + Days(2), tz.ymd(2020, 3, 1));
+        assert_eq!(Utc.ymd(2020, 3, 1) - Days(1), Utc.ymd(2020, 2, 29));
+    }
+
+    #[test]
+    fn test_datetime_add_months() {
+        let tz = FixedOffset::west(5 * 3600);
+        let dt = tz.ymd(2020, 1, 31).and_hms(22, 0, 0); // 2020-02-01 in UTC
+        assert_eq!(dt + Months(1), tz.ymd(2020, 2, 29).and_hms(22, 0, 0));
+        assert_eq!(dt - Months(2), tz.ymd(2019, 11, 30).and_hms(22, 0, 0));
+        assert_eq!(dt.checked_add_months(Months(u32::max_value())), None);
+        assert_eq!(tz.ymd(2020, 1, 31) + Months(1), tz.ymd(2020, 2, 29));
+        assert_eq!(Utc.ymd(2020, 3, 31) - Months(1), Utc.ymd(2020, 2, 29));
+    }
+
+    #[test]
+    fn test_datetime_add_hours_minutes_wrapping() {
+        use Timelike;
+
+        let tz = FixedOffset::west(5 * 3600);
+        let dt = tz.ymd(2019, 12, 31).and_hms_nano(21, 45, 30, 123);
+        assert_eq!(dt.add_hours_wrapping(0), Some(dt));
+        assert_eq!(
+            dt.add_hours_wrapping(3),
+            Some(tz.ymd(2020, 1, 1).and_hms_nano(0, 45, 30, 123))
+        );
+        assert_eq!(
+            dt.add_hours_wrapping(-46),
+            Some(tz.ymd(2019, 12, 29).and_hms_nano(23, 45, 30, 123))
+        );
+        assert_eq!(
+            dt.add_minutes_wrapping(135),
+            Some(tz.ymd(2020, 1, 1).and_hms_nano(0, 0, 30, 123))
+        );
+        assert_eq!(
+            dt.add_minutes_wrapping(-22 * 60),
+            Some(tz.ymd(2019, 12, 30).and_hms_nano(23, 45, 30, 123))
+        );
+        for &n in &[1_000_000_000_000, i64::MAX, i64::MIN] {
+            assert_eq!(dt.add_hours_wrapping(n), None);
+            assert_eq!(dt.add_minutes_wrapping(n), None);
+        }
+        let max = DateTime::<Utc>::max_value();
+        assert_eq!(max.add_hours_wrapping(1), None);
+        assert_eq!(max.add_minutes_wrapping(1), None);
+        assert_eq!(max.add_hours_wrapping(-2).map(|dt| dt.hour()), Some(22));
+
+        // the local time moves, skipping over the gap of `Dst` at 02:00 on 2019-03-31
+        let tz = Dst;
+        let dt = tz.ymd(2019, 3, 31).and_hms(1, 30, 0);
+        assert_eq!(dt.add_hours_wrapping(1), None);
+        assert_eq!(dt.add_minutes_wrapping(30), None);
+        let moved = dt.add_hours_wrapping(2).unwrap();
+        assert_eq!(moved.naive_local(), dt.naive_local() + Duration::hours(2));
+        assert_eq!(moved.signed_duration_since(dt), Duration::hours(1));
+        assert_eq!(moved.add_hours_wrapping(-2), Some(dt));
+    }
+
+    #[test]
+    fn test_datetime_add_days_across_dst() {
+        use Timelike;
+
+        let tz = Dst;
+        let dt = tz.ymd(2019, 3, 30).and_hms(9, 0, 0);
+
+        // calendar days keep the wall clock time, exact days of 24 hours don't
+        let next = dt.checked_add_days(Days(1)).unwrap();
+        assert_eq!(next, tz.ymd(2019, 3, 31).and_hms(9, 0, 0));
+        assert_eq!(next.offset(), &FixedOffset::east(2 * 3600));
+        assert_eq!(next.signed_duration_since(dt), Duration::hours(23));
+        assert_eq!((dt + Duration::days(1)).hour(), 10);
+        assert_eq!(next.checked_sub_days(Days(1)), Some(dt));
+        assert_eq!(next - Days(1), dt);
+
+        // landing in the gap gives `None`
+        let dt = tz.ymd(2019, 3, 30).and_hms(2, 30, 0);
+        assert_eq!(dt.checked_add_days(Days(1)), None);
+        let dt = tz.ymd(2019, 4, 1).and_hms(2, 30, 0);
+        assert_eq!(dt.checked_sub_days(Days(1)), None);
+        assert_eq!(
+            dt.checked_sub_days(Days(2)),
+            Some(tz.ymd(2019, 3, 30).and_hms(2, 30, 0))
+        );
+    }
+
+    #[test]
+    fn test_datetime_with_fields_across_dst() {
+        let tz = Dst;
+
+        // the setters re-resolve the offset for the new local date and time
+        let dt = tz.ymd(2018, 3, 31).and_hms(3, 30, 0);
+        assert_eq!(dt.offset(), &FixedOffset::east(3600));
+        let moved = dt.with_year(2019).unwrap();
+        assert_eq!(moved.offset(), &FixedOffset::east(2 * 3600));
+        assert_eq!(
+            moved.naive_local(),
+            dt.naive_local().with_year(2019).unwrap()
+        );
+        assert_eq!(moved.with_year(2018), Some(dt));
+        assert_eq!(
+            moved.with_month(1).unwrap().offset(),
+            &FixedOffset::east(3600)
+        );
+
+        // landing in the gap gives `None` instead of keeping the stale offset
+        let dt = tz.ymd(2018, 3, 31).and_hms(2, 30, 0);
+        assert_eq!(dt.with_year(2019), None);
+        assert_eq!(dt.with_year_clamped(2019), None);
+        assert_eq!(dt.with_month_clamped(3), Some(dt));
+        let dt = tz.ymd(2019, 3, 30).and_hms(2, 30, 0);
+        assert_eq!(dt.with_day(31), None);
+        assert_eq!(dt.with_day0(30), None);
+        assert_eq!(dt.with_ordinal(90), None);
+        assert_eq!(dt.with_ordinal0(89), None);
+        assert_eq!(tz.ymd(2019, 1, 31).and_hms(2, 30, 0).with_month(3), None);
+        assert_eq!(tz.ymd(2019, 1, 31).and_hms(2, 30, 0).with_month0(2), None);
+
+        // February 29 is only kept in leap years unless clamped
+        let dt = tz.ymd(2016, 2, 29).and_hms(12, 0, 0);
+        assert_eq!(dt.with_year(2015), None);
+        assert_eq!(
+            dt.with_year_clamped(2015),
+            Some(tz.ymd(2015, 2, 28).and_hms(12, 0, 0))
+        );
+        assert_eq!(
+            dt.with_year_clamped(2020),
+            Some(tz.ymd(2020, 2, 29).and_hms(12, 0, 0))
+        );
+        assert_eq!(dt.with_year_clamped(1_000_000), None);
+        assert_eq!(
+            dt.with_month_clamped(4),
+            Some(tz.ymd(2016, 4, 29).and_hms(12, 0, 0))
+        );
+        let dt = tz.ymd(2016, 1, 31).and_hms(12, 0, 0);
+        assert_eq!(dt.with_month(2), None);
+        assert_eq!(
+            dt.with_month_clamped(2),
+            Some(tz.ymd(2016, 2, 29).and_hms(12, 0, 0))
+        );
+        assert_eq!(dt.with_month_clamped(13), None);
+    }
+
+    #[test]
+    fn test_datetime_humanize_from() {
+        let now = Utc.ymd(2019, 12, 1).and_hms(12, 0, 0);
+        let check = |secs: i64, expected: &str| {
+            assert_eq!((now + Duration::seconds(secs)).humanize_from(now), expected);
+        };
+
+        check(0, "now");
+        check(1, "in 1 second");
+        check(-1, "1 second ago");
+        check(59, "in 59 seconds");
+        check(60, "in 1 minute");
+        check(-119, "1 minute ago");
+        check(-120, "2 minutes ago");
+        check(3_599, "in 59 minutes");
+        check(3_600, "in 1 hour");
+        check(-86_399, "23 hours ago");
+        check(86_400, "in 1 day");
+        check(-6 * 86_400, "6 days ago");
+        check(7 * 86_400, "in 1 week");
+        check(-29 * 86_400, "4 weeks ago");
+        check(30 * 86_400, "in 1 month");
+        check(-364 * 86_400, "12 months ago");
+        check(365 * 86_400, "in 1 year");
+        check(-1000 * 365 * 86_400, "1000 years ago");
+
+        // sub-second differences are ignored, as is the time zone of either side
+        let dt = FixedOffset::east(9 * 3600)
+            .ymd(2019, 12, 1)
+            .and_hms_milli(21, 0, 0, 999);
+        assert_eq!(dt.humanize_from(now), "now");
+        assert_eq!(now.humanize_from(dt + Duration::hours(1)), "1 hour ago");
+    }
+
     #[test]
     #[allow(non_snake_case)]
     fn test_datetime_rfc2822_and_rfc3339() {
@@ -1859,6 +3784,111 @@ mod tests {
         dt.to_rfc3339_opts(SecondsFormat::__NonExhaustive, true);
     }
 
+    #[test]
+    fn test_datetime_to_string_with_offset_style() {
+        use OffsetStyle::*;
+        use SecondsFormat;
+
+        let ut = Utc.ymd(2015, 6, 30).and_hms_milli(23, 59, 59, 1_500); // leap second
+        let zero = ut.with_timezone(&FixedOffset::east(0));
+        for &(style, offset) in &[
+            (Z, "Z"),
+            (Colon, "+00:00"),
+            (NoColon, "+0000"),
+            (Seconds, "+00:00:00"),
+        ] {
+            let expected = format!("2015-06-30 23:59:60.500 {}", offset);
+            assert_eq!(ut.to_string_with_offset_style(style), expected);
+            assert_eq!(zero.to_string_with_offset_style(style), expected);
+            let expected = format!("2015-06-30T23:59:60.500{}", offset);
+            assert_eq!(
+                ut.to_rfc3339_with_offset_style(SecondsFormat::Millis, style),
+                expected
+            );
+            assert_eq!(
+                zero.to_rfc3339_with_offset_style(SecondsFormat::Millis, style),
+                expected
+            );
+        }
+
+        let dt = FixedOffset::east(5 * 3600 + 45 * 60 + 30)
+            .ymd(2015, 7, 1)
+            .and_hms(5, 45, 30);
+        assert_eq!(dt.to_string(), "2015-07-01 05:45:30 +05:45:30");
+        assert_eq!(
+            dt.to_string_with_offset_style(Z),
+            "2015-07-01 05:45:30 +05:45"
+        );
+        assert_eq!(
+            dt.to_string_with_offset_style(Colon),
+            "2015-07-01 05:45:30 +05:45"
+        );
+        assert_eq!(
+            dt.to_string_with_offset_style(NoColon),
+            "2015-07-01 05:45:30 +0545"
+        );
+        assert_eq!(
+            dt.to_string_with_offset_style(Seconds),
+            "2015-07-01 05:45:30 +05:45:30"
+        );
+
+        let dt = FixedOffset::west(30).ymd(2015, 7, 1).and_hms(0, 0, 0);
+        assert_eq!(
+            dt.to_string_with_offset_style(Z),
+            "2015-07-01 00:00:00 -00:00"
+        );
+        assert_eq!(
+            dt.to_string_with_offset_style(Seconds),
+            "2015-07-01 00:00:00 -00:00:30"
+        );
+        assert_eq!(
+            dt.to_rfc3339_with_offset_style(SecondsFormat::Secs, Seconds),
+            "2015-07-01T00:00:00-00:00:30"
+        );
+    }
+
+    #[test]
+    fn test_datetime_display_precision() {
+        use SecondsFormat;
+
+        let edt = FixedOffset::east(-4 * 3600);
+        let ymdhmsn = |h, n, s, nano| edt.ymd(2015, 6, 30).and_hms_nano(h, n, s, nano);
+
+        let cases = [
+            (
+                ymdhmsn(23, 56, 4, 0),
+                "2015-06-30 23:56:04 -04:00",
+                "2015-06-30T23:56:04-04:00",
+            ),
+            (
+                ymdhmsn(23, 56, 4, 100_000_000),
+                "2015-06-30 23:56:04.100 -04:00",
+                "2015-06-30T23:56:04.100-04:00",
+            ),
+            (
+                ymdhmsn(23, 56, 4, 123_400),
+                "2015-06-30 23:56:04.000123400 -04:00",
+                "2015-06-30T23:56:04.000123400-04:00",
+            ),
+            (
+                ymdhmsn(23, 56, 4, 1_000),
+                "2015-06-30 23:56:04.000001 -04:00",
+                "2015-06-30T23:56:04.000001-04:00",
+            ),
+            (
+                ymdhmsn(23, 59, 59, 1_000_000_000),
+                "2015-06-30 23:59:60 -04:00",
+                "2015-06-30T23:59:60-04:00",
+            ),
+        ];
+        for &(dt, display, rfc3339) in &cases {
+            assert_eq!(dt.to_string(), display);
+            assert_eq!(dt.to_rfc3339(), rfc3339);
+            assert_eq!(dt.to_rfc3339_opts(SecondsFormat::AutoSi, false), rfc3339);
+            assert_eq!(DateTime::parse_from_rfc3339(&dt.to_rfc3339()), Ok(dt));
+        }
+    }
+
     #[test]
     fn test_datetime_from_str() {
         assert_eq!(
@@ -1907,6 +3937,61 @@ mod tests {
             Utc.datetime_from_str("Fri, 09 Aug 2013 23:54:35 GMT", "%a, %d %b %Y %H:%M:%S GMT"),
             Ok(Utc.ymd(2013, 8, 9).and_hms(23, 54, 35))
         );
+
+        // time zone names are skipped without giving an offset
+        assert_eq!(
+            DateTime::parse_from_str("2014-5-7 12:34:56 KST +0900", "%Y-%m-%d %H:%M:%S %Z %z"),
+            Ok(ymdhms(2014, 5, 7, 12, 34, 56, 9 * 3600))
+        );
+        assert!(DateTime::parse_from_str("2014-5-7 12:34:56 KST", "%Y-%m-%d %H:%M:%S %Z").is_err());
+        assert_eq!(
+            Utc.datetime_from_str("Fri, 09 Aug 2013 23:54:35 GMT", "%a, %d %b %Y %H:%M:%S %Z"),
+            Ok(Utc.ymd(2013, 8, 9).and_hms(23, 54, 35))
+        );
+        let dt = Utc.ymd(2013, 8, 9).and_hms(23, 54, 35);
+        assert_eq!(
+            Utc.datetime_from_str(&dt.format("%F %T %Z").to_string(), "%F %T %Z"),
+            Ok(dt)
+        );
+        let dt = ymdhms(2013, 8, 9, 23, 54, 35, -(9 * 3600 + 30 * 60));
+        assert_eq!(
+            DateTime::parse_from_str(&dt.format("%F %T %Z %z").to_string(), "%F %T %Z %z"),
+            Ok(dt)
+        );
+
+        // offsets
+        for &fmt in &[
+            "%Y-%m-%dT%H:%M:%S%z",
+            "%Y-%m-%dT%H:%M:%S%:z",
+            "%Y-%m-%dT%H:%M:%S%#z",
+        ] {
+            for &s in &["2014-5-7T12:34:56+0900", "2014-5-7T12:34:56+09:00"] {
+                assert_eq!(
+                    DateTime::parse_from_str(s, fmt),
+                    Ok(ymdhms(2014, 5, 7, 12, 34, 56, 9 * 3600))
+                );
+            }
+        }
+        assert!(DateTime::parse_from_str("2014-5-7T12:34:56Z", "%Y-%m-%dT%H:%M:%S%z").is_err());
+        assert!(DateTime::parse_from_str("2014-5-7T12:34:56+09", "%Y-%m-%dT%H:%M:%S%:z").is_err());
+        assert_eq!(
+            DateTime::parse_from_str("2014-5-7T12:34:56Z", "%Y-%m-%dT%H:%M:%S%#z"),
+            Ok(ymdhms(2014, 5, 7, 12, 34, 56, 0))
+        );
+        assert_eq!(
+            DateTime::parse_from_str("2014-5-7T12:34:56-09", "%Y-%m-%dT%H:%M:%S%#z"),
+            Ok(ymdhms(2014, 5, 7, 12, 34, 56, -9 * 3600))
+        );
+        let dt = ymdhms(2014, 5, 7, 12, 34, 56, -9 * 3600 - 30 * 60);
+        assert_eq!(dt.format("%z").to_string(), "-0930");
+        assert_eq!(dt.format("%:z").to_string(), "-09:30");
+        assert_eq!(
+            Utc.ymd(2014, 5, 7)
+                .and_hms(0, 0, 0)
+                .format("%z")
+                .to_string(),
+            "+0000"
+        );
     }
 
     #[test]
@@ -0,0 +1,23 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! The number of calendar days.
+
+/// The number of calendar days, to be added to or subtracted from dates.
+///
+/// Unlike a `Duration`, moving by `Days` changes the date only and keeps the local time,
+/// even when the offset of the time zone changes in between
+/// (so a day is not always 24 hours long in that case).
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::{Days, NaiveDate};
+///
+/// let from_ymd = NaiveDate::from_ymd;
+///
+/// assert_eq!(from_ymd(2019, 12, 25) + Days(10), from_ymd(2020, 1, 4));
+/// assert_eq!(from_ymd(2020, 3, 1) - Days(1), from_ymd(2020, 2, 29));
+/// ~~~~
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Days(pub u64);
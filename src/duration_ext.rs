@@ -0,0 +1,396 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+use oldtime::Duration;
+use std::i64;
+use std::time::Duration as StdDuration;
+
+const NANOS_PER_SEC: i64 = 1_000_000_000;
+/// The largest number of seconds that `Duration::seconds` accepts.
+const MAX_SECS: i64 = i64::MAX / 1000;
+
+/// Extension trait for checked, saturating and comparing operations on `Duration`.
+///
+/// With the default `clock` feature `Duration` is the type from the `time` crate,
+/// and otherwise a compatible type built into Chrono.
+/// This trait adds the same methods to whichever is in use,
+/// so code using them doesn't depend on the features.
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::{Duration, DurationExt};
+///
+/// let d = Duration::seconds(-90);
+/// assert_eq!(d.abs(), Duration::seconds(90));
+/// assert_eq!(d.checked_mul(2), Some(Duration::seconds(-180)));
+/// assert_eq!(d.checked_div(0), None);
+/// assert_eq!(Duration::max_value().checked_mul(2), None);
+/// ~~~~
+pub trait DurationExt: Sized {
+    /// Multiply a duration by a scalar, returning `None` if overflow occurred.
+    fn checked_mul(&self, rhs: i64) -> Option<Self>;
+
+    /// Divide a duration by a scalar, returning `None` if `rhs` is zero or overflow occurred.
+    ///
+    /// The result is rounded toward zero to the nanosecond, and the remainder is discarded.
+    fn checked_div(&self, rhs: i64) -> Option<Self>;
+
+    /// Negate a duration, returning `None` if overflow occurred.
+    ///
+    /// Only `Duration::min_value()` overflows, as `i64::MIN` milliseconds can't be negated.
+    fn checked_neg(&self) -> Option<Self>;
+
+    /// Returns `true` if the duration is shorter than zero.
+    fn is_negative(&self) -> bool;
+
+    /// Returns `true` if the duration is longer than zero.
+    fn is_positive(&self) -> bool;
+
+    /// Returns the absolute value of the duration.
+    ///
+    /// The magnitude of `Duration::min_value()` is one millisecond longer than
+    /// `Duration::max_value()`, so it saturates to `Duration::max_value()` instead.
+    fn abs(&self) -> Self;
+
+    /// The smallest positive duration: one nanosecond.
+    ///
+    /// Useful for turning an exclusive bound into an inclusive one,
+    /// e.g. `end - Duration::min_positive()` is the last instant before `end`.
+    fn min_positive() -> Self;
+
+    /// Returns the total number of whole microseconds in the duration,
+    /// saturating to `i64::MAX` or `i64::MIN` on overflow
+    /// (beyond about 292,471 years in either direction).
+    fn num_microseconds_saturating(&self) -> i64;
+
+    /// Returns the total number of whole nanoseconds in the duration,
+    /// saturating to `i64::MAX` or `i64::MIN` on overflow
+    /// (beyond about 292 years in either direction).
+    fn num_nanoseconds_saturating(&self) -> i64;
+
+    /// Converts into a `std::time::Duration`, saturating negative durations to zero.
+    ///
+    /// Use `Duration::to_std` to detect negative durations instead.
+    fn to_std_saturating(&self) -> StdDuration;
+}
+
+/// Splits the duration into whole seconds and the rest in nanoseconds,
+/// both with the sign of the duration.
+fn to_parts(d: &Duration) -> (i64, i64) {
+    let secs = d.num_seconds();
+    // less than a second, so this always fits
+    let nanos = (*d - Duration::seconds(secs)).num_nanoseconds().unwrap();
+    (secs, nanos)
+}
+
+/// Makes a duration of `secs` seconds and `nanos` nanoseconds, less than a second,
+/// returning `None` if it is out of range.
+fn from_parts(secs: i64, nanos: i64) -> Option<Duration> {
+    // `Duration::seconds` panics beyond `i64::MAX` milliseconds
+    if secs < -MAX_SECS || secs > MAX_SECS {
+        return None;
+    }
+    Duration::seconds(secs).checked_add(&Duration::nanoseconds(nanos))
+}
+
+/// Returns `(carry * NANOS_PER_SEC + nanos) / rhs` for `carry < rhs`
+/// without overflowing, by multiplying `carry` bit by bit modulo `rhs`.
+fn div_nanos(carry: u64, nanos: u64, rhs: u64) -> u64 {
+    // `carry * m == quot * rhs + rem` for the leading bits `m` of `NANOS_PER_SEC`,
+    // and `rem < rhs <= 2^63`, so doubling `rem` or adding `carry` can't overflow
+    let (mut quot, mut rem) = (0, 0);
+    for bit in (0..30).rev() {
+        quot *= 2;
+        rem *= 2;
+        if rem >= rhs {
+            rem -= rhs;
+            quot += 1;
+        }
+        if (NANOS_PER_SEC as u64 >> bit) & 1 == 1 {
+            rem += carry;
+            if rem >= rhs {
+                rem -= rhs;
+                quot += 1;
+            }
+        }
+    }
+    quot + (rem + nanos) / rhs
+}
+
+impl DurationExt for Duration {
+    fn checked_mul(&self, rhs: i64) -> Option<Duration> {
+        let (secs, nanos) = to_parts(self);
+        // all products have the same sign, so an intermediate overflow means the result overflows
+        let secs = try_opt!(secs.checked_mul(rhs));
+        let secs = try_opt!(secs.checked_add(try_opt!(nanos.checked_mul(rhs / NANOS_PER_SEC))));
+        let nanos = nanos * (rhs % NANOS_PER_SEC);
+        let secs = try_opt!(secs.checked_add(nanos / NANOS_PER_SEC));
+        from_parts(secs, nanos % NANOS_PER_SEC)
+    }
+
+    fn checked_div(&self, rhs: i64) -> Option<Duration> {
+        if rhs == 0 {
+            return None;
+        }
+        // divide the magnitudes, so that the result is truncated toward zero
+        let (secs, nanos) = to_parts(self);
+        let negative = (secs < 0 || nanos < 0) != (rhs < 0);
+        let (secs, nanos) = (secs.abs() as u64, nanos.abs() as u64);
+        let rhs = if rhs < 0 {
+            (rhs as u64).wrapping_neg()
+        } else {
+            rhs as u64
+        };
+        // the carried seconds make less than a second when divided
+        let nanos = div_nanos(secs % rhs, nanos, rhs) as i64;
+        let secs = (secs / rhs) as i64;
+        // only `Duration::min_value() / -1` can go out of range
+        if negative {
+            from_parts(-secs, -nanos)
+        } else {
+            from_parts(secs, nanos)
+        }
+    }
+
+    fn checked_neg(&self) -> Option<Duration> {
+        Duration::zero().checked_sub(self)
+    }
+
+    #[inline]
+    fn is_negative(&self) -> bool {
+        *self < Duration::zero()
+    }
+
+    #[inline]
+    fn is_positive(&self) -> bool {
+        *self > Duration::zero()
+    }
+
+    fn abs(&self) -> Duration {
+        if self.is_negative() {
+            self.checked_neg().unwrap_or_else(Duration::max_value)
+        } else {
+            *self
+        }
+    }
+
+    #[inline]
+    fn min_positive() -> Duration {
+        Duration::nanoseconds(1)
+    }
+
+    fn num_microseconds_saturating(&self) -> i64 {
+        self.num_microseconds().unwrap_or(if self.is_negative() {
+            i64::MIN
+        } else {
+            i64::MAX
+        })
+    }
+
+    fn num_nanoseconds_saturating(&self) -> i64 {
+        self.num_nanoseconds().unwrap_or(if self.is_negative() {
+            i64::MIN
+        } else {
+            i64::MAX
+        })
+    }
+
+    fn to_std_saturating(&self) -> StdDuration {
+        self.to_std().unwrap_or_else(|_| StdDuration::new(0, 0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DurationExt;
+    use oldtime::Duration;
+    use std::i64;
+    use std::time::Duration as StdDuration;
+
+    #[test]
+    fn test_duration_num_saturating() {
+        // same as the checked accessors when they don't overflow
+        for &d in &[
+            Duration::zero(),
+            Duration::nanoseconds(-1001),
+            Duration::microseconds(i64::MAX),
+            Duration::microseconds(i64::MIN),
+            Duration::nanoseconds(i64::MAX),
+            Duration::nanoseconds(i64::MIN),
+            Duration::days(106_751),
+            Duration::days(-106_751),
+        ] {
+            if let Some(micros) = d.num_microseconds() {
+                assert_eq!(d.num_microseconds_saturating(), micros);
+            }
+            if let Some(nanos) = d.num_nanoseconds() {
+                assert_eq!(d.num_nanoseconds_saturating(), nanos);
+            }
+        }
+        assert_eq!(
+            Duration::nanoseconds(-1001).num_microseconds_saturating(),
+            -1
+        );
+
+        assert_eq!(Duration::max_value().num_milliseconds(), i64::MAX);
+        assert_eq!(Duration::min_value().num_milliseconds(), i64::MIN);
+        assert_eq!(
+            Duration::max_value().num_microseconds_saturating(),
+            i64::MAX
+        );
+        assert_eq!(
+            Duration::min_value().num_microseconds_saturating(),
+            i64::MIN
+        );
+        let d = Duration::days(106_752);
+        assert_eq!(d.num_nanoseconds_saturating(), i64::MAX);
+        assert_eq!((-d).num_nanoseconds_saturating(), i64::MIN);
+        assert_eq!(d.num_microseconds_saturating(), 106_752 * 86_400_000_000);
+        let d = Duration::nanoseconds(i64::MAX) + Duration::nanoseconds(1);
+        assert_eq!(d.num_nanoseconds_saturating(), i64::MAX);
+        assert_eq!((-d).num_nanoseconds(), Some(i64::MIN));
+        assert_eq!(
+            (-d - Duration::nanoseconds(1)).num_nanoseconds_saturating(),
+            i64::MIN
+        );
+    }
+
+    #[test]
+    fn test_duration_checked_mul_div() {
+        assert_eq!(
+            Duration::seconds(3).checked_mul(-2),
+            Some(Duration::seconds(-6))
+        );
+        assert_eq!(
+            Duration::max_value().checked_mul(1),
+            Some(Duration::max_value())
+        );
+        assert_eq!(Duration::max_value().checked_mul(2), None);
+        assert_eq!(Duration::min_value().checked_mul(-1), None);
+        assert_eq!(Duration::seconds(i64::MAX / 1000 / 3).checked_mul(4), None);
+        assert_eq!(
+            Duration::seconds(7).checked_div(2),
+            Some(Duration::milliseconds(3500))
+        );
+        assert_eq!(
+            Duration::nanoseconds(-7).checked_div(2),
+            Some(Duration::nanoseconds(-3))
+        );
+        assert_eq!(Duration::seconds(1).checked_div(0), None);
+        assert_eq!(Duration::min_value().checked_div(-1), None);
+        assert_eq!(
+            Duration::max_value().checked_div(-1),
+            Some(-Duration::max_value())
+        );
+
+        // divisors beyond `i32`, where the carried seconds times `NANOS_PER_SEC` overflow `i64`
+        assert_eq!(
+            Duration::days(1).checked_div(86_400_000_000_000),
+            Some(Duration::nanoseconds(1))
+        );
+        assert_eq!(
+            Duration::days(-1).checked_div(86_400_000_000_001),
+            Some(Duration::zero())
+        );
+        assert_eq!(
+            Duration::max_value().checked_div(i64::MAX),
+            Some(Duration::milliseconds(1))
+        );
+        assert_eq!(
+            Duration::min_value().checked_div(i64::MIN),
+            Some(Duration::milliseconds(1))
+        );
+        assert_eq!(
+            Duration::min_value().checked_div(i64::MAX),
+            Some(Duration::milliseconds(-1))
+        );
+        assert_eq!(
+            Duration::seconds(-3).checked_div(-3_000_000_000),
+            Some(Duration::nanoseconds(1))
+        );
+    }
+
+    #[test]
+    fn test_duration_sign() {
+        assert!(Duration::min_value().is_negative() && !Duration::min_value().is_positive());
+        assert!(Duration::max_value().is_positive() && !Duration::max_value().is_negative());
+        assert!(!Duration::zero().is_negative() && !Duration::zero().is_positive());
+        assert!(Duration::nanoseconds(1).is_positive());
+        assert!(Duration::nanoseconds(-1).is_negative());
+    }
+
+    #[test]
+    fn test_duration_checked_neg() {
+        assert_eq!(
+            Duration::max_value().checked_neg(),
+            Some(Duration::min_value() + Duration::milliseconds(1))
+        );
+        assert_eq!(Duration::min_value().checked_neg(), None);
+        assert_eq!(Duration::zero().checked_neg(), Some(Duration::zero()));
+        assert_eq!(
+            Duration::nanoseconds(-1_500_000_000).checked_neg(),
+            Some(Duration::nanoseconds(1_500_000_000))
+        );
+        assert_eq!(-(-Duration::max_value()), Duration::max_value());
+    }
+
+    #[test]
+    fn test_duration_min_positive() {
+        let nano = Duration::min_positive();
+        assert_eq!(nano, Duration::nanoseconds(1));
+        assert!(nano.is_positive());
+        assert!(!(-nano).is_positive());
+        assert_eq!(nano.num_nanoseconds(), Some(1));
+        assert_eq!(
+            Duration::seconds(1) - nano,
+            Duration::nanoseconds(999_999_999)
+        );
+        assert_eq!(Duration::zero() - nano, Duration::nanoseconds(-1));
+        assert_eq!(Duration::max_value().checked_add(&nano), None);
+        assert_eq!(Duration::min_value().checked_sub(&nano), None);
+    }
+
+    #[test]
+    fn test_duration_abs() {
+        assert_eq!(Duration::zero().abs(), Duration::zero());
+        assert_eq!(Duration::seconds(3).abs(), Duration::seconds(3));
+        assert_eq!(Duration::seconds(-3).abs(), Duration::seconds(3));
+        assert_eq!(Duration::nanoseconds(-1).abs(), Duration::nanoseconds(1));
+        assert_eq!(
+            Duration::nanoseconds(-1_500_000_000).abs(),
+            Duration::nanoseconds(1_500_000_000)
+        );
+        assert_eq!(Duration::max_value().abs(), Duration::max_value());
+        assert_eq!(
+            (Duration::min_value() + Duration::milliseconds(1)).abs(),
+            Duration::max_value()
+        );
+        assert_eq!(Duration::min_value().abs(), Duration::max_value());
+    }
+
+    #[test]
+    fn test_duration_to_std_saturating() {
+        let zero = StdDuration::new(0, 0);
+        assert_eq!(Duration::zero().to_std_saturating(), zero);
+        assert_eq!(
+            Duration::nanoseconds(1).to_std_saturating(),
+            StdDuration::new(0, 1)
+        );
+        assert_eq!(
+            Duration::milliseconds(123765).to_std_saturating(),
+            StdDuration::new(123, 765000000)
+        );
+        assert_eq!(
+            Duration::max_value().to_std_saturating(),
+            StdDuration::new(9223372036854775, 807000000)
+        );
+        assert_eq!(Duration::nanoseconds(-1).to_std_saturating(), zero);
+        assert_eq!(Duration::seconds(-86401).to_std_saturating(), zero);
+        assert_eq!(Duration::min_value().to_std_saturating(), zero);
+        assert_eq!(
+            Duration::seconds(3).to_std_saturating(),
+            StdDuration::new(3, 0)
+        );
+    }
+}
@@ -0,0 +1,191 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! A snapshot of the date fields of any `Datelike` value.
+
+use std::fmt;
+
+use naive::{IsoWeek, NaiveDate};
+use {Datelike, Weekday};
+
+/// A snapshot of the date fields of any [`Datelike`](./trait.Datelike.html) value,
+/// for keeping values of different date types together.
+///
+/// `Datelike` can't be used as a trait object because its `with_*` methods return `Self`,
+/// so `Date<Local>`, `Date<Utc>` and `NaiveDate` can't share a `Vec<Box<Datelike>>`.
+/// Converting each of them into an `ErasedDate` gives a single type instead,
+/// which keeps the calendar date as seen in the original value's time zone
+/// and implements `Datelike` itself.
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::{Datelike, ErasedDate, NaiveDate, TimeZone, Utc};
+///
+/// let dates = vec![
+///     ErasedDate::new(&Utc.ymd(2019, 12, 25)),
+///     ErasedDate::new(&NaiveDate::from_ymd(2020, 2, 29)),
+/// ];
+/// let formatted: Vec<String> = dates.iter()
+///     .map(|d| format!("{}-{:02}-{:02} ({:?})", d.year(), d.month(), d.day(), d.weekday()))
+///     .collect();
+/// assert_eq!(formatted, ["2019-12-25 (Wed)", "2020-02-29 (Sat)"]);
+/// ~~~~
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ErasedDate {
+    date: NaiveDate,
+}
+
+impl ErasedDate {
+    /// Takes a snapshot of the date fields of `date`.
+    ///
+    /// Panics when its year and ordinal are out of the range of
+    /// [`NaiveDate`](./naive/struct.NaiveDate.html),
+    /// which doesn't happen for the date types in Chrono.
+    pub fn new<D: Datelike>(date: &D) -> ErasedDate {
+        let date = NaiveDate::from_yo_opt(date.year(), date.ordinal())
+            .expect("ErasedDate::new: out-of-range date");
+        ErasedDate { date: date }
+    }
+
+    /// Returns the snapshot as a `NaiveDate`.
+    #[inline]
+    pub fn naive_date(&self) -> NaiveDate {
+        self.date
+    }
+}
+
+impl Datelike for ErasedDate {
+    #[inline]
+    fn year(&self) -> i32 {
+        self.date.year()
+    }
+    #[inline]
+    fn month(&self) -> u32 {
+        self.date.month()
+    }
+    #[inline]
+    fn month0(&self) -> u32 {
+        self.date.month0()
+    }
+    #[inline]
+    fn day(&self) -> u32 {
+        self.date.day()
+    }
+    #[inline]
+    fn day0(&self) -> u32 {
+        self.date.day0()
+    }
+    #[inline]
+    fn ordinal(&self) -> u32 {
+        self.date.ordinal()
+    }
+    #[inline]
+    fn ordinal0(&self) -> u32 {
+        self.date.ordinal0()
+    }
+    #[inline]
+    fn weekday(&self) -> Weekday {
+        self.date.weekday()
+    }
+    #[inline]
+    fn iso_week(&self) -> IsoWeek {
+        self.date.iso_week()
+    }
+
+    #[inline]
+    fn with_year(&self, year: i32) -> Option<ErasedDate> {
+        self.date
+            .with_year(year)
+            .map(|date| ErasedDate { date: date })
+    }
+
+    #[inline]
+    fn with_month(&self, month: u32) -> Option<ErasedDate> {
+        self.date
+            .with_month(month)
+            .map(|date| ErasedDate { date: date })
+    }
+
+    #[inline]
+    fn with_month0(&self, month0: u32) -> Option<ErasedDate> {
+        self.date
+            .with_month0(month0)
+            .map(|date| ErasedDate { date: date })
+    }
+
+    #[inline]
+    fn with_day(&self, day: u32) -> Option<ErasedDate> {
+        self.date
+            .with_day(day)
+            .map(|date| ErasedDate { date: date })
+    }
+
+    #[inline]
+    fn with_day0(&self, day0: u32) -> Option<ErasedDate> {
+        self.date
+            .with_day0(day0)
+            .map(|date| ErasedDate { date: date })
+    }
+
+    #[inline]
+    fn with_ordinal(&self, ordinal: u32) -> Option<ErasedDate> {
+        self.date
+            .with_ordinal(ordinal)
+            .map(|date| ErasedDate { date: date })
+    }
+
+    #[inline]
+    fn with_ordinal0(&self, ordinal0: u32) -> Option<ErasedDate> {
+        self.date
+            .with_ordinal0(ordinal0)
+            .map(|date| ErasedDate { date: date })
+    }
+}
+
+impl fmt::Debug for ErasedDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.date, f)
+    }
+}
+
+impl fmt::Display for ErasedDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.date, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ErasedDate;
+    use naive::NaiveDate;
+    use offset::{FixedOffset, TimeZone, Utc};
+    use Datelike;
+
+    #[test]
+    fn test_erased_date_snapshot() {
+        // the date in the original time zone is kept, not the UTC date
+        let kst = FixedOffset::east(9 * 3600);
+        let dt = Utc.ymd(2019, 12, 31).and_hms(20, 0, 0).with_timezone(&kst);
+        let dates = [
+            ErasedDate::new(&dt),
+            ErasedDate::new(&dt.date()),
+            ErasedDate::new(&dt.naive_local()),
+            ErasedDate::new(&NaiveDate::from_ymd(2020, 1, 1)),
+        ];
+        for d in &dates {
+            assert_eq!(d.naive_date(), NaiveDate::from_ymd(2020, 1, 1));
+            assert_eq!(d.to_string(), "2020-01-01");
+            assert_eq!(d.iso_week(), NaiveDate::from_ymd(2020, 1, 1).iso_week());
+        }
+
+        let d = dates[0];
+        assert_eq!(d.with_day(31).map(|d| d.ordinal()), Some(31));
+        assert_eq!(d.with_month(2).and_then(|d| d.with_day(30)), None);
+        assert_eq!(
+            d.with_year(2019),
+            Some(ErasedDate::new(&Utc.ymd(2019, 1, 1)))
+        );
+        assert_eq!(format!("{:?}", d), "2020-01-01");
+    }
+}
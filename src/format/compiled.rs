@@ -0,0 +1,365 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Formatting items prepared once for parsing many strings in the same format.
+
+use super::{parse, Item, Numeric, ParseResult, Parsed};
+use naive::{NaiveDate, NaiveDateTime, NaiveTime};
+
+/// A date and time field read by the fast path, which also indexes the values read by `parse_fast`.
+#[derive(Clone, Copy, Debug)]
+enum Field {
+    Year,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    Second,
+}
+
+/// A single step of the fast path.
+#[derive(Clone, Debug)]
+enum Step {
+    /// Reads the given string.
+    Literal(Box<str>),
+    /// Reads the given byte, a common special case of `Literal`.
+    Byte(u8),
+    /// Reads zero or more whitespace, as `Item::Space` does.
+    Space,
+    /// Reads exactly the given number of ASCII digits into the field.
+    Digits(Field, usize),
+}
+
+/// Formatting items prepared for parsing, made once and reused for every input.
+///
+/// Parsing with `Compiled` gives exactly the same results and errors as
+/// [`parse`](./fn.parse.html) with the same items,
+/// but the format string is read only once and parsing doesn't allocate.
+///
+/// When the items consist only of the full year (`%Y`), month (`%m`), day (`%d`),
+/// hour (`%H`), minute (`%M`) and optionally second (`%S`), each at most once,
+/// together with literals and whitespace (e.g. `%Y-%m-%d %H:%M:%S`),
+/// an input with every number written in full width (like `2019-07-08 09:10:11`)
+/// is read by a much faster specialized routine.
+/// Any other input, including an invalid one, takes the generic path.
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::NaiveDate;
+/// use chrono::format::{Compiled, StrftimeItems};
+///
+/// let compiled = Compiled::new(StrftimeItems::new("%Y-%m-%d %H:%M:%S"));
+/// assert_eq!(compiled.parse_naive_datetime("2019-07-08 09:10:11"),
+///            Ok(NaiveDate::from_ymd(2019, 7, 8).and_hms(9, 10, 11)));
+/// // not in full width, but still valid for the generic path
+/// assert_eq!(compiled.parse_naive_datetime("2019-7-8 9:10:11"),
+///            Ok(NaiveDate::from_ymd(2019, 7, 8).and_hms(9, 10, 11)));
+/// assert!(compiled.parse_naive_datetime("2019-07-08 24:10:11").is_err());
+/// ~~~~
+#[derive(Clone, Debug)]
+pub struct Compiled<'a> {
+    items: Vec<Item<'a>>,
+    fast: Option<Vec<Step>>,
+}
+
+impl<'a> Compiled<'a> {
+    /// Prepares given formatting items for parsing.
+    pub fn new<I>(items: I) -> Compiled<'a>
+    where
+        I: Iterator<Item = Item<'a>>,
+    {
+        let items: Vec<Item<'a>> = items.collect();
+        let fast = fast_path(&items);
+        Compiled {
+            items: items,
+            fast: fast,
+        }
+    }
+
+    /// Parses a string into a `NaiveDateTime`,
+    /// like [`NaiveDateTime::parse_from_str`](../naive/struct.NaiveDateTime.html#method.parse_from_str)
+    /// with the format string these items came from.
+    pub fn parse_naive_datetime(&self, s: &str) -> ParseResult<NaiveDateTime> {
+        if let Some(ref steps) = self.fast {
+            if let Some(datetime) = parse_fast(steps, s) {
+                return Ok(datetime);
+            }
+        }
+
+        let mut parsed = Parsed::new();
+        try!(parse(&mut parsed, s, self.items.iter().map(borrow_item)));
+        parsed.to_naive_datetime_with_offset(0) // no offset adjustment
+    }
+}
+
+/// Returns the item with any owned string borrowed, which is cheaper to clone.
+fn borrow_item<'b>(item: &'b Item) -> Item<'b> {
+    match *item {
+        Item::OwnedLiteral(ref s) => Item::Literal(&s[..]),
+        Item::OwnedSpace(ref s) => Item::Space(&s[..]),
+        ref item => item.clone(),
+    }
+}
+
+/// Returns the steps of the fast path for given items, or `None` if they can't use it.
+fn fast_path(items: &[Item]) -> Option<Vec<Step>> {
+    let mut steps = Vec::with_capacity(items.len());
+    let mut seen = [false; 6];
+    for item in items {
+        let step = match *item {
+            Item::Literal(s) => literal_step(s),
+            Item::OwnedLiteral(ref s) => literal_step(s),
+            Item::Space(_) | Item::OwnedSpace(_) => Step::Space,
+            Item::Numeric(ref spec, _) => {
+                let (field, width) = match *spec {
+                    Numeric::Year => (Field::Year, 4),
+                    Numeric::Month => (Field::Month, 2),
+                    Numeric::Day => (Field::Day, 2),
+                    Numeric::Hour => (Field::Hour, 2),
+                    Numeric::Minute => (Field::Minute, 2),
+                    Numeric::Second => (Field::Second, 2),
+                    _ => return None,
+                };
+                // a repeated field should be checked for consistency by the generic path
+                if seen[field as usize] {
+                    return None;
+                }
+                seen[field as usize] = true;
+                Step::Digits(field, width)
+            }
+            Item::Fixed(_) | Item::Error => return None,
+        };
+        steps.push(step);
+    }
+
+    // every field but the second is required for a `NaiveDateTime`
+    if seen[..5].iter().all(|&seen| seen) {
+        Some(steps)
+    } else {
+        None
+    }
+}
+
+/// Returns the step reading the literal `s`.
+fn literal_step(s: &str) -> Step {
+    if s.len() == 1 {
+        Step::Byte(s.as_bytes()[0])
+    } else {
+        Step::Literal(s.to_owned().into_boxed_str())
+    }
+}
+
+/// Parses a string with every number in full width along the fast path.
+///
+/// Returns `None` for anything else, which is left to the generic path
+/// so that it reports the same error (or result) as it would otherwise do.
+fn parse_fast(steps: &[Step], s: &str) -> Option<NaiveDateTime> {
+    let s = s.as_bytes();
+    let mut pos = 0;
+    // indexed by `Field`
+    let mut values = [0u32; 6];
+    for step in steps {
+        match *step {
+            Step::Literal(ref prefix) => {
+                let end = pos + prefix.len();
+                if end > s.len() || &s[pos..end] != prefix.as_bytes() {
+                    return None;
+                }
+                pos = end;
+            }
+            Step::Byte(b) => {
+                if pos >= s.len() || s[pos] != b {
+                    return None;
+                }
+                pos += 1;
+            }
+            Step::Space => {
+                while pos < s.len() {
+                    match s[pos] {
+                        b' ' | b'\t' | b'\n' | b'\r' | b'\x0b' | b'\x0c' => pos += 1,
+                        // any other whitespace is left to the generic path
+                        0x80...0xff => return None,
+                        _ => break,
+                    }
+                }
+            }
+            Step::Digits(field, width) => {
+                let end = pos + width;
+                if end > s.len() {
+                    return None;
+                }
+                let mut value = 0;
+                for &c in &s[pos..end] {
+                    if c < b'0' || b'9' < c {
+                        return None;
+                    }
+                    value = value * 10 + u32::from(c - b'0');
+                }
+                values[field as usize] = value;
+                pos = end;
+            }
+        }
+    }
+    if pos < s.len() {
+        return None;
+    }
+
+    let date = try_opt!(NaiveDate::from_ymd_opt(
+        values[Field::Year as usize] as i32,
+        values[Field::Month as usize],
+        values[Field::Day as usize],
+    ));
+    // as in `Parsed::to_naive_time`, the second 60 is a leap second
+    let (second, nano) = match values[Field::Second as usize] {
+        60 => (59, 1_000_000_000),
+        second => (second, 0),
+    };
+    let time = try_opt!(NaiveTime::from_hms_nano_opt(
+        values[Field::Hour as usize],
+        values[Field::Minute as usize],
+        second,
+        nano
+    ));
+    Some(date.and_time(time))
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(bench)]
+    extern crate test;
+
+    use super::Compiled;
+    use format::{Fixed, Item, Numeric, Pad, StrftimeItems};
+    use naive::{NaiveDate, NaiveDateTime};
+
+    #[test]
+    fn test_compiled_parse_naive_datetime() {
+        let fmts = [
+            "%Y-%m-%d %H:%M:%S",
+            "%Y-%m-%d %H:%M",
+            "%Y%m%d%H%M%S",
+            "%d/%m/%Y %H:%M:%S",
+            "%Y-%m-%dT%H:%M:%S%.f", // the generic path only
+            "%Y-%m-%d %H:%M:%S %Y", // the repeated field needs the generic path
+            "%Y-%j %H:%M:%S",       // the generic path only
+            "%Y-%m-%d %H:%M:%S%Z",  // not parsable
+        ];
+        let inputs = [
+            "2019-07-08 09:10:11",
+            "2019-07-08 09:10",
+            "20190708091011",
+            "08/07/2019 09:10:11",
+            "2019-07-08T09:10:11.123",
+            "2019-07-08 09:10:11 2019",
+            "2019-07-08 09:10:11 2020",
+            "2019-189 09:10:11",
+            "2019-7-8 9:10:11",
+            "2019-07-08  09:10:11",
+            " 2019-07-08 09:10:11",
+            "2019-07-08 09:10:11 ",
+            "2019-07-08 09:10:1",
+            "2019-07-08 09:10:111",
+            "2019-07-08 09:10:xx",
+            "2019-07-08 09:10:",
+            "2019-07-08",
+            "12019-07-08 09:10:11",
+            "+2019-07-08 09:10:11",
+            "-2019-07-08 09:10:11",
+            "0000-01-01 00:00:00",
+            "2019-02-29 09:10:11",
+            "2020-02-29 09:10:11",
+            "2019-13-08 09:10:11",
+            "2019-00-08 09:10:11",
+            "2019-07-32 09:10:11",
+            "2019-07-08 24:10:11",
+            "2019-07-08 09:60:11",
+            "2019-07-08 23:59:60",
+            "2019-07-08 09:10:61",
+            "2019-07-08 09:10:99",
+            "2019-07-08\t09:10:11",
+            "2019-07-08\u{3000}09:10:11",
+            "2019-07-08 \u{e9}09:10:11",
+            "2019\u{2013}07-08 09:10:11",
+            "",
+        ];
+        for fmt in &fmts {
+            let compiled = Compiled::new(StrftimeItems::new(fmt));
+            for s in &inputs {
+                assert_eq!(
+                    compiled.parse_naive_datetime(s),
+                    NaiveDateTime::parse_from_str(s, fmt),
+                    "parsing {:?} with {:?}",
+                    s,
+                    fmt
+                );
+            }
+        }
+
+        let compiled = Compiled::new(StrftimeItems::new("%Y-%m-%d %H:%M:%S"));
+        assert_eq!(
+            compiled.parse_naive_datetime("2019-07-08 23:59:60"),
+            Ok(NaiveDate::from_ymd(2019, 7, 8).and_hms_milli(23, 59, 59, 1_000))
+        );
+    }
+
+    #[test]
+    fn test_compiled_owned_items() {
+        let items = vec![
+            Item::Numeric(Numeric::Year, Pad::Zero),
+            Item::OwnedLiteral("--".to_owned().into_boxed_str()),
+            Item::Numeric(Numeric::Month, Pad::Zero),
+            Item::OwnedLiteral("--".to_owned().into_boxed_str()),
+            Item::Numeric(Numeric::Day, Pad::Zero),
+            Item::OwnedSpace(" ".to_owned().into_boxed_str()),
+            Item::Numeric(Numeric::Hour, Pad::Zero),
+            Item::Numeric(Numeric::Minute, Pad::Zero),
+        ];
+        let compiled = Compiled::new(items.into_iter());
+        let expected = NaiveDate::from_ymd(2019, 7, 8).and_hms(9, 10, 0);
+        assert_eq!(
+            compiled.parse_naive_datetime("2019--07--08 0910"),
+            Ok(expected)
+        );
+        assert_eq!(
+            compiled.parse_naive_datetime("2019--7--8 \t0910"),
+            Ok(expected)
+        );
+        assert!(compiled.parse_naive_datetime("2019-07-08 0910").is_err());
+
+        let compiled = Compiled::new(vec![Item::Fixed(Fixed::RFC3339)].into_iter());
+        assert_eq!(
+            compiled.parse_naive_datetime("2019-07-08T09:10:00+09:00"),
+            Ok(expected)
+        );
+    }
+
+    #[cfg(bench)]
+    static INPUTS: [&'static str; 4] = [
+        "2019-07-08 09:10:11",
+        "1970-01-01 00:00:00",
+        "2038-01-19 03:14:07",
+        "1999-12-31 23:59:59",
+    ];
+
+    #[cfg(bench)]
+    #[bench]
+    fn bench_parse_from_str(bh: &mut test::Bencher) {
+        bh.iter(|| {
+            for s in &INPUTS {
+                let _ = test::black_box(NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S"));
+            }
+        });
+    }
+
+    #[cfg(bench)]
+    #[bench]
+    fn bench_compiled_parse_naive_datetime(bh: &mut test::Bencher) {
+        let compiled = Compiled::new(StrftimeItems::new("%Y-%m-%d %H:%M:%S"));
+        bh.iter(|| {
+            for s in &INPUTS {
+                let _ = test::black_box(compiled.parse_naive_datetime(s));
+            }
+        });
+    }
+}
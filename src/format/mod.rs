@@ -14,6 +14,27 @@
 //! They are generated from more readable **format strings**;
 //! currently Chrono supports [one built-in syntax closely resembling
 //! C's `strftime` format](./strftime/index.html).
+//!
+//! The formatting items can also be constructed directly, without any format string,
+//! from [`Numeric`](./enum.Numeric.html) (numbers like years or hours)
+//! and [`Fixed`](./enum.Fixed.html) (names and other fixed-format items like offsets).
+//!
+//! ```rust
+//! use chrono::{TimeZone, Utc};
+//! use chrono::format::{Fixed, Item, Numeric, Pad};
+//!
+//! let items = [
+//!     Item::Fixed(Fixed::ShortWeekdayName),
+//!     Item::Literal(", "),
+//!     Item::Numeric(Numeric::Day, Pad::None),
+//!     Item::Space(" "),
+//!     Item::Fixed(Fixed::LongMonthName),
+//!     Item::Space(" "),
+//!     Item::Numeric(Numeric::Year, Pad::Zero),
+//! ];
+//! let dt = Utc.ymd(2019, 10, 5).and_hms(9, 10, 11);
+//! assert_eq!(dt.format_with_items(items.iter().cloned()).to_string(), "Sat, 5 October 2019");
+//! ```
 
 #![allow(ellipsis_inclusive_range_patterns)]
 
@@ -26,6 +47,7 @@ use naive::{NaiveDate, NaiveTime};
 use offset::{FixedOffset, Offset};
 use {Datelike, ParseWeekdayError, Timelike, Weekday};
 
+pub use self::compiled::Compiled;
 pub use self::parse::parse;
 pub use self::parsed::Parsed;
 pub use self::strftime::StrftimeItems;
@@ -178,9 +200,11 @@ pub enum Fixed {
     Nanosecond6,
     /// Same to [`Nanosecond`](#variant.Nanosecond) but the accuracy is fixed to 9.
     Nanosecond9,
-    /// Timezone name.
+    /// Timezone name, i.e. the [abbreviation](../offset/trait.Offset.html#method.abbreviation)
+    /// of the offset (`UTC` or `JST`), or the offset (`+09:00`) if the time zone has no name.
     ///
-    /// It does not support parsing, its use in the parser is an immediate failure.
+    /// In the parser, it consumes one or more ASCII letters but doesn't give any offset,
+    /// as the abbreviations are ambiguous.
     TimezoneName,
     /// Offset from the local time to UTC (`+09:00` or `-04:00` or `+00:00`).
     ///
@@ -236,6 +260,9 @@ enum InternalInternal {
     Nanosecond6NoDot,
     /// Same to [`Nanosecond`](#variant.Nanosecond) but the accuracy is fixed to 9 and there is no leading dot.
     Nanosecond9NoDot,
+    /// English ordinal suffix for the day of month (`st`, `nd`, `rd` or `th`).
+    /// Any of them is accepted and discarded in parsing.
+    DayOrdinalSuffix,
 }
 
 /// A single formatting item. This is used for both formatting and parsing.
@@ -363,6 +390,31 @@ const TOO_SHORT: ParseError = ParseError(ParseErrorKind::TooShort);
 const TOO_LONG: ParseError = ParseError(ParseErrorKind::TooLong);
 const BAD_FORMAT: ParseError = ParseError(ParseErrorKind::BadFormat);
 
+/// Returns the English ordinal suffix for given number, i.e. `st`, `nd`, `rd` or `th`.
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::format::ordinal_suffix;
+///
+/// assert_eq!(ordinal_suffix(1), "st");
+/// assert_eq!(ordinal_suffix(2), "nd");
+/// assert_eq!(ordinal_suffix(3), "rd");
+/// assert_eq!(ordinal_suffix(4), "th");
+/// assert_eq!(ordinal_suffix(11), "th");
+/// assert_eq!(ordinal_suffix(22), "nd");
+/// assert_eq!(ordinal_suffix(113), "th");
+/// ~~~~
+pub fn ordinal_suffix(n: u32) -> &'static str {
+    match (n % 10, n % 100) {
+        (_, 11) | (_, 12) | (_, 13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    }
+}
+
 /// Tries to format given arguments with given formatting items.
 /// Internally used by `DelayedFormat`.
 pub fn format<'a, I>(
@@ -375,35 +427,7 @@ pub fn format<'a, I>(
 where
     I: Iterator<Item = Item<'a>>,
 {
-    // full and abbreviated month and weekday names
-    static SHORT_MONTHS: [&'static str; 12] = [
-        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
-    ];
-    static LONG_MONTHS: [&'static str; 12] = [
-        "January",
-        "February",
-        "March",
-        "April",
-        "May",
-        "June",
-        "July",
-        "August",
-        "September",
-        "October",
-        "November",
-        "December",
-    ];
-    static SHORT_WEEKDAYS: [&'static str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
-    static LONG_WEEKDAYS: [&'static str; 7] = [
-        "Monday",
-        "Tuesday",
-        "Wednesday",
-        "Thursday",
-        "Friday",
-        "Saturday",
-        "Sunday",
-    ];
-
+    use names::{LONG_MONTHS, LONG_WEEKDAYS, SHORT_MONTHS, SHORT_WEEKDAYS};
     use std::fmt::Write;
     let mut result = String::new();
 
@@ -416,10 +440,10 @@ where
                 use self::Numeric::*;
 
                 let week_from_sun = |d: &NaiveDate| {
-                    (d.ordinal() as i32 - d.weekday().num_days_from_sunday() as i32 + 7) / 7
+                    (d.ordinal() as i32 - d.weekday().num_days_from_sunday() as i32 + 6) / 7
                 };
                 let week_from_mon = |d: &NaiveDate| {
-                    (d.ordinal() as i32 - d.weekday().num_days_from_monday() as i32 + 7) / 7
+                    (d.ordinal() as i32 - d.weekday().num_days_from_monday() as i32 + 6) / 7
                 };
 
                 let (width, v) = match spec {
@@ -586,6 +610,12 @@ where
                         let nano = t.nanosecond() % 1_000_000_000;
                         write!(result, "{:09}", nano)
                     }),
+                    Internal(InternalFixed {
+                        val: InternalInternal::DayOrdinalSuffix,
+                    }) => date.map(|d| {
+                        result.push_str(ordinal_suffix(d.day()));
+                        Ok(())
+                    }),
                     TimezoneName => off.map(|&(ref name, _)| {
                         result.push_str(name);
                         Ok(())
@@ -653,12 +683,15 @@ where
     w.pad(&result)
 }
 
+mod compiled;
 mod parsed;
 
 // due to the size of parsing routines, they are in separate modules.
 mod parse;
 mod scan;
 
+#[cfg(feature = "relative")]
+pub mod relative;
 pub mod strftime;
 
 /// A *temporary* object which can be used as an argument to `format!` or others.
@@ -696,7 +729,11 @@ impl<'a, I: Iterator<Item = Item<'a>> + Clone> DelayedFormat<I> {
     where
         Off: Offset + fmt::Display,
     {
-        let name_and_diff = (offset.to_string(), offset.fix());
+        let name = match offset.abbreviation() {
+            Some(name) => name.to_owned(),
+            None => offset.to_string(),
+        };
+        let name_and_diff = (name, offset.fix());
         DelayedFormat {
             date: date,
             time: time,
@@ -392,7 +392,23 @@ where
                         try!(parsed.set_nanosecond(nano));
                     }
 
-                    TimezoneName => return Err(BAD_FORMAT),
+                    Internal(InternalFixed {
+                        val: InternalInternal::DayOrdinalSuffix,
+                    }) => {
+                        if s.len() < 2 {
+                            return Err(TOO_SHORT);
+                        }
+                        match &s.as_bytes()[..2] {
+                            b"st" | b"nd" | b"rd" | b"th" | b"ST" | b"ND" | b"RD" | b"TH" => {}
+                            _ => return Err(INVALID),
+                        }
+                        s = &s[2..];
+                    }
+
+                    // the name is too ambiguous to give an offset, so it is only skipped
+                    TimezoneName => {
+                        s = try!(scan::timezone_name(s.trim_left()));
+                    }
 
                     TimezoneOffsetColon | TimezoneOffset => {
                         let offset = try_consume!(scan::timezone_offset(
@@ -652,6 +668,19 @@ fn test_parse() {
     check!("        4",    [internal_fix!(Nanosecond9NoDot)]; INVALID);
     check!(".42100000",    [internal_fix!(Nanosecond9NoDot)]; INVALID);
 
+    // fixed: ordinal suffix
+    check!("st",   [internal_fix!(DayOrdinalSuffix)]; );
+    check!("nd",   [internal_fix!(DayOrdinalSuffix)]; );
+    check!("rd",   [internal_fix!(DayOrdinalSuffix)]; );
+    check!("TH",   [internal_fix!(DayOrdinalSuffix)]; );
+    check!("3th",  [num!(Day), internal_fix!(DayOrdinalSuffix)]; day: 3); // not checked
+    check!("21st", [num!(Day), internal_fix!(DayOrdinalSuffix)]; day: 21);
+    check!("21",   [num!(Day), internal_fix!(DayOrdinalSuffix)]; TOO_SHORT);
+    check!("t",    [internal_fix!(DayOrdinalSuffix)]; TOO_SHORT);
+    check!("sd",   [internal_fix!(DayOrdinalSuffix)]; INVALID);
+    check!("Th",   [internal_fix!(DayOrdinalSuffix)]; INVALID);
+    check!("ths",  [internal_fix!(DayOrdinalSuffix)]; TOO_LONG);
+
     // fixed: timezone offsets
     check!("+00:00",    [fix!(TimezoneOffset)]; offset: 0);
     check!("-00:00",    [fix!(TimezoneOffset)]; offset: 0);
@@ -694,7 +723,17 @@ fn test_parse() {
     check!("z",         [internal_fix!(TimezoneOffsetPermissive)]; offset: 0);
     check!("+12:00",    [internal_fix!(TimezoneOffsetPermissive)]; offset: 12 * 60 * 60);
     check!("+12",       [internal_fix!(TimezoneOffsetPermissive)]; offset: 12 * 60 * 60);
-    check!("???",       [fix!(TimezoneName)]; BAD_FORMAT); // not allowed
+    check!("KST",       [fix!(TimezoneName)]; ); // doesn't give an offset
+    check!("  utc",     [fix!(TimezoneName)]; );
+    check!("KST+09:00", [fix!(TimezoneName), fix!(TimezoneOffset)]; offset: 9 * 60 * 60);
+    check!("KST +0900", [fix!(TimezoneName), sp!(" "), fix!(TimezoneOffset)]; offset: 9 * 60 * 60);
+    check!("",          [fix!(TimezoneName)]; TOO_SHORT);
+    check!("???",       [fix!(TimezoneName)]; INVALID);
+    check!("+09:00",    [fix!(TimezoneName)]; ); // the offset printed for unknown names
+    check!("-0930",     [fix!(TimezoneName)]; );
+    check!("+09",       [fix!(TimezoneName)]; TOO_SHORT);
+    check!("+09:00 +09:00", [fix!(TimezoneName), sp!(" "), fix!(TimezoneOffsetColon)];
+           offset: 9 * 60 * 60);
 
     // some practical examples
     check!("2015-02-04T14:37:05+09:00",
@@ -0,0 +1,406 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+/*!
+Parsing of relative, human-readable date and time expressions.
+
+This module is only available with the `relative` feature.
+Every expression is resolved against an explicit reference `DateTime`,
+so the result never depends on the current time.
+
+## Expressions
+
+The following expressions are recognized case-insensitively.
+
+| Expression                       | Meaning                                                    |
+|----------------------------------|------------------------------------------------------------|
+| `yesterday`, `today`, `tomorrow` | The reference time of day on the previous, same or next day. |
+| `next <weekday>`                 | The reference time of day on the first such weekday after the reference date. |
+| `last <weekday>`                 | The reference time of day on the last such weekday before the reference date. |
+| `midnight`, `noon`               | 00:00:00 or 12:00:00 on the reference date.                |
+| `in <N> <unit>`                  | `N` units after the reference time.                        |
+| `<N> <unit> ago`                 | `N` units before the reference time.                       |
+
+The day expressions (the first three rows) can be followed by `midnight` or `noon`
+to replace the time of day, as in `tomorrow noon`.
+
+`<N>` is a non-negative decimal number or `a`/`an` (meaning 1).
+`<unit>` is one of `second`, `minute`, `hour`, `day` or `week`, optionally in plural.
+Days and weeks move the local date and keep the local time of day,
+while shorter units move the absolute time.
+
+The vocabulary is kept in tables so that other languages can be added later.
+*/
+
+use oldtime::Duration as OldDuration;
+
+use super::scan;
+use super::{ParseResult, IMPOSSIBLE, INVALID, OUT_OF_RANGE, TOO_LONG, TOO_SHORT};
+use naive::{NaiveDateTime, NaiveTime};
+use offset::TimeZone;
+use {DateTime, Datelike};
+
+/// Units accepted by the `in <N> <unit>` and `<N> <unit> ago` expressions.
+#[derive(Clone, Copy)]
+enum Unit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+}
+
+// all words should be in lower case.
+static DAY_WORDS: [(&'static str, i64); 3] = [("yesterday", -1), ("today", 0), ("tomorrow", 1)];
+static DIRECTION_WORDS: [(&'static str, i64); 2] = [("next", 1), ("last", -1)];
+static TIME_WORDS: [(&'static str, u32); 2] = [("midnight", 0), ("noon", 12)];
+static NUMBER_WORDS: [(&'static str, i64); 2] = [("a", 1), ("an", 1)];
+static UNIT_WORDS: [(&'static str, Unit); 10] = [
+    ("second", Unit::Second),
+    ("seconds", Unit::Second),
+    ("minute", Unit::Minute),
+    ("minutes", Unit::Minute),
+    ("hour", Unit::Hour),
+    ("hours", Unit::Hour),
+    ("day", Unit::Day),
+    ("days", Unit::Day),
+    ("week", Unit::Week),
+    ("weeks", Unit::Week),
+];
+static FUTURE_WORD: &'static str = "in";
+static PAST_WORD: &'static str = "ago";
+
+/// Finds a word in given vocabulary table case-insensitively.
+fn lookup<T: Copy>(table: &[(&'static str, T)], word: &str) -> Option<T> {
+    table
+        .iter()
+        .find(|&&(name, _)| scan::equals(word, name))
+        .map(|&(_, v)| v)
+}
+
+/// Parses a count for the `in <N> <unit>` and `<N> <unit> ago` expressions.
+fn count(word: Option<&str>) -> ParseResult<i64> {
+    let word = match word {
+        Some(word) => word,
+        None => return Err(TOO_SHORT),
+    };
+    if let Some(n) = lookup(&NUMBER_WORDS, word) {
+        return Ok(n);
+    }
+    match try!(scan::number(word, 1, usize::max_value())) {
+        ("", n) => Ok(n),
+        _ => Err(INVALID),
+    }
+}
+
+/// Parses a unit for the `in <N> <unit>` and `<N> <unit> ago` expressions.
+fn unit(word: Option<&str>) -> ParseResult<Unit> {
+    match word {
+        Some(word) => lookup(&UNIT_WORDS, word).ok_or(INVALID),
+        None => Err(TOO_SHORT),
+    }
+}
+
+/// Replaces the time of day when an optional time word (`noon` etc.) is given.
+fn with_time_word(local: NaiveDateTime, word: Option<&str>) -> ParseResult<NaiveDateTime> {
+    match word {
+        Some(word) => {
+            let hour = try!(lookup(&TIME_WORDS, word).ok_or(INVALID));
+            Ok(local.date().and_time(NaiveTime::from_hms(hour, 0, 0)))
+        }
+        None => Ok(local),
+    }
+}
+
+/// Moves the local date and time by given number of days.
+fn add_days(local: NaiveDateTime, days: i64) -> ParseResult<NaiveDateTime> {
+    if days.abs() > OldDuration::max_value().num_days() {
+        return Err(OUT_OF_RANGE);
+    }
+    local
+        .checked_add_signed(OldDuration::days(days))
+        .ok_or(OUT_OF_RANGE)
+}
+
+/// Parses a relative date and time expression like `tomorrow`, `next tuesday`, `in 3 days`
+/// or `2 hours ago`, resolved against the `reference` date and time.
+/// See the [module documentation](./index.html) for the full list of expressions.
+///
+/// The result keeps the time zone of `reference`.
+/// It is an error if the resulting local date and time does not exist or is ambiguous
+/// in that time zone.
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::{Utc, TimeZone};
+/// use chrono::format::relative::parse_relative;
+///
+/// // a Wednesday
+/// let now = Utc.ymd(2019, 10, 16).and_hms(15, 30, 0);
+/// assert_eq!(parse_relative("tomorrow", &now), Ok(Utc.ymd(2019, 10, 17).and_hms(15, 30, 0)));
+/// assert_eq!(parse_relative("yesterday noon", &now),
+///            Ok(Utc.ymd(2019, 10, 15).and_hms(12, 0, 0)));
+/// assert_eq!(parse_relative("next Monday", &now), Ok(Utc.ymd(2019, 10, 21).and_hms(15, 30, 0)));
+/// assert_eq!(parse_relative("last wed", &now), Ok(Utc.ymd(2019, 10, 9).and_hms(15, 30, 0)));
+/// assert_eq!(parse_relative("in 3 days", &now), Ok(Utc.ymd(2019, 10, 19).and_hms(15, 30, 0)));
+/// assert_eq!(parse_relative("an hour ago", &now), Ok(Utc.ymd(2019, 10, 16).and_hms(14, 30, 0)));
+/// assert!(parse_relative("the day after tomorrow", &now).is_err());
+/// ~~~~
+pub fn parse_relative<Tz: TimeZone>(
+    s: &str,
+    reference: &DateTime<Tz>,
+) -> ParseResult<DateTime<Tz>> {
+    let mut words = s.split_whitespace();
+    let first = match words.next() {
+        Some(word) => word,
+        None => return Err(TOO_SHORT),
+    };
+
+    let local = reference.naive_local();
+    let (n, unit) = if let Some(days) = lookup(&DAY_WORDS, first) {
+        let local = try!(add_days(local, days));
+        let local = try!(with_time_word(local, words.next()));
+        return finish(words.next(), reference, local);
+    } else if let Some(dir) = lookup(&DIRECTION_WORDS, first) {
+        let weekday = match words.next() {
+            Some(word) => match try!(scan::short_or_long_weekday(word)) {
+                ("", weekday) => weekday,
+                _ => return Err(INVALID),
+            },
+            None => return Err(TOO_SHORT),
+        };
+        let from = i64::from(local.weekday().num_days_from_monday());
+        let to = i64::from(weekday.num_days_from_monday());
+        let days = if dir > 0 {
+            (to - from + 6) % 7 + 1
+        } else {
+            -((from - to + 6) % 7 + 1)
+        };
+        let local = try!(add_days(local, days));
+        let local = try!(with_time_word(local, words.next()));
+        return finish(words.next(), reference, local);
+    } else if lookup(&TIME_WORDS, first).is_some() {
+        let local = try!(with_time_word(local, Some(first)));
+        return finish(words.next(), reference, local);
+    } else if scan::equals(first, FUTURE_WORD) {
+        let n = try!(count(words.next()));
+        (n, try!(unit(words.next())))
+    } else {
+        let n = try!(count(Some(first)));
+        let unit = try!(unit(words.next()));
+        match words.next() {
+            Some(word) if scan::equals(word, PAST_WORD) => (-n, unit),
+            Some(_) => return Err(INVALID),
+            None => return Err(TOO_SHORT),
+        }
+    };
+
+    if words.next().is_some() {
+        return Err(TOO_LONG);
+    }
+    let secs = match unit {
+        Unit::Day => return finish(None, reference, try!(add_days(local, n))),
+        Unit::Week => {
+            let days = try!(n.checked_mul(7).ok_or(OUT_OF_RANGE));
+            return finish(None, reference, try!(add_days(local, days)));
+        }
+        Unit::Second => Some(n),
+        Unit::Minute => n.checked_mul(60),
+        Unit::Hour => n.checked_mul(3600),
+    };
+    let secs = try!(secs.ok_or(OUT_OF_RANGE));
+    if secs.abs() > OldDuration::max_value().num_seconds() {
+        return Err(OUT_OF_RANGE);
+    }
+    reference
+        .clone()
+        .checked_add_signed(OldDuration::seconds(secs))
+        .ok_or(OUT_OF_RANGE)
+}
+
+/// Converts the resulting local date and time back to the reference time zone,
+/// making sure that no input is left.
+fn finish<Tz: TimeZone>(
+    rest: Option<&str>,
+    reference: &DateTime<Tz>,
+    local: NaiveDateTime,
+) -> ParseResult<DateTime<Tz>> {
+    if rest.is_some() {
+        return Err(TOO_LONG);
+    }
+    reference
+        .timezone()
+        .from_local_datetime(&local)
+        .single()
+        .ok_or(IMPOSSIBLE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_relative;
+    use format::{IMPOSSIBLE, INVALID, OUT_OF_RANGE, TOO_LONG, TOO_SHORT};
+    use offset::{FixedOffset, TimeZone, Utc};
+    use test_zones::Dst;
+    use DateTime;
+
+    #[test]
+    fn test_parse_relative_days() {
+        let now = Utc.ymd(2019, 10, 16).and_hms_milli(15, 30, 0, 250);
+        let at = |y, m, d, h, n, s| Ok(Utc.ymd(y, m, d).and_hms_milli(h, n, s, 250));
+        let at0 = |y, m, d, h| Ok(Utc.ymd(y, m, d).and_hms(h, 0, 0));
+
+        assert_eq!(parse_relative("today", &now), at(2019, 10, 16, 15, 30, 0));
+        assert_eq!(
+            parse_relative("  Yesterday ", &now),
+            at(2019, 10, 15, 15, 30, 0)
+        );
+        assert_eq!(
+            parse_relative("TOMORROW", &now),
+            at(2019, 10, 17, 15, 30, 0)
+        );
+        assert_eq!(parse_relative("noon", &now), at0(2019, 10, 16, 12));
+        assert_eq!(parse_relative("midnight", &now), at0(2019, 10, 16, 0));
+        assert_eq!(
+            parse_relative("tomorrow midnight", &now),
+            at0(2019, 10, 17, 0)
+        );
+        assert_eq!(parse_relative("today noon", &now), at0(2019, 10, 16, 12));
+
+        // 2019-10-16 is a Wednesday
+        assert_eq!(
+            parse_relative("next thu", &now),
+            at(2019, 10, 17, 15, 30, 0)
+        );
+        assert_eq!(
+            parse_relative("next wednesday", &now),
+            at(2019, 10, 23, 15, 30, 0)
+        );
+        assert_eq!(
+            parse_relative("next tuesday", &now),
+            at(2019, 10, 22, 15, 30, 0)
+        );
+        assert_eq!(
+            parse_relative("last tue", &now),
+            at(2019, 10, 15, 15, 30, 0)
+        );
+        assert_eq!(
+            parse_relative("last Wednesday", &now),
+            at(2019, 10, 9, 15, 30, 0)
+        );
+        assert_eq!(
+            parse_relative("last thursday", &now),
+            at(2019, 10, 10, 15, 30, 0)
+        );
+        assert_eq!(parse_relative("next sun noon", &now), at0(2019, 10, 20, 12));
+    }
+
+    #[test]
+    fn test_parse_relative_units() {
+        let now = Utc.ymd(2019, 10, 16).and_hms(15, 30, 0);
+        let at = |y, m, d, h, n, s| Ok(Utc.ymd(y, m, d).and_hms(h, n, s));
+
+        assert_eq!(parse_relative("in 0 seconds", &now), Ok(now));
+        assert_eq!(
+            parse_relative("in 1 second", &now),
+            at(2019, 10, 16, 15, 30, 1)
+        );
+        assert_eq!(
+            parse_relative("in 45 minutes", &now),
+            at(2019, 10, 16, 16, 15, 0)
+        );
+        assert_eq!(
+            parse_relative("in an hour", &now),
+            at(2019, 10, 16, 16, 30, 0)
+        );
+        assert_eq!(
+            parse_relative("in 3 days", &now),
+            at(2019, 10, 19, 15, 30, 0)
+        );
+        assert_eq!(
+            parse_relative("in 2 weeks", &now),
+            at(2019, 10, 30, 15, 30, 0)
+        );
+        assert_eq!(
+            parse_relative("10 seconds ago", &now),
+            at(2019, 10, 16, 15, 29, 50)
+        );
+        assert_eq!(
+            parse_relative("a minute ago", &now),
+            at(2019, 10, 16, 15, 29, 0)
+        );
+        assert_eq!(
+            parse_relative("36 hours ago", &now),
+            at(2019, 10, 15, 3, 30, 0)
+        );
+        assert_eq!(
+            parse_relative("1 day ago", &now),
+            at(2019, 10, 15, 15, 30, 0)
+        );
+        assert_eq!(
+            parse_relative("5 Weeks AGO", &now),
+            at(2019, 9, 11, 15, 30, 0)
+        );
+    }
+
+    #[test]
+    fn test_parse_relative_offset() {
+        let tz = FixedOffset::east(9 * 3600);
+        let now = tz.ymd(2019, 10, 16).and_hms(23, 30, 0);
+        assert_eq!(
+            parse_relative("tomorrow", &now),
+            Ok(tz.ymd(2019, 10, 17).and_hms(23, 30, 0))
+        );
+        assert_eq!(
+            parse_relative("noon", &now),
+            Ok(tz.ymd(2019, 10, 16).and_hms(12, 0, 0))
+        );
+        assert_eq!(
+            parse_relative("in 1 hour", &now),
+            Ok(tz.ymd(2019, 10, 17).and_hms(0, 30, 0))
+        );
+    }
+
+    #[test]
+    fn test_parse_relative_nonexistent_local_time() {
+        // 02:00 to 03:00 does not exist on 2019-03-31
+        let now = Dst.ymd(2019, 3, 30).and_hms(2, 30, 0);
+        assert_eq!(parse_relative("tomorrow", &now), Err(IMPOSSIBLE));
+        let expected: DateTime<Dst> = Dst.ymd(2019, 3, 31).and_hms(1, 30, 0);
+        assert_eq!(parse_relative("in 23 hours", &now), Ok(expected));
+    }
+
+    #[test]
+    fn test_parse_relative_errors() {
+        let now = Utc.ymd(2019, 10, 16).and_hms(15, 30, 0);
+
+        assert_eq!(parse_relative("", &now), Err(TOO_SHORT));
+        assert_eq!(parse_relative("   ", &now), Err(TOO_SHORT));
+        assert_eq!(parse_relative("next", &now), Err(TOO_SHORT));
+        assert_eq!(parse_relative("in", &now), Err(TOO_SHORT));
+        assert_eq!(parse_relative("in 3", &now), Err(TOO_SHORT));
+        assert_eq!(parse_relative("3 days", &now), Err(TOO_SHORT));
+        assert_eq!(parse_relative("someday", &now), Err(INVALID));
+        assert_eq!(parse_relative("next week", &now), Err(INVALID));
+        assert_eq!(parse_relative("next mondays", &now), Err(INVALID));
+        assert_eq!(parse_relative("tomorrow evening", &now), Err(INVALID));
+        assert_eq!(parse_relative("in -3 days", &now), Err(INVALID));
+        assert_eq!(parse_relative("in 3 fortnights", &now), Err(INVALID));
+        assert_eq!(parse_relative("3 days later", &now), Err(INVALID));
+        assert_eq!(parse_relative("today noon please", &now), Err(TOO_LONG));
+        assert_eq!(parse_relative("in 3 days please", &now), Err(TOO_LONG));
+        assert_eq!(parse_relative("3 days ago please", &now), Err(TOO_LONG));
+        assert_eq!(
+            parse_relative("in 100000000 weeks", &now),
+            Err(OUT_OF_RANGE)
+        );
+        assert_eq!(
+            parse_relative("in 9223372036854775807 hours", &now),
+            Err(OUT_OF_RANGE)
+        );
+        assert_eq!(
+            parse_relative("in 99999999999999999999 days", &now),
+            Err(OUT_OF_RANGE)
+        );
+    }
+}
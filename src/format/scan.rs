@@ -12,7 +12,7 @@ use Weekday;
 
 /// Returns true when two slices are equal case-insensitively (in ASCII).
 /// Assumes that the `pattern` is already converted to lower case.
-fn equals(s: &str, pattern: &str) -> bool {
+pub fn equals(s: &str, pattern: &str) -> bool {
     let mut xs = s.as_bytes().iter().map(|&c| match c {
         b'A'...b'Z' => c + 32,
         _ => c,
@@ -214,6 +214,30 @@ pub fn space(s: &str) -> ParseResult<&str> {
     }
 }
 
+/// Tries to consume one or more ASCII letters, e.g. a time zone abbreviation,
+/// or an offset like `+09:30` that `%Z` prints when the abbreviation is unknown.
+pub fn timezone_name(s: &str) -> ParseResult<&str> {
+    match s.as_bytes().first() {
+        Some(&b'+') | Some(&b'-') => return timezone_offset(s, colon_or_space).map(|(s, _)| s),
+        _ => {}
+    }
+    let upto = s
+        .as_bytes()
+        .iter()
+        .position(|&c| match c {
+            b'a'...b'z' | b'A'...b'Z' => false,
+            _ => true,
+        })
+        .unwrap_or_else(|| s.len());
+    if upto > 0 {
+        Ok(&s[upto..])
+    } else if s.is_empty() {
+        Err(TOO_SHORT)
+    } else {
+        Err(INVALID)
+    }
+}
+
 /// Consumes any number (including zero) of colon or spaces.
 pub fn colon_or_space(s: &str) -> ParseResult<&str> {
     Ok(s.trim_left_matches(|c: char| c == ':' || c.is_whitespace()))
@@ -22,13 +22,14 @@ The following specifiers are available both to formatting and parsing.
 |       |          |                                                                            |
 | `%d`  | `08`     | Day number (01--31), zero-padded to 2 digits.                              |
 | `%e`  | ` 8`     | Same to `%d` but space-padded. Same to `%_d`.                              |
+| `%o`  | `th`     | English ordinal suffix for the day number (`st`, `nd`, `rd` or `th`). [9]  |
 |       |          |                                                                            |
 | `%a`  | `Sun`    | Abbreviated weekday name. Always 3 letters.                                |
 | `%A`  | `Sunday` | Full weekday name. Also accepts corresponding abbreviation in parsing.     |
 | `%w`  | `0`      | Sunday = 0, Monday = 1, ..., Saturday = 6.                                 |
 | `%u`  | `7`      | Monday = 1, Tuesday = 2, ..., Sunday = 7. (ISO 8601)                       |
 |       |          |                                                                            |
-| `%U`  | `28`     | Week number starting with Sunday (00--53), zero-padded to 2 digits. [3]    |
+| `%U`  | `27`     | Week number starting with Sunday (00--53), zero-padded to 2 digits. [3]    |
 | `%W`  | `27`     | Same to `%U`, but week 1 starts with the first Monday in that year instead.|
 |       |          |                                                                            |
 | `%G`  | `2001`   | Same to `%Y` but uses the year number in ISO 8601 week date. [4]           |
@@ -68,10 +69,10 @@ The following specifiers are available both to formatting and parsing.
 | `%r`  | `12:34:60 AM` | Hour-minute-second format in 12-hour clocks. Same to `%I:%M:%S %p`.   |
 |       |          |                                                                            |
 |       |          | **TIME ZONE SPECIFIERS:**                                                  |
-| `%Z`  | `ACST`   | *Formatting only:* Local time zone name.                                   |
-| `%z`  | `+0930`  | Offset from the local time to UTC (with UTC being `+0000`).                |
-| `%:z` | `+09:30` | Same to `%z` but with a colon.                                             |
-| `%#z` | `+09`    | *Parsing only:* Same to `%z` but allows minutes to be missing or present.  |
+| `%Z`  | `ACST`   | Local time zone abbreviation, or the offset if unknown. [11]               |
+| `%z`  | `+0930`  | Offset from the local time to UTC (with UTC being `+0000`). [10]           |
+| `%:z` | `+09:30` | Same to `%z` but with a colon. [10]                                        |
+| `%#z` | `+09`    | *Parsing only:* Same to `%z` but allows minutes to be missing or present. [10] |
 |       |          |                                                                            |
 |       |          | **DATE & TIME SPECIFIERS:**                                                |
 |`%c`|`Sun Jul  8 00:34:60 2001`|`ctime` date & time format. Same to `%a %b %e %T %Y` sans `\n`.|
@@ -84,9 +85,18 @@ The following specifiers are available both to formatting and parsing.
 | `%n`  |          | Literal newline (`\n`).                                                    |
 | `%%`  |          | Literal percent sign.                                                      |
 
-It is possible to override the default padding behavior of numeric specifiers `%?`.
+It is possible to override the default padding behavior of numeric specifiers `%?`,
+in the same way as GNU `strftime` (so `%-d` prints `8` instead of `08`).
 This is not allowed for other specifiers and will result in the `BAD_FORMAT` error.
 
+The numeric specifiers are
+`%Y`, `%C`, `%y`, `%m`, `%d`, `%e`, `%U`, `%W`, `%G`, `%g`, `%V`, `%j`, `%w`, `%u`,
+`%H`, `%k`, `%I`, `%l`, `%M`, `%S`, `%f` and `%s`.
+Each modifier works with all of them,
+but `%w`, `%u` and `%s` are never padded by default and always print at least one digit,
+so the modifiers don't change them.
+Specifiers that expand to multiple items, like `%D` or `%T`, don't accept any modifier.
+
 Modifier | Description
 -------- | -----------
 `%-?`    | Suppresses any padding including spaces and zeroes. (e.g. `%j` = `012`, `%-j` = `12`)
@@ -154,9 +164,39 @@ Notes:
    and parsing `07`, `070000` etc. will yield the same.
    Note that they can read nothing if the fractional part is zero.
 
+9. `%o`:
+   The 11th, 12th and 13th days get `th` as usual in English, so `%-d%o` prints `11th` but `21st`.
+   Parsing accepts any of those suffixes (in lower or upper case) without checking the day.
+
+10. `%z`, `%:z`, `%#z`:
+    `%z` prints the offset as `+hhmm` and `%:z` as `+hh:mm`, where the sign is always present
+    and UTC is `+0000` or `+00:00` respectively (not `Z`).
+    Seconds of the offset, if any, are not printed.
+
+    In parsing, `%z` and `%:z` behave identically:
+    they read a sign, two digits of hours and two digits of minutes,
+    optionally separated by a colon or whitespace, so both `+0930` and `+09:30` are accepted.
+    Leading whitespace is skipped.
+
+    `%#z` is a more lenient variant for parsing only. It additionally accepts `Z` or `z` for UTC,
+    and the minutes may be missing, so `Z`, `+09`, `+0930` and `+09:30` are all accepted.
+    Formatting with `%#z` panics; use `%:z` to produce RFC 3339 offsets instead.
+
+11. `%Z`:
+    Prints the [abbreviation](../../offset/trait.Offset.html#method.abbreviation) of the offset
+    like `UTC` or `JST` when the time zone knows it,
+    and otherwise the offset as in `%:z` (so `FixedOffset` and `Local` print `+09:30`).
+
+    In parsing, `%Z` reads one or more ASCII letters (after skipping leading whitespace),
+    or an offset like `+09:30` as printed above,
+    but doesn't give any offset, as the abbreviations are ambiguous
+    (e.g. `CST` is used for the Central Standard Time, China and Cuba).
+    Use `%z` in addition to get the offset,
+    or parse into `NaiveDateTime` and pick the time zone separately.
+
 */
 
-use super::{Fixed, InternalFixed, InternalInternal, Item, Numeric, Pad};
+use super::{Fixed, InternalFixed, InternalInternal, Item, Numeric, Pad, ParseResult, BAD_FORMAT};
 
 /// Parsing iterator for `strftime`-like format strings.
 #[derive(Clone, Debug)]
@@ -178,6 +218,38 @@ impl<'a> StrftimeItems<'a> {
             recons: &FMT_NONE,
         }
     }
+
+    /// Checks that the format string has no unknown specifiers and no parse-only `%#z`.
+    ///
+    /// Formatting with such a format string fails with `fmt::Error`,
+    /// which makes `to_string` panic, so format strings taken from the user
+    /// (e.g. templates) should be validated first.
+    /// Use `%%` for a literal percent sign; a lone `%` at the end of the string is invalid.
+    ///
+    /// This doesn't know the value to be formatted, so specifiers that the value can't supply
+    /// still pass; for example `%z`, `%:z` and `%Z` fail when formatting a naive date or time.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::format::strftime::StrftimeItems;
+    ///
+    /// assert!(StrftimeItems::new("100%% done at %H:%M").validate().is_ok());
+    /// assert!(StrftimeItems::new("100% done").validate().is_err());
+    /// assert!(StrftimeItems::new("100%").validate().is_err());
+    /// ~~~~
+    pub fn validate(&self) -> ParseResult<()> {
+        for item in self.clone() {
+            match item {
+                Item::Error
+                | Item::Fixed(Fixed::Internal(InternalFixed {
+                    val: InternalInternal::TimezoneOffsetPermissive,
+                })) => return Err(BAD_FORMAT),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
 }
 
 const HAVE_ALTERNATES: &'static str = "z";
@@ -302,6 +374,7 @@ impl<'a> Iterator for StrftimeItems<'a> {
                     'l' => nums!(Hour12),
                     'm' => num0!(Month),
                     'n' => sp!("\n"),
+                    'o' => internal_fix!(DayOrdinalSuffix),
                     'p' => fix!(UpperAmPm),
                     'r' => recons![
                         num0!(Hour12),
@@ -506,11 +579,12 @@ fn test_strftime_docs() {
     assert_eq!(dt.format("%d").to_string(), "08");
     assert_eq!(dt.format("%e").to_string(), " 8");
     assert_eq!(dt.format("%e").to_string(), dt.format("%_d").to_string());
+    assert_eq!(dt.format("%o").to_string(), "th");
     assert_eq!(dt.format("%a").to_string(), "Sun");
     assert_eq!(dt.format("%A").to_string(), "Sunday");
     assert_eq!(dt.format("%w").to_string(), "0");
     assert_eq!(dt.format("%u").to_string(), "7");
-    assert_eq!(dt.format("%U").to_string(), "28");
+    assert_eq!(dt.format("%U").to_string(), "27");
     assert_eq!(dt.format("%W").to_string(), "27");
     assert_eq!(dt.format("%G").to_string(), "2001");
     assert_eq!(dt.format("%g").to_string(), "01");
@@ -553,7 +627,7 @@ fn test_strftime_docs() {
     assert_eq!(dt.format("%r").to_string(), "12:34:60 AM");
 
     // time zone specifiers
-    //assert_eq!(dt.format("%Z").to_string(), "ACST");
+    assert_eq!(dt.format("%Z").to_string(), "+09:30");
     assert_eq!(dt.format("%z").to_string(), "+0930");
     assert_eq!(dt.format("%:z").to_string(), "+09:30");
 
@@ -577,3 +651,76 @@ fn test_strftime_docs() {
     assert_eq!(dt.format("%n").to_string(), "\n");
     assert_eq!(dt.format("%%").to_string(), "%");
 }
+
+#[cfg(test)]
+#[test]
+fn test_strftime_padding_modifiers() {
+    use naive::NaiveDate;
+
+    let dt = NaiveDate::from_ymd(2001, 7, 8).and_hms_nano(9, 4, 5, 6);
+
+    // same as GNU `date`
+    assert_eq!(dt.format("%d %-d %_d %0d").to_string(), "08 8  8 08");
+    assert_eq!(dt.format("%e %-e %_e %0e").to_string(), " 8 8  8 08");
+    assert_eq!(dt.format("%m %-m %_m %0m").to_string(), "07 7  7 07");
+    assert_eq!(dt.format("%y %-y %_y %0y").to_string(), "01 1  1 01");
+    assert_eq!(
+        dt.format("%Y %-Y %_Y %0Y").to_string(),
+        "2001 2001 2001 2001"
+    );
+    assert_eq!(dt.format("%j %-j %_j %0j").to_string(), "189 189 189 189");
+    assert_eq!(dt.format("%V %-V %_V %0V").to_string(), "27 27 27 27");
+    assert_eq!(dt.format("%H %-H %_H %0H").to_string(), "09 9  9 09");
+    assert_eq!(dt.format("%k %-k %_k %0k").to_string(), " 9 9  9 09");
+    assert_eq!(dt.format("%I %-I %_I %0I").to_string(), "09 9  9 09");
+    assert_eq!(dt.format("%l %-l %_l %0l").to_string(), " 9 9  9 09");
+    assert_eq!(dt.format("%M %-M %_M %0M").to_string(), "04 4  4 04");
+    assert_eq!(dt.format("%S %-S %_S %0S").to_string(), "05 5  5 05");
+    assert_eq!(dt.format("%u %-u %_u %0u").to_string(), "7 7 7 7");
+    assert_eq!(dt.format("%-m/%-d/%Y %-l:%M").to_string(), "7/8/2001 9:04");
+
+    let dt = NaiveDate::from_ymd(2001, 1, 5).and_hms(0, 0, 0);
+    assert_eq!(dt.format("%j %-j %_j %0j").to_string(), "005 5   5 005");
+    assert_eq!(dt.format("%U %-U %_U %0U").to_string(), "00 0  0 00");
+
+    // only for numeric specifiers
+    for fmt in &["%-a", "%_b", "%0p", "%-D", "%_T", "%0F", "%-z", "%-%"] {
+        assert!(
+            StrftimeItems::new(fmt).any(|item| item == Item::Error),
+            "{} should be rejected",
+            fmt
+        );
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_strftime_literals() {
+    use naive::NaiveDate;
+
+    let dt = NaiveDate::from_ymd(2001, 7, 8).and_hms(0, 34, 59);
+    assert_eq!(
+        dt.format("100%% done at %H:%M").to_string(),
+        "100% done at 00:34"
+    );
+    assert_eq!(dt.format("%%Y%%%Y%%").to_string(), "%Y%2001%");
+    assert_eq!(
+        dt.format("날짜: %Y년 — «%m» ✓").to_string(),
+        "날짜: 2001년 — «07» ✓"
+    );
+    assert_eq!(dt.format("\t{}").to_string(), "\t{}");
+
+    for &s in &["", "%%", "100%% done", "%Y-%m-%d", "%-d%o", "%:z", "✓%H✓"] {
+        assert_eq!(StrftimeItems::new(s).validate(), Ok(()), "{:?}", s);
+    }
+    for &s in &[
+        "%", "100%", "%Y%", "%-", "%_", "%#", "%#Y", "%#z", "%Q", "%-%", "✓%",
+    ] {
+        assert_eq!(StrftimeItems::new(s).validate(), Err(BAD_FORMAT), "{:?}", s);
+    }
+
+    // validation does not consume the items
+    let items = StrftimeItems::new("%H:%M");
+    assert_eq!(items.validate(), Ok(()));
+    assert_eq!(dt.format_with_items(items).to_string(), "00:34");
+}
@@ -0,0 +1,336 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Holiday calendars for the business day arithmetic.
+//!
+//! A [`HolidayCalendar`](./trait.HolidayCalendar.html) decides which dates are not business days.
+//! Calendars are composed with [`union`](./trait.HolidayCalendar.html#method.union),
+//! and [`Weekends`](./struct.Weekends.html) marks the weekly days off
+//! which are otherwise *not* excluded.
+//! Any calendar can then be given to
+//! [`NaiveDate::is_business_day`](../naive/struct.NaiveDate.html#method.is_business_day),
+//! [`NaiveDate::next_business_day`](../naive/struct.NaiveDate.html#method.next_business_day) and
+//! [`NaiveDate::business_days_between`](../naive/struct.NaiveDate.html#method.business_days_between).
+//!
+//! Chrono ships no holiday data for any country;
+//! [`FixedHolidays`](./struct.FixedHolidays.html) can be filled from whatever source is at hand.
+//!
+//! # Example
+//!
+//! ~~~~
+//! use chrono::NaiveDate;
+//! use chrono::holiday::{FixedHolidays, HolidayCalendar};
+//!
+//! let from_ymd = NaiveDate::from_ymd;
+//! let holidays: FixedHolidays = vec![
+//!     (from_ymd(2019, 12, 25), "Christmas Day".to_owned()),
+//!     (from_ymd(2019, 12, 26), "Boxing Day".to_owned()),
+//! ].into_iter().collect();
+//! let cal = holidays.with_weekends();
+//!
+//! assert_eq!(cal.holiday_name(from_ymd(2019, 12, 25)), Some("Christmas Day"));
+//! assert!(cal.is_holiday(from_ymd(2019, 12, 28))); // Saturday
+//! assert_eq!(from_ymd(2019, 12, 24).next_business_day(&cal), Some(from_ymd(2019, 12, 27)));
+//! ~~~~
+
+use std::iter::FromIterator;
+
+use naive::NaiveDate;
+use {Datelike, Weekday};
+
+/// A set of dates which are not business days, optionally with their names.
+///
+/// Implemented for [`FixedHolidays`](./struct.FixedHolidays.html),
+/// [`Weekends`](./struct.Weekends.html), the [`Union`](./struct.Union.html) of two calendars,
+/// and a slice of `NaiveDate`s sorted in the ascending order (without names).
+pub trait HolidayCalendar {
+    /// Returns `true` if given date is not a business day.
+    fn is_holiday(&self, date: NaiveDate) -> bool;
+
+    /// Returns the name of the holiday at given date if it is known.
+    ///
+    /// It may be `None` even when [`is_holiday`](#tymethod.is_holiday) returns `true`,
+    /// e.g. for weekends. The default implementation always returns `None`.
+    fn holiday_name(&self, _date: NaiveDate) -> Option<&str> {
+        None
+    }
+
+    /// Makes a calendar where a date is a holiday when it is a holiday in either calendar.
+    /// The name from `self` takes precedence over the name from `other`.
+    fn union<C: HolidayCalendar>(self, other: C) -> Union<Self, C>
+    where
+        Self: Sized,
+    {
+        Union {
+            first: self,
+            second: other,
+        }
+    }
+
+    /// Makes a calendar which also treats Saturdays and Sundays as holidays.
+    ///
+    /// Same to `self.union(Weekends::default())`.
+    fn with_weekends(self) -> Union<Self, Weekends>
+    where
+        Self: Sized,
+    {
+        self.union(Weekends::default())
+    }
+}
+
+impl<'a, C: HolidayCalendar + ?Sized> HolidayCalendar for &'a C {
+    #[inline]
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        (**self).is_holiday(date)
+    }
+
+    #[inline]
+    fn holiday_name(&self, date: NaiveDate) -> Option<&str> {
+        (**self).holiday_name(date)
+    }
+}
+
+/// The slice should be sorted in the ascending order, as it is binary-searched.
+impl HolidayCalendar for [NaiveDate] {
+    #[inline]
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.binary_search(&date).is_ok()
+    }
+}
+
+/// The calendar of the weekly days off, by default Saturdays and Sundays.
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::{NaiveDate, Weekday};
+/// use chrono::holiday::{HolidayCalendar, Weekends};
+///
+/// let fri_sat = Weekends::new(&[Weekday::Fri, Weekday::Sat]);
+/// assert!(fri_sat.is_holiday(NaiveDate::from_ymd(2019, 12, 6)));
+/// assert!(!fri_sat.is_holiday(NaiveDate::from_ymd(2019, 12, 8)));
+/// ~~~~
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Weekends {
+    /// Bit `i` is set when the weekday `i` days from Monday is a day off.
+    days: u8,
+}
+
+impl Weekends {
+    /// Makes a new calendar with given weekdays as the days off.
+    pub fn new(weekdays: &[Weekday]) -> Weekends {
+        let mut days = 0;
+        for weekday in weekdays {
+            days |= 1 << weekday.num_days_from_monday();
+        }
+        Weekends { days: days }
+    }
+}
+
+impl Default for Weekends {
+    fn default() -> Weekends {
+        Weekends::new(&[Weekday::Sat, Weekday::Sun])
+    }
+}
+
+impl HolidayCalendar for Weekends {
+    #[inline]
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.days & (1 << date.weekday().num_days_from_monday()) != 0
+    }
+}
+
+/// The calendar of holidays at fixed dates with their names.
+///
+/// It can be collected from an iterator of `(NaiveDate, String)` pairs;
+/// when the same date appears more than once, the last name is kept.
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::NaiveDate;
+/// use chrono::holiday::{FixedHolidays, HolidayCalendar};
+///
+/// let mut holidays = FixedHolidays::new();
+/// holidays.insert(NaiveDate::from_ymd(2020, 1, 1), "New Year's Day");
+///
+/// assert!(holidays.is_holiday(NaiveDate::from_ymd(2020, 1, 1)));
+/// assert_eq!(holidays.holiday_name(NaiveDate::from_ymd(2020, 1, 1)), Some("New Year's Day"));
+/// assert_eq!(holidays.holiday_name(NaiveDate::from_ymd(2020, 1, 2)), None);
+/// ~~~~
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct FixedHolidays {
+    /// Sorted by the date, without duplicate dates.
+    holidays: Vec<(NaiveDate, String)>,
+}
+
+impl FixedHolidays {
+    /// Makes a new calendar without any holiday.
+    pub fn new() -> FixedHolidays {
+        FixedHolidays {
+            holidays: Vec::new(),
+        }
+    }
+
+    /// Adds a holiday at given date, replacing the name if there was one at that date.
+    pub fn insert<S: Into<String>>(&mut self, date: NaiveDate, name: S) {
+        let name = name.into();
+        match self
+            .holidays
+            .binary_search_by(|holiday| holiday.0.cmp(&date))
+        {
+            Ok(i) => self.holidays[i].1 = name,
+            Err(i) => self.holidays.insert(i, (date, name)),
+        }
+    }
+
+    /// Returns an iterator over the holidays in the ascending order of dates.
+    pub fn iter<'a>(&'a self) -> FixedHolidaysIter<'a> {
+        FixedHolidaysIter {
+            inner: self.holidays.iter(),
+        }
+    }
+
+    fn find(&self, date: NaiveDate) -> Option<&(NaiveDate, String)> {
+        self.holidays
+            .binary_search_by(|holiday| holiday.0.cmp(&date))
+            .ok()
+            .map(|i| &self.holidays[i])
+    }
+}
+
+impl FromIterator<(NaiveDate, String)> for FixedHolidays {
+    fn from_iter<I: IntoIterator<Item = (NaiveDate, String)>>(iter: I) -> FixedHolidays {
+        let mut holidays = FixedHolidays::new();
+        for (date, name) in iter {
+            holidays.insert(date, name);
+        }
+        holidays
+    }
+}
+
+impl HolidayCalendar for FixedHolidays {
+    #[inline]
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.find(date).is_some()
+    }
+
+    #[inline]
+    fn holiday_name(&self, date: NaiveDate) -> Option<&str> {
+        self.find(date).map(|holiday| &holiday.1[..])
+    }
+}
+
+/// An iterator over the holidays of [`FixedHolidays`](./struct.FixedHolidays.html).
+#[derive(Clone, Debug)]
+pub struct FixedHolidaysIter<'a> {
+    inner: ::std::slice::Iter<'a, (NaiveDate, String)>,
+}
+
+impl<'a> Iterator for FixedHolidaysIter<'a> {
+    type Item = (NaiveDate, &'a str);
+
+    fn next(&mut self) -> Option<(NaiveDate, &'a str)> {
+        self.inner.next().map(|holiday| (holiday.0, &holiday.1[..]))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+/// The union of two calendars, made by [`HolidayCalendar::union`](./trait.HolidayCalendar.html#method.union).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Union<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A: HolidayCalendar, B: HolidayCalendar> HolidayCalendar for Union<A, B> {
+    #[inline]
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.first.is_holiday(date) || self.second.is_holiday(date)
+    }
+
+    #[inline]
+    fn holiday_name(&self, date: NaiveDate) -> Option<&str> {
+        self.first
+            .holiday_name(date)
+            .or_else(|| self.second.holiday_name(date))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FixedHolidays, HolidayCalendar, Weekends};
+    use naive::NaiveDate;
+    use Weekday;
+
+    #[test]
+    fn test_weekends() {
+        let from_ymd = NaiveDate::from_ymd;
+        let weekends = Weekends::default();
+        // 2019-12-02 is a Monday
+        let days: Vec<bool> = (2..9)
+            .map(|d| weekends.is_holiday(from_ymd(2019, 12, d)))
+            .collect();
+        assert_eq!(days, [false, false, false, false, false, true, true]);
+        assert_eq!(weekends.holiday_name(from_ymd(2019, 12, 7)), None);
+
+        let none = Weekends::new(&[]);
+        assert!((2..9).all(|d| !none.is_holiday(from_ymd(2019, 12, d))));
+        let fri = Weekends::new(&[Weekday::Fri, Weekday::Fri]);
+        assert_eq!(fri, Weekends::new(&[Weekday::Fri]));
+        assert!(fri.is_holiday(from_ymd(2019, 12, 6)));
+    }
+
+    #[test]
+    fn test_fixed_holidays() {
+        let from_ymd = NaiveDate::from_ymd;
+        let holidays: FixedHolidays = vec![
+            (from_ymd(2020, 1, 1), "New Year".to_owned()),
+            (from_ymd(2019, 12, 25), "Christmas Day".to_owned()),
+            (from_ymd(2020, 1, 1), "New Year's Day".to_owned()),
+        ]
+        .into_iter()
+        .collect();
+
+        assert!(holidays.is_holiday(from_ymd(2019, 12, 25)));
+        assert!(!holidays.is_holiday(from_ymd(2019, 12, 24)));
+        assert_eq!(
+            holidays.holiday_name(from_ymd(2020, 1, 1)),
+            Some("New Year's Day")
+        );
+        assert_eq!(
+            holidays.iter().collect::<Vec<_>>(),
+            [
+                (from_ymd(2019, 12, 25), "Christmas Day"),
+                (from_ymd(2020, 1, 1), "New Year's Day")
+            ]
+        );
+        assert!(!FixedHolidays::new().is_holiday(from_ymd(2020, 1, 1)));
+    }
+
+    #[test]
+    fn test_union() {
+        let from_ymd = NaiveDate::from_ymd;
+        let mut first = FixedHolidays::new();
+        first.insert(from_ymd(2019, 12, 25), "Christmas Day");
+        let mut second = FixedHolidays::new();
+        second.insert(from_ymd(2019, 12, 25), "Christmas");
+        second.insert(from_ymd(2019, 12, 26), "Boxing Day");
+        let dates = [from_ymd(2019, 12, 31)];
+
+        let cal = first.union(second).union(&dates[..]).with_weekends();
+        assert_eq!(
+            cal.holiday_name(from_ymd(2019, 12, 25)),
+            Some("Christmas Day")
+        );
+        assert_eq!(cal.holiday_name(from_ymd(2019, 12, 26)), Some("Boxing Day"));
+        assert!(cal.is_holiday(from_ymd(2019, 12, 31)));
+        assert_eq!(cal.holiday_name(from_ymd(2019, 12, 31)), None);
+        assert!(cal.is_holiday(from_ymd(2019, 12, 28)));
+        assert!(!cal.is_holiday(from_ymd(2019, 12, 27)));
+        assert!(!cal.is_holiday(from_ymd(2020, 1, 1)));
+    }
+}
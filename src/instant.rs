@@ -0,0 +1,170 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! An instant normalized to UTC, for ordering date and times across offsets.
+
+use std::fmt;
+
+use naive::NaiveDateTime;
+use offset::{TimeZone, Utc};
+use DateTime;
+
+/// An instant in time regardless of the time zone, stored as a UNIX timestamp
+/// (the number of non-leap seconds since January 1, 1970 0:00:00 UTC) and nanoseconds.
+///
+/// `DateTime`s in different time zones are different types, so a map keyed by
+/// `DateTime<Utc>` can't be queried with a `DateTime<Local>` without converting it first.
+/// Keying the map by `UtcInstant` instead does the conversion in one place,
+/// since every `DateTime` converts into it with `From`,
+/// and `UtcInstant`s order exactly like the `DateTime`s they came from.
+///
+/// A leap second keeps the timestamp of the preceding second
+/// and has 1,000,000,000 or more nanoseconds, as in
+/// [`DateTime::timestamp_subsec_nanos`](./struct.DateTime.html#method.timestamp_subsec_nanos),
+/// so it is ordered after that second and before the next one,
+/// and converting back gives the same leap second.
+///
+/// # Example
+///
+/// ~~~~
+/// use std::collections::BTreeMap;
+/// use chrono::{FixedOffset, TimeZone, Utc, UtcInstant};
+///
+/// let mut events = BTreeMap::new();
+/// events.insert(UtcInstant::from(Utc.ymd(2019, 12, 31).and_hms(14, 0, 0)), "meeting");
+/// events.insert(UtcInstant::from(Utc.ymd(2019, 12, 31).and_hms(15, 0, 0)), "party");
+///
+/// // query with the bounds in another time zone
+/// let kst = FixedOffset::east(9 * 3600);
+/// let start = UtcInstant::from(kst.ymd(2020, 1, 1).and_hms(0, 0, 0));
+/// let end = UtcInstant::from(kst.ymd(2020, 1, 2).and_hms(0, 0, 0));
+/// let new_year: Vec<_> = events.iter()
+///                               .filter(|&(&k, _)| start <= k && k < end)
+///                               .map(|(_, &v)| v)
+///                               .collect();
+/// assert_eq!(new_year, ["party"]);
+///
+/// let (&instant, _) = events.iter().next().unwrap();
+/// assert_eq!(instant.with_timezone(&kst).to_string(), "2019-12-31 23:00:00 +09:00");
+/// ~~~~
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UtcInstant {
+    // the field order gives the derived ordering
+    secs: i64,
+    nanos: u32,
+}
+
+impl UtcInstant {
+    /// Returns the number of non-leap seconds since January 1, 1970 0:00:00 UTC.
+    #[inline]
+    pub fn timestamp(&self) -> i64 {
+        self.secs
+    }
+
+    /// Returns the number of nanoseconds since the last second boundary,
+    /// which can be 1,000,000,000 or more for a leap second.
+    #[inline]
+    pub fn timestamp_subsec_nanos(&self) -> u32 {
+        self.nanos
+    }
+
+    /// Converts the instant into a `DateTime` in given time zone.
+    #[inline]
+    pub fn with_timezone<Tz: TimeZone>(&self, tz: &Tz) -> DateTime<Tz> {
+        tz.from_utc_datetime(&self.naive_utc())
+    }
+
+    fn naive_utc(&self) -> NaiveDateTime {
+        // always valid, as it came from a `DateTime`
+        NaiveDateTime::from_timestamp(self.secs, self.nanos)
+    }
+}
+
+impl<Tz: TimeZone> From<DateTime<Tz>> for UtcInstant {
+    #[inline]
+    fn from(dt: DateTime<Tz>) -> UtcInstant {
+        UtcInstant {
+            secs: dt.timestamp(),
+            nanos: dt.timestamp_subsec_nanos(),
+        }
+    }
+}
+
+impl From<UtcInstant> for DateTime<Utc> {
+    #[inline]
+    fn from(instant: UtcInstant) -> DateTime<Utc> {
+        DateTime::from_utc(instant.naive_utc(), Utc)
+    }
+}
+
+impl fmt::Debug for UtcInstant {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&DateTime::<Utc>::from(*self), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UtcInstant;
+    use naive::NaiveDate;
+    use offset::{FixedOffset, TimeZone, Utc};
+    use DateTime;
+
+    #[test]
+    fn test_utc_instant_order() {
+        let kst = FixedOffset::east(9 * 3600);
+        let est = FixedOffset::west(5 * 3600);
+        let dts = [
+            DateTime::<Utc>::min_value().with_timezone(&kst),
+            est.ymd(1969, 12, 31).and_hms_nano(18, 59, 59, 999_999_999),
+            Utc.ymd(1970, 1, 1).and_hms(0, 0, 0).with_timezone(&kst),
+            Utc.ymd(2015, 6, 30).and_hms(23, 59, 59).with_timezone(&est),
+            kst.ymd(2015, 7, 1).and_hms_milli(8, 59, 59, 1_000), // leap second
+            kst.ymd(2015, 7, 1).and_hms_milli(8, 59, 59, 1_999),
+            Utc.ymd(2015, 7, 1).and_hms(0, 0, 0).with_timezone(&est),
+            DateTime::<Utc>::max_value().with_timezone(&kst),
+        ];
+        for (i, a) in dts.iter().enumerate() {
+            for (j, b) in dts.iter().enumerate() {
+                assert_eq!(
+                    UtcInstant::from(*a).cmp(&UtcInstant::from(*b)),
+                    i.cmp(&j),
+                    "{:?} <=> {:?}",
+                    a,
+                    b
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_utc_instant_conversions() {
+        let kst = FixedOffset::east(9 * 3600);
+        let leap = NaiveDate::from_ymd(2015, 6, 30).and_hms_milli(23, 59, 59, 1_500);
+        for &naive in &[
+            leap,
+            NaiveDate::from_ymd(1969, 12, 31).and_hms_nano(23, 59, 59, 999_999_999),
+            DateTime::<Utc>::min_value().naive_utc(),
+            DateTime::<Utc>::max_value().naive_utc(),
+        ] {
+            let dt = kst.from_utc_datetime(&naive);
+            let instant = UtcInstant::from(dt);
+            assert_eq!(instant, UtcInstant::from(dt.with_timezone(&Utc)));
+            assert_eq!(instant.timestamp(), dt.timestamp());
+            assert_eq!(
+                instant.timestamp_subsec_nanos(),
+                dt.timestamp_subsec_nanos()
+            );
+            assert_eq!(instant.with_timezone(&kst), dt);
+            assert_eq!(instant.with_timezone(&kst).offset(), &kst);
+            assert_eq!(DateTime::<Utc>::from(instant).naive_utc(), naive);
+        }
+
+        let instant = UtcInstant::from(Utc.from_utc_datetime(&leap));
+        assert_eq!(
+            (instant.timestamp(), instant.timestamp_subsec_nanos()),
+            (1_435_708_799, 1_500_000_000)
+        );
+        assert_eq!(format!("{:?}", instant), "2015-06-30T23:59:60.500Z");
+    }
+}
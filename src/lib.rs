@@ -39,6 +39,11 @@
 //! chrono = { version = "0.4", features = ["serde"] }
 //! ```
 //!
+//! The `serde-lenient` feature additionally makes `DateTime<Utc>` deserialize
+//! from a unix timestamp in seconds as well as from an RFC 3339 string
+//! in human-readable formats like JSON
+//! (see [`serde::ts_or_rfc3339`](./serde/ts_or_rfc3339/index.html)).
+//!
 //! Then put this in your crate root:
 //!
 //! ```rust
@@ -380,6 +385,9 @@
 //!
 //! Advanced time zone handling is not yet supported.
 //! For now you can try the [Chrono-tz](https://github.com/chronotope/chrono-tz/) crate instead.
+//! It provides the IANA time zone database entries as `TimeZone` implementations,
+//! e.g. `dt.with_timezone(&chrono_tz::America::New_York)`.
+//! (Chrono can't re-export it behind a feature, since Chrono-tz itself depends on Chrono.)
 
 #![doc(html_root_url = "https://docs.rs/chrono/latest/")]
 #![cfg_attr(bench, feature(test))] // lib stability features as per RFC #507
@@ -426,11 +434,43 @@ doctest!("../README.md");
 // this reexport is to aid the transition and should not be in the prelude!
 pub use oldtime::Duration;
 
-pub use date::{Date, MAX_DATE, MIN_DATE};
+// `Duration` may be a type from the `time` crate, so these can't be its associated constants.
+
+/// The number of nanoseconds in a second, for converting between `Duration` units.
+pub const NANOSECONDS_PER_SECOND: i64 = 1_000_000_000;
+/// The number of microseconds in a second.
+pub const MICROSECONDS_PER_SECOND: i64 = 1_000_000;
+/// The number of milliseconds in a second.
+pub const MILLISECONDS_PER_SECOND: i64 = 1_000;
+/// The number of nanoseconds in a millisecond.
+pub const NANOSECONDS_PER_MILLISECOND: i64 = 1_000_000;
+/// The number of microseconds in a millisecond.
+pub const MICROSECONDS_PER_MILLISECOND: i64 = 1_000;
+/// The number of nanoseconds in a microsecond.
+pub const NANOSECONDS_PER_MICROSECOND: i64 = 1_000;
+/// The number of seconds in a minute.
+pub const SECONDS_PER_MINUTE: i64 = 60;
+/// The number of seconds in an hour.
+pub const SECONDS_PER_HOUR: i64 = 3_600;
+/// The number of seconds in a day, ignoring leap seconds.
+pub const SECONDS_PER_DAY: i64 = 86_400;
+/// The number of seconds in a week, ignoring leap seconds.
+pub const SECONDS_PER_WEEK: i64 = 604_800;
+
+pub use age::Age;
+#[cfg(feature = "clock")]
+pub use clock::SystemClock;
+pub use clock::{Clock, FixedClock};
+pub use date::{ComponentRangeError, Date, TimeComponent, MAX_DATE, MIN_DATE};
 #[cfg(feature = "rustc-serialize")]
 pub use datetime::rustc_serialize::TsSeconds;
-pub use datetime::{DateTime, SecondsFormat};
+pub use datetime::{DateTime, OffsetStyle, SecondsFormat};
+pub use days::Days;
+pub use duration_ext::DurationExt;
+pub use erased_date::ErasedDate;
 pub use format::{ParseError, ParseResult};
+pub use instant::UtcInstant;
+pub use month::Months;
 #[doc(no_inline)]
 pub use naive::{IsoWeek, NaiveDate, NaiveDateTime, NaiveTime};
 #[cfg(feature = "clock")]
@@ -438,12 +478,15 @@ pub use naive::{IsoWeek, NaiveDate, NaiveDateTime, NaiveTime};
 pub use offset::Local;
 #[doc(no_inline)]
 pub use offset::{FixedOffset, LocalResult, Offset, TimeZone, Utc};
+pub use range::{DateTimeRange, DateTimeSteps};
 pub use round::SubsecRound;
 
 /// A convenience module appropriate for glob imports (`use chrono::prelude::*;`).
 pub mod prelude {
     #[doc(no_inline)]
     pub use Date;
+    #[doc(no_inline)]
+    pub use DurationExt;
     #[cfg(feature = "clock")]
     #[doc(no_inline)]
     pub use Local;
@@ -452,7 +495,7 @@ pub mod prelude {
     #[doc(no_inline)]
     pub use {DateTime, SecondsFormat};
     #[doc(no_inline)]
-    pub use {Datelike, Timelike, Weekday};
+    pub use {Datelike, Month, Timelike, Weekday};
     #[doc(no_inline)]
     pub use {FixedOffset, Utc};
     #[doc(no_inline)]
@@ -473,6 +516,8 @@ macro_rules! try_opt {
 
 const EPOCH_NUM_DAYS_FROM_CE: i32 = 719_163;
 
+mod age;
+mod clock;
 mod div;
 pub mod offset;
 #[cfg(not(feature = "clock"))]
@@ -490,7 +535,11 @@ pub mod naive {
     mod isoweek;
     mod time;
 
-    pub use self::date::{NaiveDate, MAX_DATE, MIN_DATE};
+    pub use self::date::{days_in_month, days_in_year, MAX_DATE, MIN_DATE};
+    pub use self::date::{
+        weekday_from_days, NaiveDate, NaiveDateDaysIterator, NaiveDateStepRange, NaiveWeek,
+        OutOfRangeError,
+    };
     #[cfg(feature = "rustc-serialize")]
     #[allow(deprecated)]
     pub use self::datetime::rustc_serialize::TsSeconds;
@@ -511,10 +560,22 @@ pub mod naive {
         pub use super::datetime::serde::*;
     }
 }
+#[cfg(feature = "cron")]
+pub mod cron;
 mod date;
 mod datetime;
+mod days;
+mod duration_ext;
+mod erased_date;
 pub mod format;
+pub mod holiday;
+mod instant;
+mod month;
+mod names;
+mod range;
 mod round;
+#[cfg(test)]
+mod test_zones;
 
 /// Serialization/Deserialization in alternate formats
 ///
@@ -661,8 +722,153 @@ impl Weekday {
             Weekday::Sun => 0,
         }
     }
+
+    /// Returns the abbreviated English name of the day, as printed by the `%a` specifier.
+    ///
+    /// `w`:              | `Mon`   | `Tue`   | `Wed`   | `Thu`   | `Fri`   | `Sat`   | `Sun`
+    /// ----------------- | ------- | ------- | ------- | ------- | ------- | ------- | -------
+    /// `w.short_name()`: | `"Mon"` | `"Tue"` | `"Wed"` | `"Thu"` | `"Fri"` | `"Sat"` | `"Sun"`
+    #[inline]
+    pub fn short_name(&self) -> &'static str {
+        names::SHORT_WEEKDAYS[self.num_days_from_monday() as usize]
+    }
+
+    /// Returns the full English name of the day, as printed by the `%A` specifier.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::Weekday;
+    ///
+    /// assert_eq!(Weekday::Mon.long_name(), "Monday");
+    /// assert_eq!(Weekday::Wed.long_name(), "Wednesday");
+    /// ~~~~
+    #[inline]
+    pub fn long_name(&self) -> &'static str {
+        names::LONG_WEEKDAYS[self.num_days_from_monday() as usize]
+    }
+
+    /// Returns all days of week, starting from Monday.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::Weekday;
+    ///
+    /// assert_eq!(Weekday::all()[0], Weekday::Mon);
+    /// assert_eq!(Weekday::all()[6], Weekday::Sun);
+    /// ~~~~
+    #[inline]
+    pub fn all() -> [Weekday; 7] {
+        [
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+            Weekday::Sat,
+            Weekday::Sun,
+        ]
+    }
+
+    /// Returns an iterator over all seven days of week, starting from `start`.
+    ///
+    /// # Example
+    ///
+    /// Combined with [`NaiveDate::week`](./naive/struct.NaiveDate.html#method.week),
+    /// this makes a calendar for weeks starting from Sunday:
+    ///
+    /// ~~~~
+    /// use chrono::{Datelike, Days, NaiveDate, Weekday};
+    ///
+    /// let start = Weekday::Sun;
+    /// let header: Vec<String> = Weekday::iter_from(start).map(|w| format!("{:?}", w)).collect();
+    /// let mut lines = vec![header.join(" ")];
+    ///
+    /// let first = NaiveDate::from_ymd(2020, 2, 1);
+    /// let mut week = first.week(start);
+    /// while week.first_day() <= NaiveDate::from_ymd(2020, 2, 29) {
+    ///     let days: Vec<String> = (0..7).map(|i| {
+    ///         let day = week.first_day() + Days(i);
+    ///         if day.month() == first.month() {
+    ///             format!("{:>3}", day.day())
+    ///         } else {
+    ///             "   ".to_owned()
+    ///         }
+    ///     }).collect();
+    ///     lines.push(days.join(" ").trim_right().to_owned());
+    ///     week = (week.last_day() + Days(1)).week(start);
+    /// }
+    ///
+    /// assert_eq!(lines.join("\n"), "\
+    /// Sun Mon Tue Wed Thu Fri Sat
+    ///                           1
+    ///   2   3   4   5   6   7   8
+    ///   9  10  11  12  13  14  15
+    ///  16  17  18  19  20  21  22
+    ///  23  24  25  26  27  28  29");
+    /// ~~~~
+    #[inline]
+    pub fn iter_from(start: Weekday) -> WeekdayIter {
+        WeekdayIter {
+            start: start,
+            front: 0,
+            back: 7,
+        }
+    }
+}
+
+/// An iterator over the days of week, made by [`Weekday::iter_from`](./enum.Weekday.html#method.iter_from).
+///
+/// It yields each day of week exactly once.
+#[derive(Clone, Debug)]
+pub struct WeekdayIter {
+    start: Weekday,
+    front: u32,
+    back: u32,
+}
+
+impl WeekdayIter {
+    fn nth_weekday(&self, n: u32) -> Weekday {
+        Weekday::all()[((self.start.num_days_from_monday() + n) % 7) as usize]
+    }
+}
+
+impl Iterator for WeekdayIter {
+    type Item = Weekday;
+
+    #[inline]
+    fn next(&mut self) -> Option<Weekday> {
+        if self.front < self.back {
+            let weekday = self.nth_weekday(self.front);
+            self.front += 1;
+            Some(weekday)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.back - self.front) as usize;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for WeekdayIter {
+    #[inline]
+    fn next_back(&mut self) -> Option<Weekday> {
+        if self.front < self.back {
+            self.back -= 1;
+            Some(self.nth_weekday(self.back))
+        } else {
+            None
+        }
+    }
 }
 
+impl ExactSizeIterator for WeekdayIter {}
+
 /// Any weekday can be represented as an integer from 0 to 6, which equals to
 /// [`Weekday::num_days_from_monday`](#method.num_days_from_monday) in this implementation.
 /// Do not heavily depend on this though; use explicit methods whenever possible.
@@ -823,7 +1029,169 @@ mod weekday_serde {
     }
 }
 
+/// The month of the year.
+///
+/// The month number (from 1 to 12) is returned by
+/// [`Datelike::month`](./trait.Datelike.html#tymethod.month);
+/// this type is for enumerating or naming months.
+/// Like [`Weekday`](./enum.Weekday.html), this type does *not* implement
+/// `PartialOrd` or `Ord` traits, as the order depends on the context (e.g. fiscal years).
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash)]
+#[cfg_attr(feature = "rustc-serialize", derive(RustcEncodable, RustcDecodable))]
+pub enum Month {
+    /// January.
+    January = 0,
+    /// February.
+    February = 1,
+    /// March.
+    March = 2,
+    /// April.
+    April = 3,
+    /// May.
+    May = 4,
+    /// June.
+    June = 5,
+    /// July.
+    July = 6,
+    /// August.
+    August = 7,
+    /// September.
+    September = 8,
+    /// October.
+    October = 9,
+    /// November.
+    November = 10,
+    /// December.
+    December = 11,
+}
+
+impl Month {
+    /// Returns the month from the month number starting from 1.
+    ///
+    /// Returns `None` when the number is not between 1 and 12.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{Datelike, Month, NaiveDate};
+    ///
+    /// let d = NaiveDate::from_ymd(2019, 12, 25);
+    /// assert_eq!(Month::from_number(d.month()), Some(Month::December));
+    /// assert_eq!(Month::from_number(13), None);
+    /// ~~~~
+    #[inline]
+    pub fn from_number(n: u32) -> Option<Month> {
+        if n == 0 {
+            return None;
+        }
+        Month::all().get(n as usize - 1).cloned()
+    }
+
+    /// The next month, wrapping from December to January.
+    #[inline]
+    pub fn succ(&self) -> Month {
+        Month::all()[(self.number_from_month() % 12) as usize]
+    }
+
+    /// The previous month, wrapping from January to December.
+    #[inline]
+    pub fn pred(&self) -> Month {
+        Month::all()[((self.number_from_month() + 10) % 12) as usize]
+    }
+
+    /// Returns a month number starting from January = 1.
+    ///
+    /// This is the same number as [`Datelike::month`](./trait.Datelike.html#tymethod.month).
+    #[inline]
+    pub fn number_from_month(&self) -> u32 {
+        *self as u32 + 1
+    }
+
+    /// Returns all months of the year, starting from January.
+    #[inline]
+    pub fn all() -> [Month; 12] {
+        [
+            Month::January,
+            Month::February,
+            Month::March,
+            Month::April,
+            Month::May,
+            Month::June,
+            Month::July,
+            Month::August,
+            Month::September,
+            Month::October,
+            Month::November,
+            Month::December,
+        ]
+    }
+
+    /// Returns an iterator over all twelve months of the year, starting from January.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{Month, NaiveDate};
+    ///
+    /// let first_days: Vec<NaiveDate> = Month::iter()
+    ///     .map(|m| NaiveDate::from_ymd(2020, m.number_from_month(), 1))
+    ///     .collect();
+    /// assert_eq!(first_days.len(), 12);
+    /// assert_eq!(first_days[1], NaiveDate::from_ymd(2020, 2, 1));
+    /// assert_eq!(Month::iter().rev().next(), Some(Month::December));
+    /// ~~~~
+    #[inline]
+    pub fn iter() -> MonthIter {
+        MonthIter { front: 0, back: 12 }
+    }
+}
+
+/// An iterator over the months of the year, made by [`Month::iter`](./enum.Month.html#method.iter).
+#[derive(Clone, Debug)]
+pub struct MonthIter {
+    front: u32,
+    back: u32,
+}
+
+impl Iterator for MonthIter {
+    type Item = Month;
+
+    #[inline]
+    fn next(&mut self) -> Option<Month> {
+        if self.front < self.back {
+            let month = Month::all()[self.front as usize];
+            self.front += 1;
+            Some(month)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.back - self.front) as usize;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for MonthIter {
+    #[inline]
+    fn next_back(&mut self) -> Option<Month> {
+        if self.front < self.back {
+            self.back -= 1;
+            Some(Month::all()[self.back as usize])
+        } else {
+            None
+        }
+    }
+}
+
+impl ExactSizeIterator for MonthIter {}
+
 /// The common set of methods for date component.
+///
+/// The `with_*` methods return `Self`, so this trait can't be used as a trait object;
+/// use [`ErasedDate`](./struct.ErasedDate.html) to keep different date types together.
 pub trait Datelike: Sized {
     /// Returns the year number in the [calendar date](./naive/struct.NaiveDate.html#calendar-date).
     fn year(&self) -> i32;
@@ -874,7 +1242,15 @@ pub trait Datelike: Sized {
     fn weekday(&self) -> Weekday;
 
     /// Returns the ISO week.
-    fn iso_week(&self) -> IsoWeek;
+    ///
+    /// The default implementation derives it from [`year`](#tymethod.year)
+    /// and [`ordinal`](#tymethod.ordinal), and panics when they are out of the range of
+    /// [`NaiveDate`](./naive/struct.NaiveDate.html).
+    fn iso_week(&self) -> IsoWeek {
+        NaiveDate::from_yo_opt(self.year(), self.ordinal())
+            .expect("Datelike::iso_week: out-of-range date")
+            .iso_week()
+    }
 
     /// Makes a new value with the year number changed.
     ///
@@ -950,6 +1326,22 @@ pub trait Datelike: Sized {
     fn num_days_from_epoch(&self) -> i32 {
         self.num_days_from_ce() - EPOCH_NUM_DAYS_FROM_CE
     }
+
+    /// Returns the day of month with the English ordinal suffix, like `1st` or `22nd`.
+    ///
+    /// # Example:
+    ///
+    /// ~~~
+    /// use chrono::{NaiveDate, Datelike};
+    ///
+    /// assert_eq!(NaiveDate::from_ymd(2015, 3, 3).day_ordinal_string(), "3rd");
+    /// assert_eq!(NaiveDate::from_ymd(2015, 3, 12).day_ordinal_string(), "12th");
+    /// assert_eq!(NaiveDate::from_ymd(2015, 3, 31).day_ordinal_string(), "31st");
+    /// ~~~
+    fn day_ordinal_string(&self) -> String {
+        let day = self.day();
+        format!("{}{}", day, format::ordinal_suffix(day))
+    }
 }
 
 /// The common set of methods for time component.
@@ -1040,3 +1432,217 @@ fn test_readme_doomsday() {
         assert!(other_dates.iter().all(|d| d.weekday() == weekday));
     }
 }
+
+#[test]
+fn test_weekday_iter_from() {
+    use Weekday::*;
+
+    let all: Vec<Weekday> = Weekday::iter_from(Mon).collect();
+    assert_eq!(all, Weekday::all().to_vec());
+    let from_sun: Vec<Weekday> = Weekday::iter_from(Sun).collect();
+    assert_eq!(from_sun, vec![Sun, Mon, Tue, Wed, Thu, Fri, Sat]);
+    let rev: Vec<Weekday> = Weekday::iter_from(Wed).rev().collect();
+    assert_eq!(rev, vec![Tue, Mon, Sun, Sat, Fri, Thu, Wed]);
+
+    let mut iter = Weekday::iter_from(Sat);
+    assert_eq!(iter.len(), 7);
+    assert_eq!(iter.next(), Some(Sat));
+    assert_eq!(iter.next_back(), Some(Fri));
+    assert_eq!(iter.len(), 5);
+    assert_eq!(iter.by_ref().count(), 5);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+
+    for w in Weekday::iter_from(Mon) {
+        assert_eq!(Weekday::iter_from(w).next(), Some(w));
+        assert_eq!(Weekday::iter_from(w).nth(1), Some(w.succ()));
+        assert_eq!(Weekday::iter_from(w).next_back(), Some(w.pred()));
+    }
+}
+
+#[test]
+fn test_weekday_names() {
+    for w in Weekday::iter_from(Weekday::Mon) {
+        assert_eq!(w.short_name(), format!("{:?}", w));
+        assert!(w.long_name().starts_with(w.short_name()));
+        assert_eq!(w.long_name().parse::<Weekday>(), Ok(w));
+        assert_eq!(w.short_name().parse::<Weekday>(), Ok(w));
+    }
+}
+
+#[test]
+fn test_month_iter() {
+    let all: Vec<u32> = Month::iter().map(|m| m.number_from_month()).collect();
+    assert_eq!(all, (1..13).collect::<Vec<u32>>());
+    assert_eq!(Month::iter().len(), 12);
+
+    let mut iter = Month::iter();
+    assert_eq!(iter.next_back(), Some(Month::December));
+    assert_eq!(iter.next(), Some(Month::January));
+    assert_eq!(iter.len(), 10);
+    assert_eq!(iter.by_ref().count(), 10);
+    assert_eq!(iter.next_back(), None);
+
+    for m in Month::iter() {
+        assert_eq!(Month::from_number(m.number_from_month()), Some(m));
+        assert_eq!(m.succ().pred(), m);
+    }
+    assert_eq!(Month::December.succ(), Month::January);
+    assert_eq!(Month::January.pred(), Month::December);
+    assert_eq!(Month::from_number(0), None);
+    assert_eq!(Month::from_number(13), None);
+}
+
+#[test]
+fn test_duration_unit_constants() {
+    let sec = Duration::seconds(1);
+    assert_eq!(sec.num_nanoseconds(), Some(NANOSECONDS_PER_SECOND));
+    assert_eq!(sec.num_microseconds(), Some(MICROSECONDS_PER_SECOND));
+    assert_eq!(sec.num_milliseconds(), MILLISECONDS_PER_SECOND);
+    let milli = Duration::milliseconds(1);
+    assert_eq!(milli.num_nanoseconds(), Some(NANOSECONDS_PER_MILLISECOND));
+    assert_eq!(milli.num_microseconds(), Some(MICROSECONDS_PER_MILLISECOND));
+    assert_eq!(
+        Duration::microseconds(1).num_nanoseconds(),
+        Some(NANOSECONDS_PER_MICROSECOND)
+    );
+    assert_eq!(Duration::minutes(1).num_seconds(), SECONDS_PER_MINUTE);
+    assert_eq!(Duration::hours(1).num_seconds(), SECONDS_PER_HOUR);
+    assert_eq!(Duration::days(1).num_seconds(), SECONDS_PER_DAY);
+    assert_eq!(Duration::weeks(1).num_seconds(), SECONDS_PER_WEEK);
+}
+
+#[test]
+fn test_duration_from_milliseconds() {
+    use std::time::Duration as StdDuration;
+    use std::{i64, u64};
+
+    // every `i64` number of milliseconds is representable
+    for &ms in &[0, 1, -1, i64::MAX, i64::MIN] {
+        assert_eq!(Duration::milliseconds(ms).num_milliseconds(), ms);
+    }
+
+    // `u64` milliseconds go through `std::time::Duration`, failing beyond `i64::MAX`
+    let from_u64 = |ms: u64| Duration::from_std(StdDuration::from_millis(ms));
+    assert_eq!(from_u64(1234).ok(), Some(Duration::milliseconds(1234)));
+    assert_eq!(
+        from_u64(i64::MAX as u64).ok(),
+        Some(Duration::milliseconds(i64::MAX))
+    );
+    assert!(from_u64(i64::MAX as u64 + 1).is_err());
+    assert!(from_u64(u64::MAX).is_err());
+}
+
+#[test]
+fn test_datelike_default_iso_week() {
+    // delegates everything but `iso_week` to `NaiveDate`
+    struct Ymd(NaiveDate);
+
+    impl Datelike for Ymd {
+        fn year(&self) -> i32 {
+            self.0.year()
+        }
+        fn month(&self) -> u32 {
+            self.0.month()
+        }
+        fn month0(&self) -> u32 {
+            self.0.month0()
+        }
+        fn day(&self) -> u32 {
+            self.0.day()
+        }
+        fn day0(&self) -> u32 {
+            self.0.day0()
+        }
+        fn ordinal(&self) -> u32 {
+            self.0.ordinal()
+        }
+        fn ordinal0(&self) -> u32 {
+            self.0.ordinal0()
+        }
+        fn weekday(&self) -> Weekday {
+            self.0.weekday()
+        }
+        fn with_year(&self, year: i32) -> Option<Ymd> {
+            self.0.with_year(year).map(Ymd)
+        }
+        fn with_month(&self, month: u32) -> Option<Ymd> {
+            self.0.with_month(month).map(Ymd)
+        }
+        fn with_month0(&self, month0: u32) -> Option<Ymd> {
+            self.0.with_month0(month0).map(Ymd)
+        }
+        fn with_day(&self, day: u32) -> Option<Ymd> {
+            self.0.with_day(day).map(Ymd)
+        }
+        fn with_day0(&self, day0: u32) -> Option<Ymd> {
+            self.0.with_day0(day0).map(Ymd)
+        }
+        fn with_ordinal(&self, ordinal: u32) -> Option<Ymd> {
+            self.0.with_ordinal(ordinal).map(Ymd)
+        }
+        fn with_ordinal0(&self, ordinal0: u32) -> Option<Ymd> {
+            self.0.with_ordinal0(ordinal0).map(Ymd)
+        }
+    }
+
+    // every weekday of the year starts both at the end and the beginning of a year
+    for year in 2014..2026 {
+        for &(month, day) in &[
+            (1, 1),
+            (1, 3),
+            (1, 4),
+            (6, 15),
+            (12, 28),
+            (12, 29),
+            (12, 31),
+        ] {
+            let date = NaiveDate::from_ymd(year, month, day);
+            assert_eq!(Ymd(date).iso_week(), date.iso_week(), "{}", date);
+        }
+    }
+    for &date in &[naive::MIN_DATE, naive::MAX_DATE] {
+        assert_eq!(Ymd(date).iso_week(), date.iso_week());
+    }
+}
+
+#[test]
+fn test_timelike_generic() {
+    fn check<T: Timelike + PartialEq + fmt::Debug>(t: T) {
+        // 13:24:35.123456789
+        assert_eq!((t.hour(), t.minute(), t.second()), (13, 24, 35));
+        assert_eq!(t.hour12(), (true, 1));
+        assert_eq!(t.nanosecond(), 123_456_789);
+        assert_eq!(t.num_seconds_from_midnight(), 13 * 3600 + 24 * 60 + 35);
+
+        assert_eq!(t.with_hour(0).map(|t| t.hour12()), Some((false, 12)));
+        assert_eq!(t.with_hour(24), None);
+        assert_eq!(t.with_minute(59).map(|t| t.minute()), Some(59));
+        assert_eq!(t.with_minute(60), None);
+        assert_eq!(
+            t.with_second(0).map(|t| t.num_seconds_from_midnight()),
+            Some(48_240)
+        );
+        assert_eq!(t.with_second(60), None);
+        assert_eq!(t.with_nanosecond(0).map(|t| t.nanosecond()), Some(0));
+        // a leap second can follow any second, as in the constructors
+        let leap = t.with_nanosecond(1_999_999_999).unwrap();
+        assert_eq!((leap.second(), leap.nanosecond()), (35, 1_999_999_999));
+        assert_eq!(
+            leap.num_seconds_from_midnight(),
+            t.num_seconds_from_midnight()
+        );
+        assert_eq!(t.with_nanosecond(2_000_000_000), None);
+    }
+
+    let time = NaiveTime::from_hms_nano(13, 24, 35, 123_456_789);
+    check(time);
+    let dt = NaiveDate::from_ymd(2019, 12, 31).and_time(time);
+    check(dt);
+    check(Utc.from_utc_datetime(&dt));
+    check(
+        FixedOffset::east(9 * 3600)
+            .from_local_datetime(&dt)
+            .unwrap(),
+    );
+}
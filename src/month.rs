@@ -0,0 +1,26 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! The number of calendar months.
+
+/// The number of calendar months, to be added to or subtracted from dates.
+///
+/// Unlike a `Duration` counting fixed days, moving by `Months` keeps the day of month
+/// and only changes the month (and the year if needed).
+/// When the resulting month is shorter than the day of month,
+/// the day is clamped to the last day of that month.
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::{Months, NaiveDate};
+///
+/// let from_ymd = NaiveDate::from_ymd;
+///
+/// assert_eq!(from_ymd(2019, 12, 15) + Months(1), from_ymd(2020, 1, 15));
+/// assert_eq!(from_ymd(2020, 1, 31) + Months(1), from_ymd(2020, 2, 29));
+/// assert_eq!(from_ymd(2020, 3, 31) - Months(1), from_ymd(2020, 2, 29));
+/// assert_eq!(from_ymd(2020, 2, 29) + Months(12), from_ymd(2021, 2, 28));
+/// ~~~~
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Months(pub u32);
@@ -3,16 +3,19 @@
 
 //! ISO 8601 calendar date without timezone.
 
-use num_traits::ToPrimitive;
+use num_traits::{FromPrimitive, ToPrimitive};
 use oldtime::Duration as OldDuration;
+use std::error::Error;
 use std::ops::{Add, AddAssign, Sub, SubAssign};
 use std::{fmt, str};
 
-use div::div_mod_floor;
+use div::{div_mod_floor, mod_floor};
 use format::{parse, DelayedFormat, ParseError, ParseResult, Parsed, StrftimeItems};
 use format::{Item, Numeric, Pad};
+use holiday::HolidayCalendar;
 use naive::{IsoWeek, NaiveDateTime, NaiveTime};
-use {Datelike, Weekday};
+use names::LONG_MONTHS;
+use {Age, Datelike, Days, Months, Weekday};
 
 use super::internals::{self, DateImpl, Mdf, Of, YearFlags};
 use super::isoweek;
@@ -20,6 +23,19 @@ use super::isoweek;
 const MAX_YEAR: i32 = internals::MAX_YEAR;
 const MIN_YEAR: i32 = internals::MIN_YEAR;
 
+/// The Julian Day Number of December 31, 1 BCE, i.e. day 0 in `num_days_from_ce`.
+const JDN_OF_DAY_0_FROM_CE: i32 = 1_721_425;
+
+/// December 30, 1899 in `num_days_from_ce`, i.e. the day 0 of the Excel serial dates
+/// from March 1, 1900 (the serial 61) onward.
+const EXCEL_DAY_0_FROM_CE: i32 = 693_594;
+
+/// The Excel serial date of the nonexistent February 29, 1900.
+const EXCEL_LEAP_BUG_SERIAL: u32 = 60;
+
+/// November 17, 1858 in `num_days_from_ce`, i.e. the day 0 of the Modified Julian Date.
+const MJD_DAY_0_FROM_CE: i32 = 678_576;
+
 //   MAX_YEAR-12-31 minus 0000-01-01
 // = ((MAX_YEAR+1)-01-01 minus 0001-01-01) + (0001-01-01 minus 0000-01-01) - 1 day
 // = ((MAX_YEAR+1)-01-01 minus 0001-01-01) + 365 days
@@ -80,7 +96,7 @@ const MAX_BITS: usize = 44;
 ///   For example, January 3, 2016 (Sunday) was on the last (53rd) week of 2015.
 ///
 /// Chrono's date types default to the ISO 8601 [calendar date](#calendar-date),
-/// but [`Datelike::iso_week`](../trait.Datelike.html#tymethod.iso_week) and
+/// but [`Datelike::iso_week`](../trait.Datelike.html#method.iso_week) and
 /// [`Datelike::weekday`](../trait.Datelike.html#tymethod.weekday) methods
 /// can be used to get the corresponding week date.
 ///
@@ -133,6 +149,71 @@ fn test_date_bounds() {
     );
 }
 
+/// Returns the day of week from the number of days since January 1, 1 CE (aka Day 1)
+/// in the proleptic Gregorian calendar,
+/// as returned by [`Datelike::num_days_from_ce`](../trait.Datelike.html#method.num_days_from_ce).
+///
+/// This is a constant-time modular computation and works for any `i32`,
+/// even out of the range of `NaiveDate`.
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::{Datelike, NaiveDate, Weekday};
+/// use chrono::naive::weekday_from_days;
+///
+/// assert_eq!(weekday_from_days(1), Weekday::Mon);
+/// assert_eq!(weekday_from_days(0), Weekday::Sun);
+/// let d = NaiveDate::from_ymd(2019, 12, 25);
+/// assert_eq!(weekday_from_days(d.num_days_from_ce()), Weekday::Wed);
+/// ~~~~
+#[inline]
+pub fn weekday_from_days(days_from_ce: i32) -> Weekday {
+    // the day 1 is Monday, so the day 0 is Sunday
+    Weekday::from_i32((mod_floor(days_from_ce, 7) + 6) % 7).unwrap()
+}
+
+/// Returns the number of days in given year of the proleptic Gregorian calendar,
+/// either 365 or 366.
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::naive::days_in_year;
+///
+/// assert_eq!(days_in_year(2019), 365);
+/// assert_eq!(days_in_year(2020), 366);
+/// assert_eq!(days_in_year(1900), 365);
+/// assert_eq!(days_in_year(2000), 366);
+/// ~~~~
+#[inline]
+pub fn days_in_year(year: i32) -> u32 {
+    YearFlags::from_year(year).ndays()
+}
+
+/// Returns the number of days in given month of given year of the proleptic Gregorian calendar.
+///
+/// Returns `None` when the month is not between 1 and 12.
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::naive::days_in_month;
+///
+/// assert_eq!(days_in_month(2019, 2), Some(28));
+/// assert_eq!(days_in_month(2020, 2), Some(29));
+/// assert_eq!(days_in_month(2020, 4), Some(30));
+/// assert_eq!(days_in_month(2020, 13), None);
+/// ~~~~
+pub fn days_in_month(year: i32, month: u32) -> Option<u32> {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => Some(31),
+        4 | 6 | 9 | 11 => Some(30),
+        2 => Some(days_in_year(year) - 337),
+        _ => None,
+    }
+}
+
 impl NaiveDate {
     /// Makes a new `NaiveDate` from year and packed ordinal-flags, with a verification.
     fn from_of(year: i32, of: Of) -> Option<NaiveDate> {
@@ -199,6 +280,62 @@ impl NaiveDate {
         NaiveDate::from_mdf(year, Mdf::new(month, day, flags))
     }
 
+    /// Makes a new `NaiveDate` from the [calendar date](#calendar-date)
+    /// (year, month and day).
+    ///
+    /// Returns `Err` on the out-of-range date, invalid month and/or day.
+    /// This is the same as [`from_ymd_opt`](#method.from_ymd_opt) but returns a `Result`.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::try_from_ymd(2015, 3, 14), Ok(NaiveDate::from_ymd(2015, 3, 14)));
+    /// assert!(NaiveDate::try_from_ymd(2015, 2, 29).is_err());
+    /// ~~~~
+    pub fn try_from_ymd(year: i32, month: u32, day: u32) -> Result<NaiveDate, OutOfRangeError> {
+        NaiveDate::from_ymd_opt(year, month, day).ok_or(OutOfRangeError(()))
+    }
+
+    /// Makes a new `NaiveDate` from the [calendar date](#calendar-date)
+    /// (year, month and day) without checking that it is valid.
+    ///
+    /// This is meant for hot paths where the inputs have been validated beforehand;
+    /// it skips the range checks of [`from_ymd_opt`](#method.from_ymd_opt).
+    /// The validity is still checked with debug assertions enabled.
+    ///
+    /// # Safety
+    ///
+    /// The date should be valid, i.e. `from_ymd_opt(year, month, day)` should return `Some`.
+    /// This requires that `year` is between the years of [`MIN_DATE`](./constant.MIN_DATE.html)
+    /// and [`MAX_DATE`](./constant.MAX_DATE.html), `month` is between 1 and 12,
+    /// and `day` is between 1 and the number of days in that month.
+    /// Otherwise the behavior is undefined:
+    /// the resulting value may silently represent some other date and
+    /// any method called on it may panic or return meaningless values.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// let d = unsafe { NaiveDate::from_ymd_unchecked(2015, 3, 14) };
+    /// assert_eq!(d, NaiveDate::from_ymd(2015, 3, 14));
+    /// ~~~~
+    #[inline]
+    pub unsafe fn from_ymd_unchecked(year: i32, month: u32, day: u32) -> NaiveDate {
+        debug_assert!(
+            NaiveDate::from_ymd_opt(year, month, day).is_some(),
+            "invalid date"
+        );
+        let flags = YearFlags::from_year(year);
+        let Of(of) = Mdf::new(month, day, flags).to_of();
+        NaiveDate {
+            ymdf: (year << 13) | (of as DateImpl),
+        }
+    }
+
     /// Makes a new `NaiveDate` from the [ordinal date](#ordinal-date)
     /// (year and day of the year).
     ///
@@ -417,7 +554,7 @@ impl NaiveDate {
     /// assert_eq!(from_ndays_opt(-100_000_000), None);
     /// ~~~~
     pub fn from_num_days_from_ce_opt(days: i32) -> Option<NaiveDate> {
-        let days = days + 365; // make December 31, 1 BCE equal to day 0
+        let days = try_opt!(days.checked_add(365)); // make December 31, 1 BCE equal to day 0
         let (year_div_400, cycle) = div_mod_floor(days, 146_097);
         let (year_mod_400, ordinal) = internals::cycle_to_yo(cycle as u32);
         let flags = YearFlags::from_year_mod_400(year_mod_400 as i32);
@@ -427,6 +564,230 @@ impl NaiveDate {
         )
     }
 
+    /// Makes a new `NaiveDate` from the number of days since January 1, 1 (Day 1)
+    /// in the proleptic Gregorian calendar, without checking that it is in range.
+    ///
+    /// This is meant for bulk conversions where the inputs have been validated beforehand;
+    /// it skips the range checks of [`from_num_days_from_ce_opt`](#method.from_num_days_from_ce_opt).
+    /// The validity is still checked with debug assertions enabled.
+    ///
+    /// # Safety
+    ///
+    /// `days` should be between the days of [`MIN_DATE`](./constant.MIN_DATE.html)
+    /// and [`MAX_DATE`](./constant.MAX_DATE.html) (inclusive),
+    /// i.e. `from_num_days_from_ce_opt(days)` should return `Some`.
+    /// Otherwise the behavior is undefined:
+    /// the resulting value may silently represent some other date and
+    /// any method called on it may panic or return meaningless values.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// let d = unsafe { NaiveDate::from_num_days_from_ce_unchecked(730_000) };
+    /// assert_eq!(d, NaiveDate::from_ymd(1999, 9, 3));
+    /// ~~~~
+    #[inline]
+    pub unsafe fn from_num_days_from_ce_unchecked(days: i32) -> NaiveDate {
+        debug_assert!(
+            NaiveDate::from_num_days_from_ce_opt(days).is_some(),
+            "out-of-range date"
+        );
+        let days = days + 365; // make December 31, 1 BCE equal to day 0
+        let (year_div_400, cycle) = div_mod_floor(days, 146_097);
+        let (year_mod_400, ordinal) = internals::cycle_to_yo(cycle as u32);
+        let flags = YearFlags::from_year_mod_400(year_mod_400 as i32);
+        let year = year_div_400 * 400 + year_mod_400 as i32;
+        let Of(of) = Of::new(ordinal, flags);
+        NaiveDate {
+            ymdf: (year << 13) | (of as DateImpl),
+        }
+    }
+
+    /// Makes a new `NaiveDate` from the [Julian Day Number][jdn] (JDN),
+    /// the number of days since January 1, 4713 BCE in the proleptic Julian calendar.
+    ///
+    /// The JDN of a date is that of its noon, so a date maps to exactly one JDN
+    /// and the time of day is not considered.
+    ///
+    /// Returns `None` on the out-of-range date.
+    ///
+    /// [jdn]: https://en.wikipedia.org/wiki/Julian_day
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// let from_jdn = NaiveDate::from_julian_day_number;
+    /// let from_ymd = NaiveDate::from_ymd;
+    ///
+    /// assert_eq!(from_jdn(2_440_588),   Some(from_ymd(1970, 1, 1)));
+    /// assert_eq!(from_jdn(2_451_545),   Some(from_ymd(2000, 1, 1)));
+    /// assert_eq!(from_jdn(0),           Some(from_ymd(-4713, 11, 24)));
+    /// assert_eq!(from_jdn(i32::max_value()), None);
+    /// ~~~~
+    pub fn from_julian_day_number(jdn: i32) -> Option<NaiveDate> {
+        let days = try_opt!(jdn.checked_sub(JDN_OF_DAY_0_FROM_CE));
+        NaiveDate::from_num_days_from_ce_opt(days)
+    }
+
+    /// Returns the [Julian Day Number][jdn] (JDN) of the date,
+    /// the number of days since January 1, 4713 BCE in the proleptic Julian calendar.
+    ///
+    /// This is the inverse of [`from_julian_day_number`](#method.from_julian_day_number).
+    ///
+    /// [jdn]: https://en.wikipedia.org/wiki/Julian_day
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::from_ymd(1970, 1, 1).to_julian_day_number(), 2_440_588);
+    /// assert_eq!(NaiveDate::from_ymd(1858, 11, 17).to_julian_day_number(), 2_400_001);
+    /// ~~~~
+    pub fn to_julian_day_number(&self) -> i32 {
+        self.num_days_from_ce() + JDN_OF_DAY_0_FROM_CE
+    }
+
+    /// Makes a new `NaiveDate` from the serial date used by Excel (in the default 1900 date system),
+    /// where the serial 1 is January 1, 1900.
+    ///
+    /// Excel, following Lotus 1-2-3, treats 1900 as a leap year,
+    /// so the serial 60 is the nonexistent February 29, 1900
+    /// and the serials from 61 are off by one from the actual number of days.
+    ///
+    /// Returns `None` for the serial 0 (January 0, 1900 in Excel), the serial 60,
+    /// and the out-of-range dates.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// let from_excel = NaiveDate::from_excel_serial;
+    ///
+    /// assert_eq!(from_excel(1), Some(NaiveDate::from_ymd(1900, 1, 1)));
+    /// assert_eq!(from_excel(59), Some(NaiveDate::from_ymd(1900, 2, 28)));
+    /// assert_eq!(from_excel(60), None);
+    /// assert_eq!(from_excel(61), Some(NaiveDate::from_ymd(1900, 3, 1)));
+    /// assert_eq!(from_excel(43_824), Some(NaiveDate::from_ymd(2019, 12, 25)));
+    /// assert_eq!(from_excel(0), None);
+    /// ~~~~
+    pub fn from_excel_serial(serial: u32) -> Option<NaiveDate> {
+        let days = match serial {
+            0 | EXCEL_LEAP_BUG_SERIAL => return None,
+            // the day 0 is December 31, 1899 before the nonexistent February 29
+            s if s < EXCEL_LEAP_BUG_SERIAL => s as i32 + 1,
+            s => try_opt!(s.to_i32()),
+        };
+        NaiveDate::from_num_days_from_ce_opt(try_opt!(days.checked_add(EXCEL_DAY_0_FROM_CE)))
+    }
+
+    /// Returns the serial date used by Excel (in the default 1900 date system),
+    /// where the serial 1 is January 1, 1900.
+    ///
+    /// This is the inverse of [`from_excel_serial`](#method.from_excel_serial),
+    /// so the serial 60 for the nonexistent February 29, 1900 is skipped.
+    /// Returns `None` for dates before January 1, 1900.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::from_ymd(1900, 1, 1).to_excel_serial(), Some(1));
+    /// assert_eq!(NaiveDate::from_ymd(1900, 3, 1).to_excel_serial(), Some(61));
+    /// assert_eq!(NaiveDate::from_ymd(2019, 12, 25).to_excel_serial(), Some(43_824));
+    /// assert_eq!(NaiveDate::from_ymd(1899, 12, 31).to_excel_serial(), None);
+    /// ~~~~
+    pub fn to_excel_serial(&self) -> Option<u32> {
+        let days = self.num_days_from_ce() - EXCEL_DAY_0_FROM_CE;
+        if days <= 1 {
+            None
+        } else if days <= EXCEL_LEAP_BUG_SERIAL as i32 {
+            Some(days as u32 - 1)
+        } else {
+            Some(days as u32)
+        }
+    }
+
+    /// Makes a new `NaiveDate` from the [Modified Julian Date][mjd] (MJD),
+    /// the number of days since November 17, 1858 00:00 UTC.
+    ///
+    /// Any fraction of a day is discarded, giving the date containing that instant;
+    /// use [`NaiveDateTime::from_mjd`](./struct.NaiveDateTime.html#method.from_mjd)
+    /// to keep the time of day.
+    /// Returns `None` when `mjd` is not finite or the date would be out of range.
+    ///
+    /// [mjd]: https://en.wikipedia.org/wiki/Julian_day#Variants
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::from_mjd(0.0), Some(NaiveDate::from_ymd(1858, 11, 17)));
+    /// assert_eq!(NaiveDate::from_mjd(58_842.75), Some(NaiveDate::from_ymd(2019, 12, 25)));
+    /// assert_eq!(NaiveDate::from_mjd(-0.5), Some(NaiveDate::from_ymd(1858, 11, 16)));
+    /// assert_eq!(NaiveDate::from_mjd(1.0e10), None);
+    /// ~~~~
+    pub fn from_mjd(mjd: f64) -> Option<NaiveDate> {
+        let days = try_opt!(mjd.floor().to_i32());
+        NaiveDate::from_num_days_from_ce_opt(try_opt!(days.checked_add(MJD_DAY_0_FROM_CE)))
+    }
+
+    /// Returns the [Modified Julian Date][mjd] (MJD) at the start of the date,
+    /// the number of days since November 17, 1858 00:00 UTC.
+    ///
+    /// This is the inverse of [`from_mjd`](#method.from_mjd).
+    ///
+    /// [mjd]: https://en.wikipedia.org/wiki/Julian_day#Variants
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::from_ymd(1858, 11, 17).to_mjd(), 0.0);
+    /// assert_eq!(NaiveDate::from_ymd(2019, 12, 25).to_mjd(), 58_842.0);
+    /// ~~~~
+    pub fn to_mjd(&self) -> f64 {
+        f64::from(self.num_days_from_ce() - MJD_DAY_0_FROM_CE)
+    }
+
+    /// Returns the earliest representable date, the same as [`MIN_DATE`](./constant.MIN_DATE.html).
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::min_value(), chrono::naive::MIN_DATE);
+    /// assert_eq!(NaiveDate::min_value().pred_opt(), None);
+    /// ~~~~
+    #[inline]
+    pub fn min_value() -> NaiveDate {
+        MIN_DATE
+    }
+
+    /// Returns the latest representable date, the same as [`MAX_DATE`](./constant.MAX_DATE.html).
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::max_value(), chrono::naive::MAX_DATE);
+    /// assert_eq!(NaiveDate::max_value().succ_opt(), None);
+    /// ~~~~
+    #[inline]
+    pub fn max_value() -> NaiveDate {
+        MAX_DATE
+    }
+
     /// Parses a string with the specified format string and returns a new `NaiveDate`.
     /// See the [`format::strftime` module](../format/strftime/index.html)
     /// on the supported escape sequences.
@@ -500,6 +861,7 @@ impl NaiveDate {
     /// use `NaiveDate::and_hms_*` methods with a subsecond parameter instead.
     ///
     /// Panics on invalid hour, minute and/or second.
+    /// Use [`and_hms_opt`](#method.and_hms_opt) to get `None` instead.
     ///
     /// # Example
     ///
@@ -547,6 +909,7 @@ impl NaiveDate {
     /// in order to represent the [leap second](./struct.NaiveTime.html#leap-second-handling).
     ///
     /// Panics on invalid hour, minute, second and/or millisecond.
+    /// Use [`and_hms_milli_opt`](#method.and_hms_milli_opt) to get `None` instead.
     ///
     /// # Example
     ///
@@ -604,6 +967,7 @@ impl NaiveDate {
     /// in order to represent the [leap second](./struct.NaiveTime.html#leap-second-handling).
     ///
     /// Panics on invalid hour, minute, second and/or microsecond.
+    /// Use [`and_hms_micro_opt`](#method.and_hms_micro_opt) to get `None` instead.
     ///
     /// # Example
     ///
@@ -661,6 +1025,7 @@ impl NaiveDate {
     /// in order to represent the [leap second](./struct.NaiveTime.html#leap-second-handling).
     ///
     /// Panics on invalid hour, minute, second and/or nanosecond.
+    /// Use [`and_hms_nano_opt`](#method.and_hms_nano_opt) to get `None` instead.
     ///
     /// # Example
     ///
@@ -823,589 +1188,1605 @@ impl NaiveDate {
             .or_else(|| NaiveDate::from_ymd_opt(self.year() - 1, 12, 31))
     }
 
-    /// Adds the `days` part of given `Duration` to the current date.
+    /// Returns the date of the Western (Gregorian) Easter Sunday in given year,
+    /// computed with the anonymous Gregorian algorithm (Meeus/Jones/Butcher).
     ///
-    /// Returns `None` when it will result in overflow.
+    /// Returns `None` for years before 1583, when the Gregorian calendar was not in use yet,
+    /// and the out-of-range dates.
     ///
     /// # Example
     ///
     /// ~~~~
-    /// # extern crate chrono; extern crate time; fn main() {
     /// use chrono::NaiveDate;
-    /// use chrono::naive::MAX_DATE;
-    /// use time::Duration;
     ///
-    /// let d = NaiveDate::from_ymd(2015, 9, 5);
-    /// assert_eq!(d.checked_add_signed(Duration::days(40)),
-    ///            Some(NaiveDate::from_ymd(2015, 10, 15)));
-    /// assert_eq!(d.checked_add_signed(Duration::days(-40)),
-    ///            Some(NaiveDate::from_ymd(2015, 7, 27)));
-    /// assert_eq!(d.checked_add_signed(Duration::days(1_000_000_000)), None);
-    /// assert_eq!(d.checked_add_signed(Duration::days(-1_000_000_000)), None);
-    /// assert_eq!(MAX_DATE.checked_add_signed(Duration::days(1)), None);
-    /// # }
+    /// assert_eq!(NaiveDate::easter_gregorian(2019), Some(NaiveDate::from_ymd(2019, 4, 21)));
+    /// assert_eq!(NaiveDate::easter_gregorian(2024), Some(NaiveDate::from_ymd(2024, 3, 31)));
+    /// assert_eq!(NaiveDate::easter_gregorian(1582), None);
     /// ~~~~
-    pub fn checked_add_signed(self, rhs: OldDuration) -> Option<NaiveDate> {
-        let year = self.year();
-        let (mut year_div_400, year_mod_400) = div_mod_floor(year, 400);
-        let cycle = internals::yo_to_cycle(year_mod_400 as u32, self.of().ordinal());
-        let cycle = try_opt!((cycle as i32).checked_add(try_opt!(rhs.num_days().to_i32())));
-        let (cycle_div_400y, cycle) = div_mod_floor(cycle, 146_097);
-        year_div_400 += cycle_div_400y;
-
-        let (year_mod_400, ordinal) = internals::cycle_to_yo(cycle as u32);
-        let flags = YearFlags::from_year_mod_400(year_mod_400 as i32);
-        NaiveDate::from_of(
-            year_div_400 * 400 + year_mod_400 as i32,
-            Of::new(ordinal, flags),
-        )
+    pub fn easter_gregorian(year: i32) -> Option<NaiveDate> {
+        if year < 1583 {
+            return None;
+        }
+        let a = year % 19;
+        let (b, c) = (year / 100, year % 100);
+        let (d, e) = (b / 4, b % 4);
+        let f = (b + 8) / 25;
+        let g = (b - f + 1) / 3;
+        let h = (19 * a + b - d - g + 15) % 30;
+        let (i, k) = (c / 4, c % 4);
+        let l = (32 + 2 * e + 2 * i - h - k) % 7;
+        let m = (a + 11 * h + 22 * l) / 451;
+        let n = h + l - 7 * m + 114;
+        NaiveDate::from_ymd_opt(year, (n / 31) as u32, (n % 31 + 1) as u32)
     }
 
-    /// Subtracts the `days` part of given `Duration` from the current date.
+    /// Returns the date of the Orthodox (Julian) Easter Sunday in given year,
+    /// as a date in the proleptic Gregorian calendar like any other `NaiveDate`.
     ///
-    /// Returns `None` when it will result in overflow.
+    /// This uses the Julian algorithm by Meeus, and then converts the resulting
+    /// Julian calendar date to the Gregorian calendar.
+    /// Returns `None` for years before 1583 and the out-of-range dates.
     ///
     /// # Example
     ///
     /// ~~~~
-    /// # extern crate chrono; extern crate time; fn main() {
     /// use chrono::NaiveDate;
-    /// use chrono::naive::MIN_DATE;
-    /// use time::Duration;
     ///
-    /// let d = NaiveDate::from_ymd(2015, 9, 5);
-    /// assert_eq!(d.checked_sub_signed(Duration::days(40)),
-    ///            Some(NaiveDate::from_ymd(2015, 7, 27)));
-    /// assert_eq!(d.checked_sub_signed(Duration::days(-40)),
-    ///            Some(NaiveDate::from_ymd(2015, 10, 15)));
-    /// assert_eq!(d.checked_sub_signed(Duration::days(1_000_000_000)), None);
-    /// assert_eq!(d.checked_sub_signed(Duration::days(-1_000_000_000)), None);
-    /// assert_eq!(MIN_DATE.checked_sub_signed(Duration::days(1)), None);
-    /// # }
+    /// assert_eq!(NaiveDate::easter_julian_in_gregorian(2019), Some(NaiveDate::from_ymd(2019, 4, 28)));
+    /// assert_eq!(NaiveDate::easter_julian_in_gregorian(2024), Some(NaiveDate::from_ymd(2024, 5, 5)));
     /// ~~~~
-    pub fn checked_sub_signed(self, rhs: OldDuration) -> Option<NaiveDate> {
-        let year = self.year();
-        let (mut year_div_400, year_mod_400) = div_mod_floor(year, 400);
-        let cycle = internals::yo_to_cycle(year_mod_400 as u32, self.of().ordinal());
-        let cycle = try_opt!((cycle as i32).checked_sub(try_opt!(rhs.num_days().to_i32())));
-        let (cycle_div_400y, cycle) = div_mod_floor(cycle, 146_097);
-        year_div_400 += cycle_div_400y;
-
-        let (year_mod_400, ordinal) = internals::cycle_to_yo(cycle as u32);
-        let flags = YearFlags::from_year_mod_400(year_mod_400 as i32);
-        NaiveDate::from_of(
-            year_div_400 * 400 + year_mod_400 as i32,
-            Of::new(ordinal, flags),
-        )
+    pub fn easter_julian_in_gregorian(year: i32) -> Option<NaiveDate> {
+        if year < 1583 {
+            return None;
+        }
+        let (a, b, c) = (year % 4, year % 7, year % 19);
+        let d = (19 * c + 15) % 30;
+        let e = (2 * a + 4 * b - d + 34) % 7;
+        let n = d + e + 114;
+        let (month, day) = (n / 31, n % 31 + 1);
+
+        // the Julian Day Number of the Julian calendar date
+        let a = (14 - month) / 12;
+        let y = try_opt!(year.checked_add(4800 - a));
+        let m = month + 12 * a - 3;
+        let jdn =
+            try_opt!(y.checked_mul(365)).checked_add(day + (153 * m + 2) / 5 + y / 4 - 32_083);
+        NaiveDate::from_julian_day_number(try_opt!(jdn))
     }
 
-    /// Subtracts another `NaiveDate` from the current date.
-    /// Returns a `Duration` of integral numbers.
+    /// Returns the date of the Western Good Friday in given year,
+    /// two days before [`easter_gregorian`](#method.easter_gregorian).
     ///
-    /// This does not overflow or underflow at all,
-    /// as all possible output fits in the range of `Duration`.
+    /// Returns `None` for years before 1583 and the out-of-range dates.
     ///
     /// # Example
     ///
     /// ~~~~
-    /// # extern crate chrono; extern crate time; fn main() {
     /// use chrono::NaiveDate;
-    /// use time::Duration;
     ///
-    /// let from_ymd = NaiveDate::from_ymd;
-    /// let since = NaiveDate::signed_duration_since;
+    /// assert_eq!(NaiveDate::good_friday(2019), Some(NaiveDate::from_ymd(2019, 4, 19)));
+    /// ~~~~
+    #[inline]
+    pub fn good_friday(year: i32) -> Option<NaiveDate> {
+        NaiveDate::easter_gregorian(year).and_then(|easter| easter.checked_sub_days(Days(2)))
+    }
+
+    /// Returns the date of the Western Whit Monday in given year,
+    /// fifty days after [`easter_gregorian`](#method.easter_gregorian).
+    ///
+    /// Returns `None` for years before 1583 and the out-of-range dates.
+    ///
+    /// # Example
     ///
-    /// assert_eq!(since(from_ymd(2014, 1, 1), from_ymd(2014, 1, 1)), Duration::zero());
-    /// assert_eq!(since(from_ymd(2014, 1, 1), from_ymd(2013, 12, 31)), Duration::days(1));
-    /// assert_eq!(since(from_ymd(2014, 1, 1), from_ymd(2014, 1, 2)), Duration::days(-1));
-    /// assert_eq!(since(from_ymd(2014, 1, 1), from_ymd(2013, 9, 23)), Duration::days(100));
-    /// assert_eq!(since(from_ymd(2014, 1, 1), from_ymd(2013, 1, 1)), Duration::days(365));
-    /// assert_eq!(since(from_ymd(2014, 1, 1), from_ymd(2010, 1, 1)), Duration::days(365*4 + 1));
-    /// assert_eq!(since(from_ymd(2014, 1, 1), from_ymd(1614, 1, 1)), Duration::days(365*400 + 97));
-    /// # }
     /// ~~~~
-    pub fn signed_duration_since(self, rhs: NaiveDate) -> OldDuration {
-        let year1 = self.year();
-        let year2 = rhs.year();
-        let (year1_div_400, year1_mod_400) = div_mod_floor(year1, 400);
-        let (year2_div_400, year2_mod_400) = div_mod_floor(year2, 400);
-        let cycle1 = i64::from(internals::yo_to_cycle(
-            year1_mod_400 as u32,
-            self.of().ordinal(),
-        ));
-        let cycle2 = i64::from(internals::yo_to_cycle(
-            year2_mod_400 as u32,
-            rhs.of().ordinal(),
-        ));
-        OldDuration::days(
-            (i64::from(year1_div_400) - i64::from(year2_div_400)) * 146_097 + (cycle1 - cycle2),
-        )
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::whit_monday(2019), Some(NaiveDate::from_ymd(2019, 6, 10)));
+    /// ~~~~
+    #[inline]
+    pub fn whit_monday(year: i32) -> Option<NaiveDate> {
+        NaiveDate::easter_gregorian(year).and_then(|easter| easter.checked_add_days(Days(50)))
     }
 
-    /// Formats the date with the specified formatting items.
-    /// Otherwise it is same to the ordinary `format` method.
+    /// Returns `true` when the current date is in an ISO week-based year with 53 weeks.
     ///
-    /// The `Iterator` of items should be `Clone`able,
-    /// since the resulting `DelayedFormat` value may be formatted multiple times.
+    /// Note that the ISO week-based year can differ from the calendar year
+    /// near the start and end of the year. See also
+    /// [`IsoWeek::weeks_in_year`](./struct.IsoWeek.html#method.weeks_in_year).
     ///
     /// # Example
     ///
     /// ~~~~
     /// use chrono::NaiveDate;
-    /// use chrono::format::strftime::StrftimeItems;
     ///
-    /// let fmt = StrftimeItems::new("%Y-%m-%d");
-    /// let d = NaiveDate::from_ymd(2015, 9, 5);
-    /// assert_eq!(d.format_with_items(fmt.clone()).to_string(), "2015-09-05");
-    /// assert_eq!(d.format("%Y-%m-%d").to_string(),             "2015-09-05");
+    /// assert!(NaiveDate::from_ymd(2020, 12, 31).is_in_long_iso_year());
+    /// assert!(NaiveDate::from_ymd(2021, 1, 3).is_in_long_iso_year()); // 2020-W53-7
+    /// assert!(!NaiveDate::from_ymd(2021, 1, 4).is_in_long_iso_year()); // 2021-W01-1
     /// ~~~~
+    #[inline]
+    pub fn is_in_long_iso_year(&self) -> bool {
+        IsoWeek::weeks_in_year(self.iso_week().year()) == 53
+    }
+
+    /// Returns the full English name of the month, e.g. `"January"`.
     ///
-    /// The resulting `DelayedFormat` can be formatted directly via the `Display` trait.
+    /// This is the same name as the `%B` format specifier produces.
+    ///
+    /// # Example
     ///
     /// ~~~~
-    /// # use chrono::NaiveDate;
-    /// # use chrono::format::strftime::StrftimeItems;
-    /// # let fmt = StrftimeItems::new("%Y-%m-%d").clone();
-    /// # let d = NaiveDate::from_ymd(2015, 9, 5);
-    /// assert_eq!(format!("{}", d.format_with_items(fmt)), "2015-09-05");
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 5).month_name(), "September");
+    /// assert_eq!(NaiveDate::from_ymd(2015, 12, 31).month_name(), "December");
     /// ~~~~
-    #[inline]
-    pub fn format_with_items<'a, I>(&self, items: I) -> DelayedFormat<I>
-    where
-        I: Iterator<Item = Item<'a>> + Clone,
-    {
-        DelayedFormat::new(Some(*self), None, items)
+    pub fn month_name(&self) -> &'static str {
+        LONG_MONTHS[self.month0() as usize]
     }
 
-    /// Formats the date with the specified format string.
-    /// See the [`format::strftime` module](../format/strftime/index.html)
-    /// on the supported escape sequences.
-    ///
-    /// This returns a `DelayedFormat`,
-    /// which gets converted to a string only when actual formatting happens.
-    /// You may use the `to_string` method to get a `String`,
-    /// or just feed it into `print!` and other formatting macros.
-    /// (In this way it avoids the redundant memory allocation.)
+    /// Returns the full English name of the day of the week, e.g. `"Monday"`.
     ///
-    /// A wrong format string does *not* issue an error immediately.
-    /// Rather, converting or formatting the `DelayedFormat` fails.
-    /// You are recommended to immediately use `DelayedFormat` for this reason.
+    /// This is the same name as the `%A` format specifier produces.
     ///
     /// # Example
     ///
     /// ~~~~
     /// use chrono::NaiveDate;
     ///
-    /// let d = NaiveDate::from_ymd(2015, 9, 5);
-    /// assert_eq!(d.format("%Y-%m-%d").to_string(), "2015-09-05");
-    /// assert_eq!(d.format("%A, %-d %B, %C%y").to_string(), "Saturday, 5 September, 2015");
+    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 5).weekday_name(), "Saturday");
+    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 7).weekday_name(), "Monday");
     /// ~~~~
+    pub fn weekday_name(&self) -> &'static str {
+        self.weekday().long_name()
+    }
+
+    /// Returns the week containing the current date, where weeks start from `start`.
     ///
-    /// The resulting `DelayedFormat` can be formatted directly via the `Display` trait.
+    /// This is useful for building calendars for locales where weeks do not start
+    /// from Monday as in the [ISO 8601 week date](#week-date).
+    ///
+    /// # Example
     ///
     /// ~~~~
-    /// # use chrono::NaiveDate;
-    /// # let d = NaiveDate::from_ymd(2015, 9, 5);
-    /// assert_eq!(format!("{}", d.format("%Y-%m-%d")), "2015-09-05");
-    /// assert_eq!(format!("{}", d.format("%A, %-d %B, %C%y")), "Saturday, 5 September, 2015");
+    /// use chrono::{NaiveDate, Weekday};
+    ///
+    /// let week = NaiveDate::from_ymd(2019, 12, 25).week(Weekday::Sun);
+    /// assert_eq!(week.first_day(), NaiveDate::from_ymd(2019, 12, 22));
+    /// assert_eq!(week.last_day(), NaiveDate::from_ymd(2019, 12, 28));
     /// ~~~~
     #[inline]
-    pub fn format<'a>(&self, fmt: &'a str) -> DelayedFormat<StrftimeItems<'a>> {
-        self.format_with_items(StrftimeItems::new(fmt))
+    pub fn week(&self, start: Weekday) -> NaiveWeek {
+        NaiveWeek {
+            date: *self,
+            start: start,
+        }
     }
-}
 
-impl Datelike for NaiveDate {
-    /// Returns the year number in the [calendar date](#calendar-date).
+    /// Returns the number of days from the current date until the next `weekday`,
+    /// from 0 (when the current date is already that `weekday`) to 6.
     ///
     /// # Example
     ///
     /// ~~~~
-    /// use chrono::{NaiveDate, Datelike};
+    /// use chrono::{NaiveDate, Weekday};
     ///
-    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).year(), 2015);
-    /// assert_eq!(NaiveDate::from_ymd(-308, 3, 14).year(), -308); // 309 BCE
+    /// let d = NaiveDate::from_ymd(2019, 12, 25); // Wednesday
+    /// assert_eq!(d.num_days_until(Weekday::Fri), 2);
+    /// assert_eq!(d.num_days_until(Weekday::Wed), 0);
+    /// assert_eq!(d.num_days_until(Weekday::Mon), 5);
     /// ~~~~
     #[inline]
-    fn year(&self) -> i32 {
-        self.ymdf >> 13
+    pub fn num_days_until(&self, weekday: Weekday) -> u32 {
+        let from = self.weekday().num_days_from_monday();
+        (weekday.num_days_from_monday() + 7 - from) % 7
     }
 
-    /// Returns the month number starting from 1.
-    ///
-    /// The return value ranges from 1 to 12.
+    /// Returns the number of days from the last `weekday` until the current date,
+    /// from 0 (when the current date is already that `weekday`) to 6.
     ///
     /// # Example
     ///
     /// ~~~~
-    /// use chrono::{NaiveDate, Datelike};
+    /// use chrono::{NaiveDate, Weekday};
     ///
-    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).month(), 9);
-    /// assert_eq!(NaiveDate::from_ymd(-308, 3, 14).month(), 3);
+    /// let d = NaiveDate::from_ymd(2019, 12, 25); // Wednesday
+    /// assert_eq!(d.num_days_since(Weekday::Fri), 5);
+    /// assert_eq!(d.num_days_since(Weekday::Wed), 0);
+    /// assert_eq!(d.num_days_since(Weekday::Mon), 2);
     /// ~~~~
     #[inline]
-    fn month(&self) -> u32 {
-        self.mdf().month()
+    pub fn num_days_since(&self, weekday: Weekday) -> u32 {
+        (7 - self.num_days_until(weekday)) % 7
     }
 
-    /// Returns the month number starting from 0.
+    /// Returns an iterator over the dates from the current date until `end`, exclusive.
     ///
-    /// The return value ranges from 0 to 11.
+    /// The iterator can be reversed to get the dates from the latest,
+    /// and never panics at the bounds of the supported dates.
+    /// It is empty when `end` is not later than the current date.
     ///
     /// # Example
     ///
     /// ~~~~
-    /// use chrono::{NaiveDate, Datelike};
+    /// use chrono::NaiveDate;
     ///
-    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).month0(), 8);
-    /// assert_eq!(NaiveDate::from_ymd(-308, 3, 14).month0(), 2);
+    /// let from_ymd = NaiveDate::from_ymd;
+    ///
+    /// let days: Vec<NaiveDate> = from_ymd(2019, 12, 30).iter_days_until(from_ymd(2020, 1, 2)).collect();
+    /// assert_eq!(days, [from_ymd(2019, 12, 30), from_ymd(2019, 12, 31), from_ymd(2020, 1, 1)]);
+    ///
+    /// let recent_first: Vec<NaiveDate> =
+    ///     from_ymd(2019, 12, 30).iter_days_until(from_ymd(2020, 1, 2)).rev().collect();
+    /// assert_eq!(recent_first, [from_ymd(2020, 1, 1), from_ymd(2019, 12, 31), from_ymd(2019, 12, 30)]);
     /// ~~~~
     #[inline]
-    fn month0(&self) -> u32 {
-        self.mdf().month() - 1
+    pub fn iter_days_until(&self, end: NaiveDate) -> NaiveDateDaysIterator {
+        let bounds = match end.pred_opt() {
+            Some(last) if *self <= last => Some((*self, last)),
+            _ => None,
+        };
+        NaiveDateDaysIterator { bounds: bounds }
     }
 
-    /// Returns the day of month starting from 1.
+    /// Returns an iterator over the dates from `start` until `end`, exclusive,
+    /// advancing by the number of whole days in `step`.
     ///
-    /// The return value ranges from 1 to 31. (The last day of month differs by months.)
+    /// The negative `step` counts backward from `start` down to `end`.
+    /// The iterator stops before going out of the supported dates,
+    /// knows its exact length and can be reversed.
+    ///
+    /// Panics when `step` is shorter than a day in either direction.
     ///
     /// # Example
     ///
     /// ~~~~
-    /// use chrono::{NaiveDate, Datelike};
+    /// # extern crate chrono; extern crate time; fn main() {
+    /// use chrono::NaiveDate;
+    /// use time::Duration;
     ///
-    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).day(), 8);
-    /// assert_eq!(NaiveDate::from_ymd(-308, 3, 14).day(), 14);
+    /// let from_ymd = NaiveDate::from_ymd;
+    ///
+    /// let weekly: Vec<NaiveDate> =
+    ///     NaiveDate::range(from_ymd(2019, 12, 2), from_ymd(2019, 12, 23), Duration::weeks(1)).collect();
+    /// assert_eq!(weekly, [from_ymd(2019, 12, 2), from_ymd(2019, 12, 9), from_ymd(2019, 12, 16)]);
+    ///
+    /// let backward: Vec<NaiveDate> =
+    ///     NaiveDate::range(from_ymd(2020, 1, 2), from_ymd(2019, 12, 30), Duration::days(-2)).collect();
+    /// assert_eq!(backward, [from_ymd(2020, 1, 2), from_ymd(2019, 12, 31)]);
+    /// # }
     /// ~~~~
+    pub fn range(start: NaiveDate, end: NaiveDate, step: OldDuration) -> NaiveDateStepRange {
+        let step = step.num_days();
+        assert!(
+            step != 0,
+            "NaiveDate::range: the step should be at least one day"
+        );
+        NaiveDateStepRange {
+            start: start,
+            end: end,
+            step: step,
+        }
+    }
+
+    /// Returns an iterator over the dates from `start` until `end`, exclusive,
+    /// advancing by `step` which should be a whole number of days.
     ///
-    /// Combined with [`NaiveDate::pred`](#method.pred),
-    /// one can determine the number of days in a particular month.
-    /// (Note that this panics when `year` is out of range.)
+    /// This is same to [`NaiveDate::range`](#method.range) except that
+    /// `step` is checked instead of being truncated to whole days.
+    /// The negative `step` counts backward from `start` down to `end`.
+    ///
+    /// Panics when `step` is zero or not a whole number of days.
+    ///
+    /// # Example
     ///
     /// ~~~~
-    /// use chrono::{NaiveDate, Datelike};
+    /// # extern crate chrono; extern crate time; fn main() {
+    /// use chrono::NaiveDate;
+    /// use time::Duration;
     ///
-    /// fn ndays_in_month(year: i32, month: u32) -> u32 {
-    ///     // the first day of the next month...
-    ///     let (y, m) = if month == 12 { (year + 1, 1) } else { (year, month + 1) };
-    ///     let d = NaiveDate::from_ymd(y, m, 1);
+    /// let from_ymd = NaiveDate::from_ymd;
     ///
-    ///     // ...is preceded by the last day of the original month
-    ///     d.pred().day()
-    /// }
+    /// let range = NaiveDate::range_step(from_ymd(2019, 12, 2), from_ymd(2019, 12, 23), Duration::weeks(1));
+    /// assert_eq!(range.len(), 3);
     ///
-    /// assert_eq!(ndays_in_month(2015, 8), 31);
-    /// assert_eq!(ndays_in_month(2015, 9), 30);
-    /// assert_eq!(ndays_in_month(2015, 12), 31);
-    /// assert_eq!(ndays_in_month(2016, 2), 29);
-    /// assert_eq!(ndays_in_month(2017, 2), 28);
+    /// let latest_first: Vec<NaiveDate> = range.rev().collect();
+    /// assert_eq!(latest_first, [from_ymd(2019, 12, 16), from_ymd(2019, 12, 9), from_ymd(2019, 12, 2)]);
+    /// # }
     /// ~~~~
-    #[inline]
-    fn day(&self) -> u32 {
-        self.mdf().day()
+    pub fn range_step(start: NaiveDate, end: NaiveDate, step: OldDuration) -> NaiveDateStepRange {
+        let days = step.num_days();
+        assert!(
+            days != 0 && OldDuration::days(days) == step,
+            "NaiveDate::range_step: the step should be a non-zero whole number of days"
+        );
+        NaiveDateStepRange {
+            start: start,
+            end: end,
+            step: days,
+        }
     }
 
-    /// Returns the day of month starting from 0.
+    /// Adds the `days` part of given `Duration` to the current date.
     ///
-    /// The return value ranges from 0 to 30. (The last day of month differs by months.)
+    /// Returns `None` when it will result in overflow.
     ///
     /// # Example
     ///
     /// ~~~~
-    /// use chrono::{NaiveDate, Datelike};
+    /// # extern crate chrono; extern crate time; fn main() {
+    /// use chrono::NaiveDate;
+    /// use chrono::naive::MAX_DATE;
+    /// use time::Duration;
     ///
-    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).day0(), 7);
-    /// assert_eq!(NaiveDate::from_ymd(-308, 3, 14).day0(), 13);
+    /// let d = NaiveDate::from_ymd(2015, 9, 5);
+    /// assert_eq!(d.checked_add_signed(Duration::days(40)),
+    ///            Some(NaiveDate::from_ymd(2015, 10, 15)));
+    /// assert_eq!(d.checked_add_signed(Duration::days(-40)),
+    ///            Some(NaiveDate::from_ymd(2015, 7, 27)));
+    /// assert_eq!(d.checked_add_signed(Duration::days(1_000_000_000)), None);
+    /// assert_eq!(d.checked_add_signed(Duration::days(-1_000_000_000)), None);
+    /// assert_eq!(MAX_DATE.checked_add_signed(Duration::days(1)), None);
+    /// # }
     /// ~~~~
-    #[inline]
-    fn day0(&self) -> u32 {
-        self.mdf().day() - 1
+    pub fn checked_add_signed(self, rhs: OldDuration) -> Option<NaiveDate> {
+        let year = self.year();
+        let (mut year_div_400, year_mod_400) = div_mod_floor(year, 400);
+        let cycle = internals::yo_to_cycle(year_mod_400 as u32, self.of().ordinal());
+        let cycle = try_opt!((cycle as i32).checked_add(try_opt!(rhs.num_days().to_i32())));
+        let (cycle_div_400y, cycle) = div_mod_floor(cycle, 146_097);
+        year_div_400 += cycle_div_400y;
+
+        let (year_mod_400, ordinal) = internals::cycle_to_yo(cycle as u32);
+        let flags = YearFlags::from_year_mod_400(year_mod_400 as i32);
+        NaiveDate::from_of(
+            year_div_400 * 400 + year_mod_400 as i32,
+            Of::new(ordinal, flags),
+        )
     }
 
-    /// Returns the day of year starting from 1.
+    /// Subtracts the `days` part of given `Duration` from the current date.
     ///
-    /// The return value ranges from 1 to 366. (The last day of year differs by years.)
+    /// Returns `None` when it will result in overflow.
     ///
     /// # Example
     ///
     /// ~~~~
-    /// use chrono::{NaiveDate, Datelike};
+    /// # extern crate chrono; extern crate time; fn main() {
+    /// use chrono::NaiveDate;
+    /// use chrono::naive::MIN_DATE;
+    /// use time::Duration;
     ///
-    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).ordinal(), 251);
-    /// assert_eq!(NaiveDate::from_ymd(-308, 3, 14).ordinal(), 74);
+    /// let d = NaiveDate::from_ymd(2015, 9, 5);
+    /// assert_eq!(d.checked_sub_signed(Duration::days(40)),
+    ///            Some(NaiveDate::from_ymd(2015, 7, 27)));
+    /// assert_eq!(d.checked_sub_signed(Duration::days(-40)),
+    ///            Some(NaiveDate::from_ymd(2015, 10, 15)));
+    /// assert_eq!(d.checked_sub_signed(Duration::days(1_000_000_000)), None);
+    /// assert_eq!(d.checked_sub_signed(Duration::days(-1_000_000_000)), None);
+    /// assert_eq!(MIN_DATE.checked_sub_signed(Duration::days(1)), None);
+    /// # }
     /// ~~~~
+    pub fn checked_sub_signed(self, rhs: OldDuration) -> Option<NaiveDate> {
+        let year = self.year();
+        let (mut year_div_400, year_mod_400) = div_mod_floor(year, 400);
+        let cycle = internals::yo_to_cycle(year_mod_400 as u32, self.of().ordinal());
+        let cycle = try_opt!((cycle as i32).checked_sub(try_opt!(rhs.num_days().to_i32())));
+        let (cycle_div_400y, cycle) = div_mod_floor(cycle, 146_097);
+        year_div_400 += cycle_div_400y;
+
+        let (year_mod_400, ordinal) = internals::cycle_to_yo(cycle as u32);
+        let flags = YearFlags::from_year_mod_400(year_mod_400 as i32);
+        NaiveDate::from_of(
+            year_div_400 * 400 + year_mod_400 as i32,
+            Of::new(ordinal, flags),
+        )
+    }
+
+    /// Adds given number of calendar months to the current date,
+    /// clamping the day of month to the last day of the resulting month.
     ///
-    /// Combined with [`NaiveDate::pred`](#method.pred),
-    /// one can determine the number of days in a particular year.
-    /// (Note that this panics when `year` is out of range.)
+    /// Returns `None` when the resulting date would be out of range.
     ///
-    /// ~~~~
-    /// use chrono::{NaiveDate, Datelike};
+    /// # Example
     ///
-    /// fn ndays_in_year(year: i32) -> u32 {
-    ///     // the first day of the next year...
-    ///     let d = NaiveDate::from_ymd(year + 1, 1, 1);
+    /// ~~~~
+    /// use chrono::{Months, NaiveDate};
     ///
-    ///     // ...is preceded by the last day of the original year
-    ///     d.pred().ordinal()
-    /// }
+    /// let from_ymd = NaiveDate::from_ymd;
     ///
-    /// assert_eq!(ndays_in_year(2015), 365);
-    /// assert_eq!(ndays_in_year(2016), 366);
-    /// assert_eq!(ndays_in_year(2017), 365);
-    /// assert_eq!(ndays_in_year(2000), 366);
-    /// assert_eq!(ndays_in_year(2100), 365);
+    /// assert_eq!(from_ymd(2019, 11, 30).checked_add_months(Months(3)), Some(from_ymd(2020, 2, 29)));
+    /// assert_eq!(from_ymd(2019, 11, 30).checked_add_months(Months(14)), Some(from_ymd(2021, 1, 30)));
+    /// assert_eq!(from_ymd(262143, 12, 1).checked_add_months(Months(1)), None);
     /// ~~~~
-    #[inline]
-    fn ordinal(&self) -> u32 {
-        self.of().ordinal()
+    pub fn checked_add_months(self, months: Months) -> Option<NaiveDate> {
+        self.diff_months(i64::from(months.0))
     }
 
-    /// Returns the day of year starting from 0.
+    /// Subtracts given number of calendar months from the current date,
+    /// clamping the day of month to the last day of the resulting month.
     ///
-    /// The return value ranges from 0 to 365. (The last day of year differs by years.)
+    /// Returns `None` when the resulting date would be out of range.
     ///
     /// # Example
     ///
     /// ~~~~
-    /// use chrono::{NaiveDate, Datelike};
+    /// use chrono::{Months, NaiveDate};
     ///
-    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).ordinal0(), 250);
-    /// assert_eq!(NaiveDate::from_ymd(-308, 3, 14).ordinal0(), 73);
+    /// let from_ymd = NaiveDate::from_ymd;
+    ///
+    /// assert_eq!(from_ymd(2020, 5, 31).checked_sub_months(Months(3)), Some(from_ymd(2020, 2, 29)));
+    /// assert_eq!(from_ymd(2020, 5, 31).checked_sub_months(Months(5)), Some(from_ymd(2019, 12, 31)));
+    /// assert_eq!(from_ymd(-262144, 1, 1).checked_sub_months(Months(1)), None);
     /// ~~~~
-    #[inline]
-    fn ordinal0(&self) -> u32 {
-        self.of().ordinal() - 1
+    pub fn checked_sub_months(self, months: Months) -> Option<NaiveDate> {
+        self.diff_months(-i64::from(months.0))
     }
 
-    /// Returns the day of week.
+    fn diff_months(self, months: i64) -> Option<NaiveDate> {
+        let total = i64::from(self.year()) * 12 + i64::from(self.month0()) + months;
+        let (year, month0) = div_mod_floor(total, 12);
+        let year = try_opt!(year.to_i32());
+        NaiveDate::from_ymd_clamped(year, month0 as u32 + 1, self.day())
+    }
+
+    /// Adds given number of days to the current date.
+    ///
+    /// Returns `None` when the resulting date would be out of range.
     ///
     /// # Example
     ///
     /// ~~~~
-    /// use chrono::{NaiveDate, Datelike, Weekday};
+    /// use chrono::{Days, NaiveDate};
     ///
-    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).weekday(), Weekday::Tue);
-    /// assert_eq!(NaiveDate::from_ymd(-308, 3, 14).weekday(), Weekday::Fri);
+    /// let from_ymd = NaiveDate::from_ymd;
+    ///
+    /// assert_eq!(from_ymd(2019, 12, 25).checked_add_days(Days(10)), Some(from_ymd(2020, 1, 4)));
+    /// assert_eq!(from_ymd(2019, 12, 25).checked_add_days(Days(1 << 40)), None);
     /// ~~~~
-    #[inline]
-    fn weekday(&self) -> Weekday {
-        self.of().weekday()
-    }
-
-    #[inline]
-    fn iso_week(&self) -> IsoWeek {
-        isoweek::iso_week_from_yof(self.year(), self.of())
+    pub fn checked_add_days(self, days: Days) -> Option<NaiveDate> {
+        self.diff_days(try_opt!(days.0.to_i64()))
     }
 
-    /// Makes a new `NaiveDate` with the year number changed.
+    /// Subtracts given number of days from the current date.
     ///
-    /// Returns `None` when the resulting `NaiveDate` would be invalid.
+    /// Returns `None` when the resulting date would be out of range.
     ///
     /// # Example
     ///
     /// ~~~~
-    /// use chrono::{NaiveDate, Datelike};
+    /// use chrono::{Days, NaiveDate};
     ///
-    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).with_year(2016),
-    ///            Some(NaiveDate::from_ymd(2016, 9, 8)));
-    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).with_year(-308),
-    ///            Some(NaiveDate::from_ymd(-308, 9, 8)));
+    /// let from_ymd = NaiveDate::from_ymd;
+    ///
+    /// assert_eq!(from_ymd(2020, 1, 4).checked_sub_days(Days(10)), Some(from_ymd(2019, 12, 25)));
+    /// assert_eq!(from_ymd(2020, 1, 4).checked_sub_days(Days(1 << 40)), None);
     /// ~~~~
+    pub fn checked_sub_days(self, days: Days) -> Option<NaiveDate> {
+        self.diff_days(-try_opt!(days.0.to_i64()))
+    }
+
+    fn diff_days(self, days: i64) -> Option<NaiveDate> {
+        let days =
+            try_opt!(try_opt!(i64::from(self.num_days_from_ce()).checked_add(days)).to_i32());
+        NaiveDate::from_num_days_from_ce_opt(days)
+    }
+
+    /// Returns the calendar years, months and days elapsed from the current date to `at`,
+    /// e.g. the age at `at` of someone born on the current date.
     ///
-    /// A leap day (February 29) is a good example that this method can return `None`.
+    /// Returns `None` when `at` is earlier than the current date.
+    /// See [`Age`](../struct.Age.html) for how the months and days are counted.
+    /// In particular, someone born on February 29 gets one year older on February 28
+    /// in common years.
+    ///
+    /// # Example
     ///
     /// ~~~~
-    /// # use chrono::{NaiveDate, Datelike};
-    /// assert!(NaiveDate::from_ymd(2016, 2, 29).with_year(2015).is_none());
-    /// assert!(NaiveDate::from_ymd(2016, 2, 29).with_year(2020).is_some());
+    /// use chrono::{Age, NaiveDate};
+    ///
+    /// let from_ymd = NaiveDate::from_ymd;
+    ///
+    /// let birth = from_ymd(2000, 2, 29);
+    /// assert_eq!(birth.age_at(from_ymd(2019, 12, 25)),
+    ///            Some(Age { years: 19, months: 9, days: 26 }));
+    /// assert_eq!(birth.age_at(from_ymd(2001, 2, 27)),
+    ///            Some(Age { years: 0, months: 11, days: 29 }));
+    /// assert_eq!(birth.age_at(from_ymd(2001, 2, 28)),
+    ///            Some(Age { years: 1, months: 0, days: 0 }));
+    /// assert_eq!(birth.age_at(from_ymd(1999, 12, 31)), None);
     /// ~~~~
-    #[inline]
-    fn with_year(&self, year: i32) -> Option<NaiveDate> {
-        // we need to operate with `mdf` since we should keep the month and day number as is
-        let mdf = self.mdf();
-
-        // adjust the flags as needed
-        let flags = YearFlags::from_year(year);
-        let mdf = mdf.with_flags(flags);
+    pub fn age_at(self, at: NaiveDate) -> Option<Age> {
+        if at < self {
+            return None;
+        }
 
-        NaiveDate::from_mdf(year, mdf)
+        // never negative as `at` is not earlier than `self`
+        let mut months =
+            ((at.year() - self.year()) * 12 + at.month() as i32 - self.month() as i32) as u32;
+        let mut anniversary = try_opt!(self.checked_add_months(Months(months)));
+        if anniversary > at {
+            months -= 1;
+            anniversary = try_opt!(self.checked_add_months(Months(months)));
+        }
+        Some(Age {
+            years: months / 12,
+            months: months % 12,
+            days: at.signed_duration_since(anniversary).num_days() as u32,
+        })
     }
 
-    /// Makes a new `NaiveDate` with the month number (starting from 1) changed.
+    /// Returns the number of whole years elapsed from `since` to the current date,
+    /// e.g. the age today of someone born on `since`.
     ///
-    /// Returns `None` when the resulting `NaiveDate` would be invalid.
+    /// Returns `None` when `since` is later than the current date.
+    /// This is same to the `years` of [`age_at`](#method.age_at),
+    /// so someone born on February 29 gets one year older on February 28 in common years.
     ///
     /// # Example
     ///
     /// ~~~~
-    /// use chrono::{NaiveDate, Datelike};
+    /// use chrono::NaiveDate;
     ///
-    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).with_month(10),
-    ///            Some(NaiveDate::from_ymd(2015, 10, 8)));
-    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).with_month(13), None); // no month 13
-    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 30).with_month(2), None); // no February 30
+    /// let from_ymd = NaiveDate::from_ymd;
+    ///
+    /// assert_eq!(from_ymd(2019, 12, 24).years_since(from_ymd(1980, 12, 25)), Some(38));
+    /// assert_eq!(from_ymd(2019, 12, 25).years_since(from_ymd(1980, 12, 25)), Some(39));
+    /// assert_eq!(from_ymd(2019, 2, 28).years_since(from_ymd(2016, 2, 29)), Some(3));
+    /// assert_eq!(from_ymd(1980, 12, 24).years_since(from_ymd(1980, 12, 25)), None);
     /// ~~~~
-    #[inline]
-    fn with_month(&self, month: u32) -> Option<NaiveDate> {
-        self.with_mdf(self.mdf().with_month(month))
+    pub fn years_since(self, since: NaiveDate) -> Option<u32> {
+        since.age_at(self).map(|age| age.years)
     }
 
-    /// Makes a new `NaiveDate` with the month number (starting from 0) changed.
+    /// Returns the number of complete calendar months from `other` to the current date,
+    /// which is negative when `other` is later than the current date.
     ///
-    /// Returns `None` when the resulting `NaiveDate` would be invalid.
+    /// The months are counted as in [`age_at`](#method.age_at) from the earlier date,
+    /// so the month from January 31 is complete on the last day of February.
+    /// Unlike dividing the number of days by 30,
+    /// this accounts for the varying lengths of months and years.
     ///
     /// # Example
     ///
     /// ~~~~
-    /// use chrono::{NaiveDate, Datelike};
+    /// use chrono::NaiveDate;
     ///
-    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).with_month0(9),
-    ///            Some(NaiveDate::from_ymd(2015, 10, 8)));
-    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).with_month0(12), None); // no month 13
-    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 30).with_month0(1), None); // no February 30
+    /// let from_ymd = NaiveDate::from_ymd;
+    ///
+    /// assert_eq!(from_ymd(2020, 3, 14).diff_calendar_months(from_ymd(2019, 12, 15)), 2);
+    /// assert_eq!(from_ymd(2020, 3, 15).diff_calendar_months(from_ymd(2019, 12, 15)), 3);
+    /// assert_eq!(from_ymd(2020, 2, 29).diff_calendar_months(from_ymd(2020, 1, 31)), 1);
+    /// assert_eq!(from_ymd(2019, 12, 15).diff_calendar_months(from_ymd(2020, 3, 15)), -3);
     /// ~~~~
-    #[inline]
-    fn with_month0(&self, month0: u32) -> Option<NaiveDate> {
-        self.with_mdf(self.mdf().with_month(month0 + 1))
+    pub fn diff_calendar_months(self, other: NaiveDate) -> i32 {
+        let (from, to, sign) = if other <= self {
+            (other, self, 1)
+        } else {
+            (self, other, -1)
+        };
+        // at most about 12.6 million months between the supported dates
+        let age = from
+            .age_at(to)
+            .expect("NaiveDate::diff_calendar_months: out of range");
+        sign * (age.years * 12 + age.months) as i32
     }
 
-    /// Makes a new `NaiveDate` with the day of month (starting from 1) changed.
+    /// Returns the number of complete calendar years from `other` to the current date,
+    /// which is negative when `other` is later than the current date.
     ///
-    /// Returns `None` when the resulting `NaiveDate` would be invalid.
+    /// This is the [`diff_calendar_months`](#method.diff_calendar_months) divided by 12,
+    /// so `today.diff_calendar_years(birthday)` is the age in years
+    /// same as [`years_since`](#method.years_since),
+    /// and someone born on February 29 gets one year older on February 28 in common years.
     ///
     /// # Example
     ///
     /// ~~~~
-    /// use chrono::{NaiveDate, Datelike};
+    /// use chrono::NaiveDate;
     ///
-    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).with_day(30),
-    ///            Some(NaiveDate::from_ymd(2015, 9, 30)));
-    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).with_day(31),
-    ///            None); // no September 31
+    /// let from_ymd = NaiveDate::from_ymd;
+    ///
+    /// let birthday = from_ymd(1980, 12, 25);
+    /// assert_eq!(from_ymd(2019, 12, 24).diff_calendar_years(birthday), 38);
+    /// assert_eq!(from_ymd(2019, 12, 25).diff_calendar_years(birthday), 39);
+    /// assert_eq!(from_ymd(2019, 2, 28).diff_calendar_years(from_ymd(2016, 2, 29)), 3);
+    /// assert_eq!(birthday.diff_calendar_years(from_ymd(2019, 12, 25)), -39);
     /// ~~~~
-    #[inline]
-    fn with_day(&self, day: u32) -> Option<NaiveDate> {
-        self.with_mdf(self.mdf().with_day(day))
+    pub fn diff_calendar_years(self, other: NaiveDate) -> i32 {
+        self.diff_calendar_months(other) / 12
     }
 
-    /// Makes a new `NaiveDate` with the day of month (starting from 0) changed.
+    /// Subtracts another `NaiveDate` from the current date.
+    /// Returns a `Duration` of integral numbers.
     ///
-    /// Returns `None` when the resulting `NaiveDate` would be invalid.
+    /// This does not overflow or underflow at all,
+    /// as all possible output fits in the range of `Duration`.
     ///
     /// # Example
     ///
     /// ~~~~
-    /// use chrono::{NaiveDate, Datelike};
+    /// # extern crate chrono; extern crate time; fn main() {
+    /// use chrono::NaiveDate;
+    /// use time::Duration;
     ///
-    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).with_day0(29),
-    ///            Some(NaiveDate::from_ymd(2015, 9, 30)));
-    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).with_day0(30),
-    ///            None); // no September 31
+    /// let from_ymd = NaiveDate::from_ymd;
+    /// let since = NaiveDate::signed_duration_since;
+    ///
+    /// assert_eq!(since(from_ymd(2014, 1, 1), from_ymd(2014, 1, 1)), Duration::zero());
+    /// assert_eq!(since(from_ymd(2014, 1, 1), from_ymd(2013, 12, 31)), Duration::days(1));
+    /// assert_eq!(since(from_ymd(2014, 1, 1), from_ymd(2014, 1, 2)), Duration::days(-1));
+    /// assert_eq!(since(from_ymd(2014, 1, 1), from_ymd(2013, 9, 23)), Duration::days(100));
+    /// assert_eq!(since(from_ymd(2014, 1, 1), from_ymd(2013, 1, 1)), Duration::days(365));
+    /// assert_eq!(since(from_ymd(2014, 1, 1), from_ymd(2010, 1, 1)), Duration::days(365*4 + 1));
+    /// assert_eq!(since(from_ymd(2014, 1, 1), from_ymd(1614, 1, 1)), Duration::days(365*400 + 97));
+    /// # }
     /// ~~~~
-    #[inline]
-    fn with_day0(&self, day0: u32) -> Option<NaiveDate> {
-        self.with_mdf(self.mdf().with_day(day0 + 1))
+    pub fn signed_duration_since(self, rhs: NaiveDate) -> OldDuration {
+        let year1 = self.year();
+        let year2 = rhs.year();
+        let (year1_div_400, year1_mod_400) = div_mod_floor(year1, 400);
+        let (year2_div_400, year2_mod_400) = div_mod_floor(year2, 400);
+        let cycle1 = i64::from(internals::yo_to_cycle(
+            year1_mod_400 as u32,
+            self.of().ordinal(),
+        ));
+        let cycle2 = i64::from(internals::yo_to_cycle(
+            year2_mod_400 as u32,
+            rhs.of().ordinal(),
+        ));
+        OldDuration::days(
+            (i64::from(year1_div_400) - i64::from(year2_div_400)) * 146_097 + (cycle1 - cycle2),
+        )
     }
 
-    /// Makes a new `NaiveDate` with the day of year (starting from 1) changed.
+    /// Advances the date by given number of business days (Monday to Friday),
+    /// skipping weekends and any weekday listed in `holidays`.
+    /// The negative `days` moves the date backward.
     ///
-    /// Returns `None` when the resulting `NaiveDate` would be invalid.
+    /// Each business day is counted when it's reached,
+    /// so adding one business day to Friday or Saturday gives the following Monday.
+    /// `self` is returned as is when `days` is zero, even when it's not a business day.
+    ///
+    /// `holidays` should be sorted in the ascending order,
+    /// as it is binary-searched for every visited date.
+    ///
+    /// Panics when the resulting date would be out of range.
     ///
     /// # Example
     ///
     /// ~~~~
-    /// use chrono::{NaiveDate, Datelike};
+    /// use chrono::NaiveDate;
     ///
-    /// assert_eq!(NaiveDate::from_ymd(2015, 1, 1).with_ordinal(60),
-    ///            Some(NaiveDate::from_ymd(2015, 3, 1)));
-    /// assert_eq!(NaiveDate::from_ymd(2015, 1, 1).with_ordinal(366),
-    ///            None); // 2015 had only 365 days
+    /// let from_ymd = NaiveDate::from_ymd;
     ///
-    /// assert_eq!(NaiveDate::from_ymd(2016, 1, 1).with_ordinal(60),
-    ///            Some(NaiveDate::from_ymd(2016, 2, 29)));
-    /// assert_eq!(NaiveDate::from_ymd(2016, 1, 1).with_ordinal(366),
-    ///            Some(NaiveDate::from_ymd(2016, 12, 31)));
+    /// assert_eq!(from_ymd(2019, 12, 6).add_business_days(1, &[]), from_ymd(2019, 12, 9));
+    /// assert_eq!(from_ymd(2019, 12, 9).add_business_days(-1, &[]), from_ymd(2019, 12, 6));
+    ///
+    /// let holidays = [from_ymd(2019, 12, 25), from_ymd(2019, 12, 26), from_ymd(2020, 1, 1)];
+    /// assert_eq!(from_ymd(2019, 12, 23).add_business_days(7, &holidays), from_ymd(2020, 1, 6));
     /// ~~~~
-    #[inline]
-    fn with_ordinal(&self, ordinal: u32) -> Option<NaiveDate> {
-        self.with_of(self.of().with_ordinal(ordinal))
+    pub fn add_business_days(self, days: i32, holidays: &[NaiveDate]) -> NaiveDate {
+        let mut date = self;
+        let mut remaining = days;
+        while remaining != 0 {
+            date = if remaining > 0 {
+                date.succ()
+            } else {
+                date.pred()
+            };
+            let weekend = date.weekday().num_days_from_monday() >= 5;
+            if !weekend && holidays.binary_search(&date).is_err() {
+                remaining -= remaining.signum();
+            }
+        }
+        date
     }
 
-    /// Makes a new `NaiveDate` with the day of year (starting from 0) changed.
+    /// Makes a new `NaiveDate` with the year number changed,
+    /// clamping the day of month to the last valid day of the resulting month.
     ///
-    /// Returns `None` when the resulting `NaiveDate` would be invalid.
+    /// Unlike [`Datelike::with_year`](../trait.Datelike.html#tymethod.with_year)
+    /// this never fails because of a missing leap day;
+    /// returns `None` only when the resulting year is out of range.
     ///
     /// # Example
     ///
     /// ~~~~
-    /// use chrono::{NaiveDate, Datelike};
+    /// use chrono::NaiveDate;
     ///
-    /// assert_eq!(NaiveDate::from_ymd(2015, 1, 1).with_ordinal0(59),
-    ///            Some(NaiveDate::from_ymd(2015, 3, 1)));
-    /// assert_eq!(NaiveDate::from_ymd(2015, 1, 1).with_ordinal0(365),
-    ///            None); // 2015 had only 365 days
+    /// assert_eq!(NaiveDate::from_ymd(2016, 2, 29).with_year_clamped(2015),
+    ///            Some(NaiveDate::from_ymd(2015, 2, 28)));
+    /// assert_eq!(NaiveDate::from_ymd(2016, 2, 29).with_year_clamped(2020),
+    ///            Some(NaiveDate::from_ymd(2020, 2, 29)));
+    /// assert_eq!(NaiveDate::from_ymd(2016, 2, 29).with_year_clamped(1_000_000), None);
+    /// ~~~~
+    pub fn with_year_clamped(&self, year: i32) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_clamped(year, self.month(), self.day())
+    }
+
+    /// Makes a new `NaiveDate` with the month number (starting from 1) changed,
+    /// clamping the day of month to the last valid day of the resulting month.
     ///
-    /// assert_eq!(NaiveDate::from_ymd(2016, 1, 1).with_ordinal0(59),
+    /// Unlike [`Datelike::with_month`](../trait.Datelike.html#tymethod.with_month)
+    /// this never fails because the month is shorter than the current day;
+    /// returns `None` only when `month` is invalid.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::from_ymd(2015, 1, 30).with_month_clamped(2),
+    ///            Some(NaiveDate::from_ymd(2015, 2, 28)));
+    /// assert_eq!(NaiveDate::from_ymd(2016, 1, 30).with_month_clamped(2),
     ///            Some(NaiveDate::from_ymd(2016, 2, 29)));
-    /// assert_eq!(NaiveDate::from_ymd(2016, 1, 1).with_ordinal0(365),
-    ///            Some(NaiveDate::from_ymd(2016, 12, 31)));
+    /// assert_eq!(NaiveDate::from_ymd(2015, 8, 31).with_month_clamped(9),
+    ///            Some(NaiveDate::from_ymd(2015, 9, 30)));
+    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).with_month_clamped(13), None); // no month 13
     /// ~~~~
-    #[inline]
-    fn with_ordinal0(&self, ordinal0: u32) -> Option<NaiveDate> {
-        self.with_of(self.of().with_ordinal(ordinal0 + 1))
+    pub fn with_month_clamped(&self, month: u32) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_clamped(self.year(), month, self.day())
     }
-}
 
-/// An addition of `Duration` to `NaiveDate` discards the fractional days,
-/// rounding to the closest integral number of days towards `Duration::zero()`.
-///
-/// Panics on underflow or overflow.
-/// Use [`NaiveDate::checked_add_signed`](#method.checked_add_signed) to detect that.
-///
-/// # Example
-///
-/// ~~~~
-/// # extern crate chrono; extern crate time; fn main() {
-/// use chrono::NaiveDate;
-/// use time::Duration;
-///
-/// let from_ymd = NaiveDate::from_ymd;
-///
-/// assert_eq!(from_ymd(2014, 1, 1) + Duration::zero(),             from_ymd(2014, 1, 1));
-/// assert_eq!(from_ymd(2014, 1, 1) + Duration::seconds(86399),     from_ymd(2014, 1, 1));
-/// assert_eq!(from_ymd(2014, 1, 1) + Duration::seconds(-86399),    from_ymd(2014, 1, 1));
-/// assert_eq!(from_ymd(2014, 1, 1) + Duration::days(1),            from_ymd(2014, 1, 2));
-/// assert_eq!(from_ymd(2014, 1, 1) + Duration::days(-1),           from_ymd(2013, 12, 31));
-/// assert_eq!(from_ymd(2014, 1, 1) + Duration::days(364),          from_ymd(2014, 12, 31));
-/// assert_eq!(from_ymd(2014, 1, 1) + Duration::days(365*4 + 1),    from_ymd(2018, 1, 1));
-/// assert_eq!(from_ymd(2014, 1, 1) + Duration::days(365*400 + 97), from_ymd(2414, 1, 1));
-/// # }
-/// ~~~~
-impl Add<OldDuration> for NaiveDate {
-    type Output = NaiveDate;
+    /// Makes a new `NaiveDate` from the calendar date,
+    /// clamping `day` to the last day of the month when it's beyond that.
+    fn from_ymd_clamped(year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+        let mut day = day;
+        loop {
+            let date = NaiveDate::from_ymd_opt(year, month, day);
+            // every month has at least 28 days, so no further clamping would help
+            if date.is_some() || day <= 28 {
+                return date;
+            }
+            day -= 1;
+        }
+    }
 
-    #[inline]
-    fn add(self, rhs: OldDuration) -> NaiveDate {
-        self.checked_add_signed(rhs)
-            .expect("`NaiveDate + Duration` overflowed")
+    /// Counts the business days (Monday to Friday) from `self` up to `other`,
+    /// skipping any weekday listed in `holidays`.
+    ///
+    /// `self` is counted but `other` is not, so the result is `0` for the same date.
+    /// When `other` is earlier than `self` the days from `other` up to `self` are counted
+    /// and the result is negated.
+    ///
+    /// `holidays` should be sorted in the ascending order and contain no duplicates,
+    /// as it is binary-searched for the range in question.
+    /// Holidays falling on the weekend are simply ignored.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// let from_ymd = NaiveDate::from_ymd;
+    ///
+    /// // from Monday to the next Monday
+    /// assert_eq!(from_ymd(2019, 12, 2).business_days_until(from_ymd(2019, 12, 9), &[]), 5);
+    /// assert_eq!(from_ymd(2019, 12, 9).business_days_until(from_ymd(2019, 12, 2), &[]), -5);
+    ///
+    /// let holidays = [from_ymd(2019, 12, 25), from_ymd(2019, 12, 26), from_ymd(2020, 1, 1)];
+    /// assert_eq!(from_ymd(2019, 12, 23).business_days_until(from_ymd(2020, 1, 6), &holidays), 7);
+    /// ~~~~
+    pub fn business_days_until(self, other: NaiveDate, holidays: &[NaiveDate]) -> i64 {
+        if other < self {
+            return -other.business_days_until(self, holidays);
+        }
+
+        let days = other.signed_duration_since(self).num_days();
+        let first = i64::from(self.weekday().num_days_from_monday());
+        let mut count = days / 7 * 5;
+        for i in 0..days % 7 {
+            if (first + i) % 7 < 5 {
+                count += 1;
+            }
+        }
+
+        let lo = match holidays.binary_search(&self) {
+            Ok(i) | Err(i) => i,
+        };
+        let hi = match holidays.binary_search(&other) {
+            Ok(i) | Err(i) => i,
+        };
+        if lo < hi {
+            for holiday in &holidays[lo..hi] {
+                if holiday.weekday().num_days_from_monday() < 5 {
+                    count -= 1;
+                }
+            }
+        }
+        count
     }
-}
 
-impl AddAssign<OldDuration> for NaiveDate {
+    /// Returns `true` if the current date is a business day in given holiday calendar.
+    ///
+    /// Unlike [`add_business_days`](#method.add_business_days) weekends are not assumed;
+    /// use [`HolidayCalendar::with_weekends`](../holiday/trait.HolidayCalendar.html#method.with_weekends)
+    /// or [`Weekends`](../holiday/struct.Weekends.html) to exclude them.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    /// use chrono::holiday::{HolidayCalendar, Weekends};
+    ///
+    /// let holidays = [NaiveDate::from_ymd(2019, 12, 25)];
+    /// let cal = (&holidays[..]).with_weekends();
+    /// assert!(NaiveDate::from_ymd(2019, 12, 24).is_business_day(&cal));
+    /// assert!(!NaiveDate::from_ymd(2019, 12, 25).is_business_day(&cal));
+    /// assert!(!NaiveDate::from_ymd(2019, 12, 28).is_business_day(&Weekends::default()));
+    /// ~~~~
     #[inline]
-    fn add_assign(&mut self, rhs: OldDuration) {
-        *self = self.add(rhs);
+    pub fn is_business_day<C: HolidayCalendar + ?Sized>(&self, cal: &C) -> bool {
+        !cal.is_holiday(*self)
     }
-}
 
-/// A subtraction of `Duration` from `NaiveDate` discards the fractional days,
-/// rounding to the closest integral number of days towards `Duration::zero()`.
-/// It is same to the addition with a negated `Duration`.
-///
-/// Panics on underflow or overflow.
-/// Use [`NaiveDate::checked_sub_signed`](#method.checked_sub_signed) to detect that.
-///
+    /// Returns the first business day in given holiday calendar after the current date.
+    ///
+    /// Returns `None` when there is no such date until the end of the representable range.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    /// use chrono::holiday::{HolidayCalendar, Weekends};
+    ///
+    /// let holidays = [NaiveDate::from_ymd(2019, 12, 25), NaiveDate::from_ymd(2019, 12, 26)];
+    /// let cal = (&holidays[..]).with_weekends();
+    /// assert_eq!(NaiveDate::from_ymd(2019, 12, 24).next_business_day(&cal),
+    ///            Some(NaiveDate::from_ymd(2019, 12, 27)));
+    /// assert_eq!(NaiveDate::from_ymd(2019, 12, 27).next_business_day(&Weekends::default()),
+    ///            Some(NaiveDate::from_ymd(2019, 12, 30)));
+    /// ~~~~
+    pub fn next_business_day<C: HolidayCalendar + ?Sized>(&self, cal: &C) -> Option<NaiveDate> {
+        let mut date = *self;
+        loop {
+            date = try_opt!(date.succ_opt());
+            if date.is_business_day(cal) {
+                return Some(date);
+            }
+        }
+    }
+
+    /// Counts the business days in given holiday calendar from `self` up to `other`.
+    ///
+    /// As in [`business_days_until`](#method.business_days_until),
+    /// `self` is counted but `other` is not, and the result is negated
+    /// when `other` is earlier than `self`.
+    /// Every date in between is looked up in the calendar.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    /// use chrono::holiday::HolidayCalendar;
+    ///
+    /// let from_ymd = NaiveDate::from_ymd;
+    /// let holidays = [from_ymd(2019, 12, 25), from_ymd(2019, 12, 26), from_ymd(2020, 1, 1)];
+    /// let cal = (&holidays[..]).with_weekends();
+    /// assert_eq!(from_ymd(2019, 12, 23).business_days_between(&cal, from_ymd(2020, 1, 6)), 7);
+    /// assert_eq!(from_ymd(2020, 1, 6).business_days_between(&cal, from_ymd(2019, 12, 23)), -7);
+    /// ~~~~
+    pub fn business_days_between<C: HolidayCalendar + ?Sized>(
+        &self,
+        cal: &C,
+        other: NaiveDate,
+    ) -> i64 {
+        if other < *self {
+            return -other.business_days_between(cal, *self);
+        }
+
+        let mut count = 0;
+        let mut date = *self;
+        while date < other {
+            if date.is_business_day(cal) {
+                count += 1;
+            }
+            date = date.succ();
+        }
+        count
+    }
+
+    /// Formats the date with the specified formatting items.
+    /// Otherwise it is same to the ordinary `format` method.
+    ///
+    /// The `Iterator` of items should be `Clone`able,
+    /// since the resulting `DelayedFormat` value may be formatted multiple times.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    /// use chrono::format::strftime::StrftimeItems;
+    ///
+    /// let fmt = StrftimeItems::new("%Y-%m-%d");
+    /// let d = NaiveDate::from_ymd(2015, 9, 5);
+    /// assert_eq!(d.format_with_items(fmt.clone()).to_string(), "2015-09-05");
+    /// assert_eq!(d.format("%Y-%m-%d").to_string(),             "2015-09-05");
+    /// ~~~~
+    ///
+    /// The resulting `DelayedFormat` can be formatted directly via the `Display` trait.
+    ///
+    /// ~~~~
+    /// # use chrono::NaiveDate;
+    /// # use chrono::format::strftime::StrftimeItems;
+    /// # let fmt = StrftimeItems::new("%Y-%m-%d").clone();
+    /// # let d = NaiveDate::from_ymd(2015, 9, 5);
+    /// assert_eq!(format!("{}", d.format_with_items(fmt)), "2015-09-05");
+    /// ~~~~
+    #[inline]
+    pub fn format_with_items<'a, I>(&self, items: I) -> DelayedFormat<I>
+    where
+        I: Iterator<Item = Item<'a>> + Clone,
+    {
+        DelayedFormat::new(Some(*self), None, items)
+    }
+
+    /// Formats the date with the specified format string.
+    /// See the [`format::strftime` module](../format/strftime/index.html)
+    /// on the supported escape sequences.
+    ///
+    /// This returns a `DelayedFormat`,
+    /// which gets converted to a string only when actual formatting happens.
+    /// You may use the `to_string` method to get a `String`,
+    /// or just feed it into `print!` and other formatting macros.
+    /// (In this way it avoids the redundant memory allocation.)
+    ///
+    /// A wrong format string does *not* issue an error immediately.
+    /// Rather, converting or formatting the `DelayedFormat` fails.
+    /// You are recommended to immediately use `DelayedFormat` for this reason.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// let d = NaiveDate::from_ymd(2015, 9, 5);
+    /// assert_eq!(d.format("%Y-%m-%d").to_string(), "2015-09-05");
+    /// assert_eq!(d.format("%A, %-d %B, %C%y").to_string(), "Saturday, 5 September, 2015");
+    /// ~~~~
+    ///
+    /// The resulting `DelayedFormat` can be formatted directly via the `Display` trait.
+    ///
+    /// ~~~~
+    /// # use chrono::NaiveDate;
+    /// # let d = NaiveDate::from_ymd(2015, 9, 5);
+    /// assert_eq!(format!("{}", d.format("%Y-%m-%d")), "2015-09-05");
+    /// assert_eq!(format!("{}", d.format("%A, %-d %B, %C%y")), "Saturday, 5 September, 2015");
+    /// ~~~~
+    #[inline]
+    pub fn format<'a>(&self, fmt: &'a str) -> DelayedFormat<StrftimeItems<'a>> {
+        self.format_with_items(StrftimeItems::new(fmt))
+    }
+}
+
+/// A week containing a date, starting from a given day of week.
+///
+/// This can be made by [`NaiveDate::week`](./struct.NaiveDate.html#method.week).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NaiveWeek {
+    date: NaiveDate,
+    start: Weekday,
+}
+
+impl NaiveWeek {
+    /// Returns the first day of the week.
+    ///
+    /// Panics when the first day of the week is out of range.
+    #[inline]
+    pub fn first_day(&self) -> NaiveDate {
+        let start = self.start.num_days_from_monday();
+        let weekday = self.date.weekday().num_days_from_monday();
+        let days = (weekday + 7 - start) % 7;
+        self.date
+            .checked_sub_days(Days(u64::from(days)))
+            .expect("first day of week out of range")
+    }
+
+    /// Returns the last day of the week.
+    ///
+    /// Panics when the first or last day of the week is out of range.
+    #[inline]
+    pub fn last_day(&self) -> NaiveDate {
+        self.first_day()
+            .checked_add_days(Days(6))
+            .expect("last day of week out of range")
+    }
+}
+
+/// An iterator over consecutive dates,
+/// made by [`NaiveDate::iter_days_until`](./struct.NaiveDate.html#method.iter_days_until).
+#[derive(Debug, Clone)]
+pub struct NaiveDateDaysIterator {
+    // the first and last dates remaining, inclusive; `None` when exhausted
+    bounds: Option<(NaiveDate, NaiveDate)>,
+}
+
+impl Iterator for NaiveDateDaysIterator {
+    type Item = NaiveDate;
+
+    fn next(&mut self) -> Option<NaiveDate> {
+        let (first, last) = try_opt!(self.bounds);
+        // `first` is earlier than `last` when they differ, so `succ` can't overflow
+        self.bounds = if first < last {
+            Some((first.succ(), last))
+        } else {
+            None
+        };
+        Some(first)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = match self.bounds {
+            Some((first, last)) => last.signed_duration_since(first).num_days() as usize + 1,
+            None => 0,
+        };
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for NaiveDateDaysIterator {
+    fn next_back(&mut self) -> Option<NaiveDate> {
+        let (first, last) = try_opt!(self.bounds);
+        // `last` is later than `first` when they differ, so `pred` can't underflow
+        self.bounds = if first < last {
+            Some((first, last.pred()))
+        } else {
+            None
+        };
+        Some(last)
+    }
+}
+
+impl ExactSizeIterator for NaiveDateDaysIterator {}
+
+/// An iterator over dates at a fixed interval of whole days,
+/// made by [`NaiveDate::range`](./struct.NaiveDate.html#method.range)
+/// and [`NaiveDate::range_step`](./struct.NaiveDate.html#method.range_step).
+///
+/// It knows its exact length and can be reversed to go from the last date back.
+#[derive(Debug, Clone)]
+pub struct NaiveDateStepRange {
+    // the next date to be returned from the front; the range is empty when it reached `end`
+    start: NaiveDate,
+    // exclusive, moved to the last date returned from the back
+    end: NaiveDate,
+    // the non-zero number of days in a step
+    step: i64,
+}
+
+impl NaiveDateStepRange {
+    fn remaining(&self) -> i64 {
+        let days = self.end.signed_duration_since(self.start).num_days();
+        if days == 0 || (days > 0) != (self.step > 0) {
+            0
+        } else {
+            // the number of steps rounded up, excluding `end`
+            (days.abs() - 1) / self.step.abs() + 1
+        }
+    }
+
+    fn nth_date(&self, n: i64) -> NaiveDate {
+        // only called for the remaining dates which are between `start` and `end`
+        self.start + OldDuration::days(self.step * n)
+    }
+}
+
+impl Iterator for NaiveDateStepRange {
+    type Item = NaiveDate;
+
+    fn next(&mut self) -> Option<NaiveDate> {
+        if self.remaining() == 0 {
+            return None;
+        }
+        let date = self.start;
+        // the next step can go out of the supported dates only after the last date
+        self.start = date
+            .checked_add_signed(OldDuration::days(self.step))
+            .unwrap_or(self.end);
+        Some(date)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<NaiveDate> {
+        if n as u64 >= self.remaining() as u64 {
+            self.start = self.end;
+            return None;
+        }
+        self.start = self.nth_date(n as i64);
+        self.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // there are less than `u32::MAX` supported dates
+        let len = self.remaining() as usize;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for NaiveDateStepRange {
+    fn next_back(&mut self) -> Option<NaiveDate> {
+        let len = self.remaining();
+        if len == 0 {
+            return None;
+        }
+        self.end = self.nth_date(len - 1);
+        Some(self.end)
+    }
+}
+
+impl ExactSizeIterator for NaiveDateStepRange {}
+
+/// An error from [`NaiveDate::try_from_ymd`](./struct.NaiveDate.html#method.try_from_ymd)
+/// when the given date is out of range or does not exist.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutOfRangeError(());
+
+impl fmt::Display for OutOfRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        OUT_OF_RANGE_MESSAGE.fmt(f)
+    }
+}
+
+impl Error for OutOfRangeError {
+    fn description(&self) -> &str {
+        OUT_OF_RANGE_MESSAGE
+    }
+}
+
+const OUT_OF_RANGE_MESSAGE: &'static str = "date is out of range or does not exist";
+
+impl Datelike for NaiveDate {
+    /// Returns the year number in the [calendar date](#calendar-date).
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDate, Datelike};
+    ///
+    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).year(), 2015);
+    /// assert_eq!(NaiveDate::from_ymd(-308, 3, 14).year(), -308); // 309 BCE
+    /// ~~~~
+    #[inline]
+    fn year(&self) -> i32 {
+        self.ymdf >> 13
+    }
+
+    /// Returns the month number starting from 1.
+    ///
+    /// The return value ranges from 1 to 12.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDate, Datelike};
+    ///
+    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).month(), 9);
+    /// assert_eq!(NaiveDate::from_ymd(-308, 3, 14).month(), 3);
+    /// ~~~~
+    #[inline]
+    fn month(&self) -> u32 {
+        self.mdf().month()
+    }
+
+    /// Returns the month number starting from 0.
+    ///
+    /// The return value ranges from 0 to 11.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDate, Datelike};
+    ///
+    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).month0(), 8);
+    /// assert_eq!(NaiveDate::from_ymd(-308, 3, 14).month0(), 2);
+    /// ~~~~
+    #[inline]
+    fn month0(&self) -> u32 {
+        self.mdf().month() - 1
+    }
+
+    /// Returns the day of month starting from 1.
+    ///
+    /// The return value ranges from 1 to 31. (The last day of month differs by months.)
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDate, Datelike};
+    ///
+    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).day(), 8);
+    /// assert_eq!(NaiveDate::from_ymd(-308, 3, 14).day(), 14);
+    /// ~~~~
+    ///
+    /// Combined with [`NaiveDate::pred`](#method.pred),
+    /// one can determine the number of days in a particular month.
+    /// (Note that this panics when `year` is out of range.)
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDate, Datelike};
+    ///
+    /// fn ndays_in_month(year: i32, month: u32) -> u32 {
+    ///     // the first day of the next month...
+    ///     let (y, m) = if month == 12 { (year + 1, 1) } else { (year, month + 1) };
+    ///     let d = NaiveDate::from_ymd(y, m, 1);
+    ///
+    ///     // ...is preceded by the last day of the original month
+    ///     d.pred().day()
+    /// }
+    ///
+    /// assert_eq!(ndays_in_month(2015, 8), 31);
+    /// assert_eq!(ndays_in_month(2015, 9), 30);
+    /// assert_eq!(ndays_in_month(2015, 12), 31);
+    /// assert_eq!(ndays_in_month(2016, 2), 29);
+    /// assert_eq!(ndays_in_month(2017, 2), 28);
+    /// ~~~~
+    #[inline]
+    fn day(&self) -> u32 {
+        self.mdf().day()
+    }
+
+    /// Returns the day of month starting from 0.
+    ///
+    /// The return value ranges from 0 to 30. (The last day of month differs by months.)
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDate, Datelike};
+    ///
+    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).day0(), 7);
+    /// assert_eq!(NaiveDate::from_ymd(-308, 3, 14).day0(), 13);
+    /// ~~~~
+    #[inline]
+    fn day0(&self) -> u32 {
+        self.mdf().day() - 1
+    }
+
+    /// Returns the day of year starting from 1.
+    ///
+    /// The return value ranges from 1 to 366. (The last day of year differs by years.)
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDate, Datelike};
+    ///
+    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).ordinal(), 251);
+    /// assert_eq!(NaiveDate::from_ymd(-308, 3, 14).ordinal(), 74);
+    /// ~~~~
+    ///
+    /// Combined with [`NaiveDate::pred`](#method.pred),
+    /// one can determine the number of days in a particular year.
+    /// (Note that this panics when `year` is out of range.)
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDate, Datelike};
+    ///
+    /// fn ndays_in_year(year: i32) -> u32 {
+    ///     // the first day of the next year...
+    ///     let d = NaiveDate::from_ymd(year + 1, 1, 1);
+    ///
+    ///     // ...is preceded by the last day of the original year
+    ///     d.pred().ordinal()
+    /// }
+    ///
+    /// assert_eq!(ndays_in_year(2015), 365);
+    /// assert_eq!(ndays_in_year(2016), 366);
+    /// assert_eq!(ndays_in_year(2017), 365);
+    /// assert_eq!(ndays_in_year(2000), 366);
+    /// assert_eq!(ndays_in_year(2100), 365);
+    /// ~~~~
+    #[inline]
+    fn ordinal(&self) -> u32 {
+        self.of().ordinal()
+    }
+
+    /// Returns the day of year starting from 0.
+    ///
+    /// The return value ranges from 0 to 365. (The last day of year differs by years.)
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDate, Datelike};
+    ///
+    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).ordinal0(), 250);
+    /// assert_eq!(NaiveDate::from_ymd(-308, 3, 14).ordinal0(), 73);
+    /// ~~~~
+    #[inline]
+    fn ordinal0(&self) -> u32 {
+        self.of().ordinal() - 1
+    }
+
+    /// Returns the day of week.
+    ///
+    /// This is computed in constant time from the internal day of year and year flags.
+    /// See also [`naive::weekday_from_days`](./fn.weekday_from_days.html)
+    /// for computing it from the number of days directly.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDate, Datelike, Weekday};
+    ///
+    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).weekday(), Weekday::Tue);
+    /// assert_eq!(NaiveDate::from_ymd(-308, 3, 14).weekday(), Weekday::Fri);
+    /// ~~~~
+    #[inline]
+    fn weekday(&self) -> Weekday {
+        self.of().weekday()
+    }
+
+    #[inline]
+    fn iso_week(&self) -> IsoWeek {
+        isoweek::iso_week_from_yof(self.year(), self.of())
+    }
+
+    /// Makes a new `NaiveDate` with the year number changed.
+    ///
+    /// Returns `None` when the resulting `NaiveDate` would be invalid.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDate, Datelike};
+    ///
+    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).with_year(2016),
+    ///            Some(NaiveDate::from_ymd(2016, 9, 8)));
+    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).with_year(-308),
+    ///            Some(NaiveDate::from_ymd(-308, 9, 8)));
+    /// ~~~~
+    ///
+    /// A leap day (February 29) is a good example that this method can return `None`.
+    ///
+    /// ~~~~
+    /// # use chrono::{NaiveDate, Datelike};
+    /// assert!(NaiveDate::from_ymd(2016, 2, 29).with_year(2015).is_none());
+    /// assert!(NaiveDate::from_ymd(2016, 2, 29).with_year(2020).is_some());
+    /// ~~~~
+    #[inline]
+    fn with_year(&self, year: i32) -> Option<NaiveDate> {
+        // we need to operate with `mdf` since we should keep the month and day number as is
+        let mdf = self.mdf();
+
+        // adjust the flags as needed
+        let flags = YearFlags::from_year(year);
+        let mdf = mdf.with_flags(flags);
+
+        NaiveDate::from_mdf(year, mdf)
+    }
+
+    /// Makes a new `NaiveDate` with the month number (starting from 1) changed.
+    ///
+    /// Returns `None` when the resulting `NaiveDate` would be invalid.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDate, Datelike};
+    ///
+    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).with_month(10),
+    ///            Some(NaiveDate::from_ymd(2015, 10, 8)));
+    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).with_month(13), None); // no month 13
+    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 30).with_month(2), None); // no February 30
+    /// ~~~~
+    #[inline]
+    fn with_month(&self, month: u32) -> Option<NaiveDate> {
+        self.with_mdf(self.mdf().with_month(month))
+    }
+
+    /// Makes a new `NaiveDate` with the month number (starting from 0) changed.
+    ///
+    /// Returns `None` when the resulting `NaiveDate` would be invalid.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDate, Datelike};
+    ///
+    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).with_month0(9),
+    ///            Some(NaiveDate::from_ymd(2015, 10, 8)));
+    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).with_month0(12), None); // no month 13
+    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 30).with_month0(1), None); // no February 30
+    /// ~~~~
+    #[inline]
+    fn with_month0(&self, month0: u32) -> Option<NaiveDate> {
+        self.with_mdf(self.mdf().with_month(month0 + 1))
+    }
+
+    /// Makes a new `NaiveDate` with the day of month (starting from 1) changed.
+    ///
+    /// Returns `None` when the resulting `NaiveDate` would be invalid.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDate, Datelike};
+    ///
+    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).with_day(30),
+    ///            Some(NaiveDate::from_ymd(2015, 9, 30)));
+    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).with_day(31),
+    ///            None); // no September 31
+    /// ~~~~
+    #[inline]
+    fn with_day(&self, day: u32) -> Option<NaiveDate> {
+        self.with_mdf(self.mdf().with_day(day))
+    }
+
+    /// Makes a new `NaiveDate` with the day of month (starting from 0) changed.
+    ///
+    /// Returns `None` when the resulting `NaiveDate` would be invalid.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDate, Datelike};
+    ///
+    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).with_day0(29),
+    ///            Some(NaiveDate::from_ymd(2015, 9, 30)));
+    /// assert_eq!(NaiveDate::from_ymd(2015, 9, 8).with_day0(30),
+    ///            None); // no September 31
+    /// ~~~~
+    #[inline]
+    fn with_day0(&self, day0: u32) -> Option<NaiveDate> {
+        self.with_mdf(self.mdf().with_day(day0 + 1))
+    }
+
+    /// Makes a new `NaiveDate` with the day of year (starting from 1) changed.
+    ///
+    /// Returns `None` when the resulting `NaiveDate` would be invalid.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDate, Datelike};
+    ///
+    /// assert_eq!(NaiveDate::from_ymd(2015, 1, 1).with_ordinal(60),
+    ///            Some(NaiveDate::from_ymd(2015, 3, 1)));
+    /// assert_eq!(NaiveDate::from_ymd(2015, 1, 1).with_ordinal(366),
+    ///            None); // 2015 had only 365 days
+    ///
+    /// assert_eq!(NaiveDate::from_ymd(2016, 1, 1).with_ordinal(60),
+    ///            Some(NaiveDate::from_ymd(2016, 2, 29)));
+    /// assert_eq!(NaiveDate::from_ymd(2016, 1, 1).with_ordinal(366),
+    ///            Some(NaiveDate::from_ymd(2016, 12, 31)));
+    /// ~~~~
+    #[inline]
+    fn with_ordinal(&self, ordinal: u32) -> Option<NaiveDate> {
+        self.with_of(self.of().with_ordinal(ordinal))
+    }
+
+    /// Makes a new `NaiveDate` with the day of year (starting from 0) changed.
+    ///
+    /// Returns `None` when the resulting `NaiveDate` would be invalid.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDate, Datelike};
+    ///
+    /// assert_eq!(NaiveDate::from_ymd(2015, 1, 1).with_ordinal0(59),
+    ///            Some(NaiveDate::from_ymd(2015, 3, 1)));
+    /// assert_eq!(NaiveDate::from_ymd(2015, 1, 1).with_ordinal0(365),
+    ///            None); // 2015 had only 365 days
+    ///
+    /// assert_eq!(NaiveDate::from_ymd(2016, 1, 1).with_ordinal0(59),
+    ///            Some(NaiveDate::from_ymd(2016, 2, 29)));
+    /// assert_eq!(NaiveDate::from_ymd(2016, 1, 1).with_ordinal0(365),
+    ///            Some(NaiveDate::from_ymd(2016, 12, 31)));
+    /// ~~~~
+    #[inline]
+    fn with_ordinal0(&self, ordinal0: u32) -> Option<NaiveDate> {
+        self.with_of(self.of().with_ordinal(ordinal0 + 1))
+    }
+}
+
+/// An addition of `Duration` to `NaiveDate` discards the fractional days,
+/// rounding to the closest integral number of days towards `Duration::zero()`.
+///
+/// Panics on underflow or overflow.
+/// Use [`NaiveDate::checked_add_signed`](#method.checked_add_signed) to detect that.
+///
+/// # Example
+///
+/// ~~~~
+/// # extern crate chrono; extern crate time; fn main() {
+/// use chrono::NaiveDate;
+/// use time::Duration;
+///
+/// let from_ymd = NaiveDate::from_ymd;
+///
+/// assert_eq!(from_ymd(2014, 1, 1) + Duration::zero(),             from_ymd(2014, 1, 1));
+/// assert_eq!(from_ymd(2014, 1, 1) + Duration::seconds(86399),     from_ymd(2014, 1, 1));
+/// assert_eq!(from_ymd(2014, 1, 1) + Duration::seconds(-86399),    from_ymd(2014, 1, 1));
+/// assert_eq!(from_ymd(2014, 1, 1) + Duration::days(1),            from_ymd(2014, 1, 2));
+/// assert_eq!(from_ymd(2014, 1, 1) + Duration::days(-1),           from_ymd(2013, 12, 31));
+/// assert_eq!(from_ymd(2014, 1, 1) + Duration::days(364),          from_ymd(2014, 12, 31));
+/// assert_eq!(from_ymd(2014, 1, 1) + Duration::days(365*4 + 1),    from_ymd(2018, 1, 1));
+/// assert_eq!(from_ymd(2014, 1, 1) + Duration::days(365*400 + 97), from_ymd(2414, 1, 1));
+/// # }
+/// ~~~~
+impl Add<OldDuration> for NaiveDate {
+    type Output = NaiveDate;
+
+    #[inline]
+    fn add(self, rhs: OldDuration) -> NaiveDate {
+        self.checked_add_signed(rhs)
+            .expect("`NaiveDate + Duration` overflowed")
+    }
+}
+
+impl AddAssign<OldDuration> for NaiveDate {
+    #[inline]
+    fn add_assign(&mut self, rhs: OldDuration) {
+        *self = self.add(rhs);
+    }
+}
+
+/// An addition of `Months` to `NaiveDate` moves the date by calendar months,
+/// clamping the day of month to the last day of the resulting month.
+///
+/// Panics when the resulting date would be out of range.
+/// Use [`NaiveDate::checked_add_months`](#method.checked_add_months) to detect that.
+impl Add<Months> for NaiveDate {
+    type Output = NaiveDate;
+
+    #[inline]
+    fn add(self, rhs: Months) -> NaiveDate {
+        self.checked_add_months(rhs)
+            .expect("`NaiveDate + Months` out of range")
+    }
+}
+
+/// A subtraction of `Months` from `NaiveDate` moves the date by calendar months,
+/// clamping the day of month to the last day of the resulting month.
+///
+/// Panics when the resulting date would be out of range.
+/// Use [`NaiveDate::checked_sub_months`](#method.checked_sub_months) to detect that.
+impl Sub<Months> for NaiveDate {
+    type Output = NaiveDate;
+
+    #[inline]
+    fn sub(self, rhs: Months) -> NaiveDate {
+        self.checked_sub_months(rhs)
+            .expect("`NaiveDate - Months` out of range")
+    }
+}
+
+/// An addition of `Days` to `NaiveDate`.
+///
+/// Panics when the resulting date would be out of range.
+/// Use [`NaiveDate::checked_add_days`](#method.checked_add_days) to detect that.
+impl Add<Days> for NaiveDate {
+    type Output = NaiveDate;
+
+    #[inline]
+    fn add(self, rhs: Days) -> NaiveDate {
+        self.checked_add_days(rhs)
+            .expect("`NaiveDate + Days` out of range")
+    }
+}
+
+/// A subtraction of `Days` from `NaiveDate`.
+///
+/// Panics when the resulting date would be out of range.
+/// Use [`NaiveDate::checked_sub_days`](#method.checked_sub_days) to detect that.
+impl Sub<Days> for NaiveDate {
+    type Output = NaiveDate;
+
+    #[inline]
+    fn sub(self, rhs: Days) -> NaiveDate {
+        self.checked_sub_days(rhs)
+            .expect("`NaiveDate - Days` out of range")
+    }
+}
+
+/// A subtraction of `Duration` from `NaiveDate` discards the fractional days,
+/// rounding to the closest integral number of days towards `Duration::zero()`.
+/// It is same to the addition with a negated `Duration`.
+///
+/// Panics on underflow or overflow.
+/// Use [`NaiveDate::checked_sub_signed`](#method.checked_sub_signed) to detect that.
+///
 /// # Example
 ///
 /// ~~~~
@@ -1658,427 +3039,1120 @@ where
     assert!(from_str(r#"null"#).is_err());
 }
 
-#[cfg(feature = "rustc-serialize")]
-mod rustc_serialize {
-    use super::NaiveDate;
-    use rustc_serialize::{Decodable, Decoder, Encodable, Encoder};
+#[cfg(feature = "rustc-serialize")]
+mod rustc_serialize {
+    use super::NaiveDate;
+    use rustc_serialize::{Decodable, Decoder, Encodable, Encoder};
+
+    impl Encodable for NaiveDate {
+        fn encode<S: Encoder>(&self, s: &mut S) -> Result<(), S::Error> {
+            format!("{:?}", self).encode(s)
+        }
+    }
+
+    impl Decodable for NaiveDate {
+        fn decode<D: Decoder>(d: &mut D) -> Result<NaiveDate, D::Error> {
+            d.read_str()?.parse().map_err(|_| d.error("invalid date"))
+        }
+    }
+
+    #[cfg(test)]
+    use rustc_serialize::json;
+
+    #[test]
+    fn test_encodable() {
+        super::test_encodable_json(json::encode);
+    }
+
+    #[test]
+    fn test_decodable() {
+        super::test_decodable_json(json::decode);
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use super::NaiveDate;
+    use serdelib::{de, ser};
+    use std::fmt;
+
+    // TODO not very optimized for space (binary formats would want something better)
+
+    impl ser::Serialize for NaiveDate {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            struct FormatWrapped<'a, D: 'a> {
+                inner: &'a D,
+            }
+
+            impl<'a, D: fmt::Debug> fmt::Display for FormatWrapped<'a, D> {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    self.inner.fmt(f)
+                }
+            }
+
+            serializer.collect_str(&FormatWrapped { inner: &self })
+        }
+    }
+
+    struct NaiveDateVisitor;
+
+    impl<'de> de::Visitor<'de> for NaiveDateVisitor {
+        type Value = NaiveDate;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "a formatted date string")
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<NaiveDate, E>
+        where
+            E: de::Error,
+        {
+            value.parse().map_err(|err| E::custom(format!("{}", err)))
+        }
+    }
+
+    impl<'de> de::Deserialize<'de> for NaiveDate {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            deserializer.deserialize_str(NaiveDateVisitor)
+        }
+    }
+
+    #[cfg(test)]
+    extern crate bincode;
+    #[cfg(test)]
+    extern crate serde_json;
+
+    #[test]
+    fn test_serde_serialize() {
+        super::test_encodable_json(self::serde_json::to_string);
+    }
+
+    #[test]
+    fn test_serde_deserialize() {
+        super::test_decodable_json(|input| self::serde_json::from_str(&input));
+    }
+
+    #[test]
+    fn test_serde_bincode() {
+        // Bincode is relevant to test separately from JSON because
+        // it is not self-describing.
+        use self::bincode::{deserialize, serialize};
+
+        let d = NaiveDate::from_ymd(2014, 7, 24);
+        let encoded = serialize(&d).unwrap();
+        let decoded: NaiveDate = deserialize(&encoded).unwrap();
+        assert_eq!(d, decoded);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{days_in_month, days_in_year, weekday_from_days, NaiveDate};
+    use super::{MAX_DATE, MAX_DAYS_FROM_YEAR_0, MAX_YEAR};
+    use super::{MIN_DATE, MIN_DAYS_FROM_YEAR_0, MIN_YEAR};
+    use oldtime::Duration;
+    use std::{i32, i64, u32};
+    use {Age, Datelike, Days, Months, Weekday};
+
+    #[test]
+    fn test_date_from_ymd() {
+        let ymd_opt = |y, m, d| NaiveDate::from_ymd_opt(y, m, d);
+
+        assert!(ymd_opt(2012, 0, 1).is_none());
+        assert!(ymd_opt(2012, 1, 1).is_some());
+        assert!(ymd_opt(2012, 2, 29).is_some());
+        assert!(ymd_opt(2014, 2, 29).is_none());
+        assert!(ymd_opt(2014, 3, 0).is_none());
+        assert!(ymd_opt(2014, 3, 1).is_some());
+        assert!(ymd_opt(2014, 3, 31).is_some());
+        assert!(ymd_opt(2014, 3, 32).is_none());
+        assert!(ymd_opt(2014, 12, 31).is_some());
+        assert!(ymd_opt(2014, 13, 1).is_none());
+    }
+
+    #[test]
+    fn test_date_try_from_ymd() {
+        for &(y, m, d) in &[(2012, 2, 29), (-4, 2, 29), (1, 1, 1), (2015, 12, 31)] {
+            assert_eq!(
+                NaiveDate::try_from_ymd(y, m, d).ok(),
+                NaiveDate::from_ymd_opt(y, m, d)
+            );
+        }
+        let err = NaiveDate::try_from_ymd(2014, 2, 29).unwrap_err();
+        assert_eq!(err.to_string(), "date is out of range or does not exist");
+        assert!(NaiveDate::try_from_ymd(2014, 13, 1).is_err());
+        assert!(NaiveDate::try_from_ymd(2014, 4, 0).is_err());
+        assert!(NaiveDate::try_from_ymd(MAX_YEAR + 1, 1, 1).is_err());
+        assert_eq!(NaiveDate::try_from_ymd(MIN_YEAR, 1, 1), Ok(MIN_DATE));
+    }
+
+    #[test]
+    fn test_date_from_ymd_unchecked() {
+        let mut count = 0;
+        for year in &[MIN_YEAR, -401, -1, 0, 1, 1900, 2000, 2019, 2020, MAX_YEAR] {
+            for month in 1..13 {
+                for day in 1..32 {
+                    if let Some(d) = NaiveDate::from_ymd_opt(*year, month, day) {
+                        assert_eq!(
+                            unsafe { NaiveDate::from_ymd_unchecked(*year, month, day) },
+                            d
+                        );
+                        count += 1;
+                    }
+                }
+            }
+        }
+        assert_eq!(count, 365 * 6 + 366 * 4);
+    }
+
+    #[test]
+    fn test_date_from_yo() {
+        let yo_opt = |y, o| NaiveDate::from_yo_opt(y, o);
+        let ymd = |y, m, d| NaiveDate::from_ymd(y, m, d);
+
+        assert_eq!(yo_opt(2012, 0), None);
+        assert_eq!(yo_opt(2012, 1), Some(ymd(2012, 1, 1)));
+        assert_eq!(yo_opt(2012, 2), Some(ymd(2012, 1, 2)));
+        assert_eq!(yo_opt(2012, 32), Some(ymd(2012, 2, 1)));
+        assert_eq!(yo_opt(2012, 60), Some(ymd(2012, 2, 29)));
+        assert_eq!(yo_opt(2012, 61), Some(ymd(2012, 3, 1)));
+        assert_eq!(yo_opt(2012, 100), Some(ymd(2012, 4, 9)));
+        assert_eq!(yo_opt(2012, 200), Some(ymd(2012, 7, 18)));
+        assert_eq!(yo_opt(2012, 300), Some(ymd(2012, 10, 26)));
+        assert_eq!(yo_opt(2012, 366), Some(ymd(2012, 12, 31)));
+        assert_eq!(yo_opt(2012, 367), None);
+
+        assert_eq!(yo_opt(2014, 0), None);
+        assert_eq!(yo_opt(2014, 1), Some(ymd(2014, 1, 1)));
+        assert_eq!(yo_opt(2014, 2), Some(ymd(2014, 1, 2)));
+        assert_eq!(yo_opt(2014, 32), Some(ymd(2014, 2, 1)));
+        assert_eq!(yo_opt(2014, 59), Some(ymd(2014, 2, 28)));
+        assert_eq!(yo_opt(2014, 60), Some(ymd(2014, 3, 1)));
+        assert_eq!(yo_opt(2014, 100), Some(ymd(2014, 4, 10)));
+        assert_eq!(yo_opt(2014, 200), Some(ymd(2014, 7, 19)));
+        assert_eq!(yo_opt(2014, 300), Some(ymd(2014, 10, 27)));
+        assert_eq!(yo_opt(2014, 365), Some(ymd(2014, 12, 31)));
+        assert_eq!(yo_opt(2014, 366), None);
+    }
+
+    #[test]
+    fn test_date_from_isoywd() {
+        let isoywd_opt = |y, w, d| NaiveDate::from_isoywd_opt(y, w, d);
+        let ymd = |y, m, d| NaiveDate::from_ymd(y, m, d);
+
+        assert_eq!(isoywd_opt(2004, 0, Weekday::Sun), None);
+        assert_eq!(isoywd_opt(2004, 1, Weekday::Mon), Some(ymd(2003, 12, 29)));
+        assert_eq!(isoywd_opt(2004, 1, Weekday::Sun), Some(ymd(2004, 1, 4)));
+        assert_eq!(isoywd_opt(2004, 2, Weekday::Mon), Some(ymd(2004, 1, 5)));
+        assert_eq!(isoywd_opt(2004, 2, Weekday::Sun), Some(ymd(2004, 1, 11)));
+        assert_eq!(isoywd_opt(2004, 52, Weekday::Mon), Some(ymd(2004, 12, 20)));
+        assert_eq!(isoywd_opt(2004, 52, Weekday::Sun), Some(ymd(2004, 12, 26)));
+        assert_eq!(isoywd_opt(2004, 53, Weekday::Mon), Some(ymd(2004, 12, 27)));
+        assert_eq!(isoywd_opt(2004, 53, Weekday::Sun), Some(ymd(2005, 1, 2)));
+        assert_eq!(isoywd_opt(2004, 54, Weekday::Mon), None);
+
+        assert_eq!(isoywd_opt(2011, 0, Weekday::Sun), None);
+        assert_eq!(isoywd_opt(2011, 1, Weekday::Mon), Some(ymd(2011, 1, 3)));
+        assert_eq!(isoywd_opt(2011, 1, Weekday::Sun), Some(ymd(2011, 1, 9)));
+        assert_eq!(isoywd_opt(2011, 2, Weekday::Mon), Some(ymd(2011, 1, 10)));
+        assert_eq!(isoywd_opt(2011, 2, Weekday::Sun), Some(ymd(2011, 1, 16)));
+
+        assert_eq!(isoywd_opt(2018, 51, Weekday::Mon), Some(ymd(2018, 12, 17)));
+        assert_eq!(isoywd_opt(2018, 51, Weekday::Sun), Some(ymd(2018, 12, 23)));
+        assert_eq!(isoywd_opt(2018, 52, Weekday::Mon), Some(ymd(2018, 12, 24)));
+        assert_eq!(isoywd_opt(2018, 52, Weekday::Sun), Some(ymd(2018, 12, 30)));
+        assert_eq!(isoywd_opt(2018, 53, Weekday::Mon), None);
+    }
 
-    impl Encodable for NaiveDate {
-        fn encode<S: Encoder>(&self, s: &mut S) -> Result<(), S::Error> {
-            format!("{:?}", self).encode(s)
+    #[test]
+    fn test_date_from_isoywd_and_iso_week() {
+        for year in 2000..2401 {
+            for week in 1..54 {
+                for &weekday in [
+                    Weekday::Mon,
+                    Weekday::Tue,
+                    Weekday::Wed,
+                    Weekday::Thu,
+                    Weekday::Fri,
+                    Weekday::Sat,
+                    Weekday::Sun,
+                ]
+                .iter()
+                {
+                    let d = NaiveDate::from_isoywd_opt(year, week, weekday);
+                    if d.is_some() {
+                        let d = d.unwrap();
+                        assert_eq!(d.weekday(), weekday);
+                        let w = d.iso_week();
+                        assert_eq!(w.year(), year);
+                        assert_eq!(w.week(), week);
+                    }
+                }
+            }
         }
-    }
 
-    impl Decodable for NaiveDate {
-        fn decode<D: Decoder>(d: &mut D) -> Result<NaiveDate, D::Error> {
-            d.read_str()?.parse().map_err(|_| d.error("invalid date"))
+        for year in 2000..2401 {
+            for month in 1..13 {
+                for day in 1..32 {
+                    let d = NaiveDate::from_ymd_opt(year, month, day);
+                    if d.is_some() {
+                        let d = d.unwrap();
+                        let w = d.iso_week();
+                        let d_ = NaiveDate::from_isoywd(w.year(), w.week(), d.weekday());
+                        assert_eq!(d, d_);
+                    }
+                }
+            }
         }
     }
 
-    #[cfg(test)]
-    use rustc_serialize::json;
-
     #[test]
-    fn test_encodable() {
-        super::test_encodable_json(json::encode);
+    fn test_date_from_num_days_from_ce() {
+        let from_ndays_from_ce = |days| NaiveDate::from_num_days_from_ce_opt(days);
+        assert_eq!(from_ndays_from_ce(1), Some(NaiveDate::from_ymd(1, 1, 1)));
+        assert_eq!(from_ndays_from_ce(2), Some(NaiveDate::from_ymd(1, 1, 2)));
+        assert_eq!(from_ndays_from_ce(31), Some(NaiveDate::from_ymd(1, 1, 31)));
+        assert_eq!(from_ndays_from_ce(32), Some(NaiveDate::from_ymd(1, 2, 1)));
+        assert_eq!(from_ndays_from_ce(59), Some(NaiveDate::from_ymd(1, 2, 28)));
+        assert_eq!(from_ndays_from_ce(60), Some(NaiveDate::from_ymd(1, 3, 1)));
+        assert_eq!(
+            from_ndays_from_ce(365),
+            Some(NaiveDate::from_ymd(1, 12, 31))
+        );
+        assert_eq!(
+            from_ndays_from_ce(365 * 1 + 1),
+            Some(NaiveDate::from_ymd(2, 1, 1))
+        );
+        assert_eq!(
+            from_ndays_from_ce(365 * 2 + 1),
+            Some(NaiveDate::from_ymd(3, 1, 1))
+        );
+        assert_eq!(
+            from_ndays_from_ce(365 * 3 + 1),
+            Some(NaiveDate::from_ymd(4, 1, 1))
+        );
+        assert_eq!(
+            from_ndays_from_ce(365 * 4 + 2),
+            Some(NaiveDate::from_ymd(5, 1, 1))
+        );
+        assert_eq!(
+            from_ndays_from_ce(146097 + 1),
+            Some(NaiveDate::from_ymd(401, 1, 1))
+        );
+        assert_eq!(
+            from_ndays_from_ce(146097 * 5 + 1),
+            Some(NaiveDate::from_ymd(2001, 1, 1))
+        );
+        assert_eq!(
+            from_ndays_from_ce(719163),
+            Some(NaiveDate::from_ymd(1970, 1, 1))
+        );
+        assert_eq!(from_ndays_from_ce(0), Some(NaiveDate::from_ymd(0, 12, 31))); // 1 BCE
+        assert_eq!(from_ndays_from_ce(-365), Some(NaiveDate::from_ymd(0, 1, 1)));
+        assert_eq!(
+            from_ndays_from_ce(-366),
+            Some(NaiveDate::from_ymd(-1, 12, 31))
+        ); // 2 BCE
+
+        for days in (-9999..10001).map(|x| x * 100) {
+            assert_eq!(
+                from_ndays_from_ce(days).map(|d| d.num_days_from_ce()),
+                Some(days)
+            );
+        }
+
+        assert_eq!(
+            from_ndays_from_ce(MIN_DATE.num_days_from_ce()),
+            Some(MIN_DATE)
+        );
+        assert_eq!(from_ndays_from_ce(MIN_DATE.num_days_from_ce() - 1), None);
+        assert_eq!(
+            from_ndays_from_ce(MAX_DATE.num_days_from_ce()),
+            Some(MAX_DATE)
+        );
+        assert_eq!(from_ndays_from_ce(MAX_DATE.num_days_from_ce() + 1), None);
     }
 
     #[test]
-    fn test_decodable() {
-        super::test_decodable_json(json::decode);
+    fn test_date_and_hms_opt() {
+        use naive::NaiveTime;
+        use Timelike;
+
+        let d = NaiveDate::from_ymd(2015, 6, 30);
+        for &(h, m, s, frac) in &[
+            (0, 0, 0, 0),
+            (23, 59, 59, 999),
+            (23, 59, 59, 1_999), // leap second
+            (24, 0, 0, 0),
+            (0, 60, 0, 0),
+            (0, 0, 60, 0),
+            (0, 0, 0, 2_000),
+        ] {
+            assert_eq!(
+                d.and_hms_opt(h, m, s),
+                NaiveTime::from_hms_opt(h, m, s).map(|t| d.and_time(t))
+            );
+            assert_eq!(
+                d.and_hms_milli_opt(h, m, s, frac),
+                NaiveTime::from_hms_milli_opt(h, m, s, frac).map(|t| d.and_time(t))
+            );
+            assert_eq!(
+                d.and_hms_micro_opt(h, m, s, frac * 1_000),
+                NaiveTime::from_hms_micro_opt(h, m, s, frac * 1_000).map(|t| d.and_time(t))
+            );
+            assert_eq!(
+                d.and_hms_nano_opt(h, m, s, frac * 1_000_000),
+                NaiveTime::from_hms_nano_opt(h, m, s, frac * 1_000_000).map(|t| d.and_time(t))
+            );
+        }
+        assert_eq!(
+            d.and_hms_milli_opt(23, 59, 59, 1_999)
+                .map(|dt| (dt.second(), dt.nanosecond())),
+            Some((59, 1_999_000_000))
+        );
+        assert_eq!(d.and_hms_nano_opt(24, 0, 0, 0), None);
     }
-}
-
-#[cfg(feature = "serde")]
-mod serde {
-    use super::NaiveDate;
-    use serdelib::{de, ser};
-    use std::fmt;
-
-    // TODO not very optimized for space (binary formats would want something better)
-
-    impl ser::Serialize for NaiveDate {
-        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-        where
-            S: ser::Serializer,
-        {
-            struct FormatWrapped<'a, D: 'a> {
-                inner: &'a D,
-            }
 
-            impl<'a, D: fmt::Debug> fmt::Display for FormatWrapped<'a, D> {
-                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-                    self.inner.fmt(f)
+    #[test]
+    fn test_date_num_days_from_ce_round_trip() {
+        let from_ndays_from_ce = NaiveDate::from_num_days_from_ce_opt;
+        let min = MIN_DATE.num_days_from_ce();
+        let max = MAX_DATE.num_days_from_ce();
+
+        // the calendar repeats every 400 years (146,097 days), so every day of a cycle
+        // covers all combinations of the year flags and ordinals;
+        // check the cycles at both bounds and around the year 0 as well
+        let cycle = 146_097;
+        for &start in &[min, -cycle, 0, max - cycle + 1] {
+            let mut prev = from_ndays_from_ce(start - 1);
+            for days in start..start + cycle {
+                let date = from_ndays_from_ce(days).unwrap();
+                assert_eq!(date.num_days_from_ce(), days);
+                if let Some(prev) = prev {
+                    assert_eq!(prev.succ(), date);
                 }
+                prev = Some(date);
             }
+        }
 
-            serializer.collect_str(&FormatWrapped { inner: &self })
+        // sample the whole range, about 191 million days
+        let mut days = min;
+        while days <= max {
+            let date = from_ndays_from_ce(days).unwrap();
+            assert_eq!(date.num_days_from_ce(), days);
+            days += 997;
         }
+
+        assert_eq!(from_ndays_from_ce(min - 1), None);
+        assert_eq!(from_ndays_from_ce(max + 1), None);
+        assert_eq!(from_ndays_from_ce(i32::MIN), None);
+        assert_eq!(from_ndays_from_ce(i32::MAX), None);
     }
 
-    struct NaiveDateVisitor;
+    #[test]
+    fn test_date_from_num_days_from_ce_unchecked() {
+        let (min, max) = (MIN_DATE.num_days_from_ce(), MAX_DATE.num_days_from_ce());
+        let days = (-9999..10001)
+            .map(|x| x * 100)
+            .chain(min..min + 800)
+            .chain(max - 800..max + 1);
+        for days in days {
+            assert_eq!(
+                unsafe { NaiveDate::from_num_days_from_ce_unchecked(days) },
+                NaiveDate::from_num_days_from_ce(days)
+            );
+        }
+    }
 
-    impl<'de> de::Visitor<'de> for NaiveDateVisitor {
-        type Value = NaiveDate;
+    #[test]
+    fn test_date_julian_day_number() {
+        let from_jdn = NaiveDate::from_julian_day_number;
+        let ymd = NaiveDate::from_ymd;
 
-        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-            write!(formatter, "a formatted date string")
+        assert_eq!(from_jdn(2_440_588), Some(ymd(1970, 1, 1)));
+        assert_eq!(from_jdn(2_440_587), Some(ymd(1969, 12, 31)));
+        assert_eq!(from_jdn(2_299_161), Some(ymd(1582, 10, 15))); // the Gregorian reform
+        assert_eq!(from_jdn(1_721_426), Some(ymd(1, 1, 1)));
+        assert_eq!(from_jdn(1), Some(ymd(-4713, 11, 25)));
+        assert_eq!(from_jdn(-1), Some(ymd(-4713, 11, 23)));
+        assert_eq!(from_jdn(i32::MAX), None);
+        assert_eq!(from_jdn(i32::MIN), None);
+
+        assert_eq!(ymd(1970, 1, 1).to_julian_day_number(), 2_440_588);
+        assert_eq!(ymd(2000, 1, 1).to_julian_day_number(), 2_451_545);
+        assert_eq!(ymd(2019, 12, 31).to_julian_day_number(), 2_458_849);
+
+        for &date in &[
+            MIN_DATE,
+            ymd(-4713, 11, 24),
+            ymd(0, 2, 29),
+            ymd(2019, 12, 1),
+            MAX_DATE,
+        ] {
+            assert_eq!(from_jdn(date.to_julian_day_number()), Some(date));
         }
+        assert_eq!(from_jdn(MIN_DATE.to_julian_day_number() - 1), None);
+        assert_eq!(from_jdn(MAX_DATE.to_julian_day_number() + 1), None);
+    }
 
-        fn visit_str<E>(self, value: &str) -> Result<NaiveDate, E>
-        where
-            E: de::Error,
-        {
-            value.parse().map_err(|err| E::custom(format!("{}", err)))
+    #[test]
+    fn test_date_fields() {
+        fn check(year: i32, month: u32, day: u32, ordinal: u32) {
+            let d1 = NaiveDate::from_ymd(year, month, day);
+            assert_eq!(d1.year(), year);
+            assert_eq!(d1.month(), month);
+            assert_eq!(d1.day(), day);
+            assert_eq!(d1.ordinal(), ordinal);
+
+            let d2 = NaiveDate::from_yo(year, ordinal);
+            assert_eq!(d2.year(), year);
+            assert_eq!(d2.month(), month);
+            assert_eq!(d2.day(), day);
+            assert_eq!(d2.ordinal(), ordinal);
+
+            assert_eq!(d1, d2);
         }
+
+        check(2012, 1, 1, 1);
+        check(2012, 1, 2, 2);
+        check(2012, 2, 1, 32);
+        check(2012, 2, 29, 60);
+        check(2012, 3, 1, 61);
+        check(2012, 4, 9, 100);
+        check(2012, 7, 18, 200);
+        check(2012, 10, 26, 300);
+        check(2012, 12, 31, 366);
+
+        check(2014, 1, 1, 1);
+        check(2014, 1, 2, 2);
+        check(2014, 2, 1, 32);
+        check(2014, 2, 28, 59);
+        check(2014, 3, 1, 60);
+        check(2014, 4, 10, 100);
+        check(2014, 7, 19, 200);
+        check(2014, 10, 27, 300);
+        check(2014, 12, 31, 365);
     }
 
-    impl<'de> de::Deserialize<'de> for NaiveDate {
-        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-        where
-            D: de::Deserializer<'de>,
-        {
-            deserializer.deserialize_str(NaiveDateVisitor)
-        }
+    #[test]
+    fn test_date_weekday() {
+        assert_eq!(NaiveDate::from_ymd(1582, 10, 15).weekday(), Weekday::Fri);
+        // May 20, 1875 = ISO 8601 reference date
+        assert_eq!(NaiveDate::from_ymd(1875, 5, 20).weekday(), Weekday::Thu);
+        assert_eq!(NaiveDate::from_ymd(2000, 1, 1).weekday(), Weekday::Sat);
     }
 
-    #[cfg(test)]
-    extern crate bincode;
-    #[cfg(test)]
-    extern crate serde_json;
+    #[test]
+    fn test_date_with_fields() {
+        let d = NaiveDate::from_ymd(2000, 2, 29);
+        assert_eq!(d.with_year(-400), Some(NaiveDate::from_ymd(-400, 2, 29)));
+        assert_eq!(d.with_year(-100), None);
+        assert_eq!(d.with_year(1600), Some(NaiveDate::from_ymd(1600, 2, 29)));
+        assert_eq!(d.with_year(1900), None);
+        assert_eq!(d.with_year(2000), Some(NaiveDate::from_ymd(2000, 2, 29)));
+        assert_eq!(d.with_year(2001), None);
+        assert_eq!(d.with_year(2004), Some(NaiveDate::from_ymd(2004, 2, 29)));
+        assert_eq!(d.with_year(i32::MAX), None);
+
+        let d = NaiveDate::from_ymd(2000, 4, 30);
+        assert_eq!(d.with_month(0), None);
+        assert_eq!(d.with_month(1), Some(NaiveDate::from_ymd(2000, 1, 30)));
+        assert_eq!(d.with_month(2), None);
+        assert_eq!(d.with_month(3), Some(NaiveDate::from_ymd(2000, 3, 30)));
+        assert_eq!(d.with_month(4), Some(NaiveDate::from_ymd(2000, 4, 30)));
+        assert_eq!(d.with_month(12), Some(NaiveDate::from_ymd(2000, 12, 30)));
+        assert_eq!(d.with_month(13), None);
+        assert_eq!(d.with_month(u32::MAX), None);
+
+        let d = NaiveDate::from_ymd(2000, 2, 8);
+        assert_eq!(d.with_day(0), None);
+        assert_eq!(d.with_day(1), Some(NaiveDate::from_ymd(2000, 2, 1)));
+        assert_eq!(d.with_day(29), Some(NaiveDate::from_ymd(2000, 2, 29)));
+        assert_eq!(d.with_day(30), None);
+        assert_eq!(d.with_day(u32::MAX), None);
 
-    #[test]
-    fn test_serde_serialize() {
-        super::test_encodable_json(self::serde_json::to_string);
+        let d = NaiveDate::from_ymd(2000, 5, 5);
+        assert_eq!(d.with_ordinal(0), None);
+        assert_eq!(d.with_ordinal(1), Some(NaiveDate::from_ymd(2000, 1, 1)));
+        assert_eq!(d.with_ordinal(60), Some(NaiveDate::from_ymd(2000, 2, 29)));
+        assert_eq!(d.with_ordinal(61), Some(NaiveDate::from_ymd(2000, 3, 1)));
+        assert_eq!(d.with_ordinal(366), Some(NaiveDate::from_ymd(2000, 12, 31)));
+        assert_eq!(d.with_ordinal(367), None);
+        assert_eq!(d.with_ordinal(u32::MAX), None);
     }
 
     #[test]
-    fn test_serde_deserialize() {
-        super::test_decodable_json(|input| self::serde_json::from_str(&input));
-    }
+    fn test_date_with_fields_clamped() {
+        let d = NaiveDate::from_ymd(2000, 2, 29);
+        assert_eq!(
+            d.with_year_clamped(-400),
+            Some(NaiveDate::from_ymd(-400, 2, 29))
+        );
+        assert_eq!(
+            d.with_year_clamped(-100),
+            Some(NaiveDate::from_ymd(-100, 2, 28))
+        );
+        assert_eq!(
+            d.with_year_clamped(1900),
+            Some(NaiveDate::from_ymd(1900, 2, 28))
+        );
+        assert_eq!(
+            d.with_year_clamped(2001),
+            Some(NaiveDate::from_ymd(2001, 2, 28))
+        );
+        assert_eq!(
+            d.with_year_clamped(2004),
+            Some(NaiveDate::from_ymd(2004, 2, 29))
+        );
+        assert_eq!(d.with_year_clamped(MAX_YEAR + 1), None);
+        assert_eq!(d.with_year_clamped(i32::MIN), None);
 
-    #[test]
-    fn test_serde_bincode() {
-        // Bincode is relevant to test separately from JSON because
-        // it is not self-describing.
-        use self::bincode::{deserialize, serialize, Infinite};
+        let d = NaiveDate::from_ymd(2000, 1, 31);
+        assert_eq!(d.with_month_clamped(0), None);
+        assert_eq!(d.with_month_clamped(1), Some(d));
+        assert_eq!(
+            d.with_month_clamped(2),
+            Some(NaiveDate::from_ymd(2000, 2, 29))
+        );
+        assert_eq!(
+            d.with_month_clamped(4),
+            Some(NaiveDate::from_ymd(2000, 4, 30))
+        );
+        assert_eq!(
+            d.with_month_clamped(12),
+            Some(NaiveDate::from_ymd(2000, 12, 31))
+        );
+        assert_eq!(d.with_month_clamped(13), None);
+        assert_eq!(d.with_month_clamped(u32::MAX), None);
 
-        let d = NaiveDate::from_ymd(2014, 7, 24);
-        let encoded = serialize(&d, Infinite).unwrap();
-        let decoded: NaiveDate = deserialize(&encoded).unwrap();
-        assert_eq!(d, decoded);
+        let d = NaiveDate::from_ymd(2001, 3, 15);
+        assert_eq!(
+            d.with_month_clamped(2),
+            Some(NaiveDate::from_ymd(2001, 2, 15))
+        );
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::NaiveDate;
-    use super::{MAX_DATE, MAX_DAYS_FROM_YEAR_0, MAX_YEAR};
-    use super::{MIN_DATE, MIN_DAYS_FROM_YEAR_0, MIN_YEAR};
-    use oldtime::Duration;
-    use std::{i32, u32};
-    use {Datelike, Weekday};
 
     #[test]
-    fn test_date_from_ymd() {
-        let ymd_opt = |y, m, d| NaiveDate::from_ymd_opt(y, m, d);
+    fn test_date_num_days_from_ce() {
+        assert_eq!(NaiveDate::from_ymd(1, 1, 1).num_days_from_ce(), 1);
 
-        assert!(ymd_opt(2012, 0, 1).is_none());
-        assert!(ymd_opt(2012, 1, 1).is_some());
-        assert!(ymd_opt(2012, 2, 29).is_some());
-        assert!(ymd_opt(2014, 2, 29).is_none());
-        assert!(ymd_opt(2014, 3, 0).is_none());
-        assert!(ymd_opt(2014, 3, 1).is_some());
-        assert!(ymd_opt(2014, 3, 31).is_some());
-        assert!(ymd_opt(2014, 3, 32).is_none());
-        assert!(ymd_opt(2014, 12, 31).is_some());
-        assert!(ymd_opt(2014, 13, 1).is_none());
+        for year in -9999..10001 {
+            assert_eq!(
+                NaiveDate::from_ymd(year, 1, 1).num_days_from_ce(),
+                NaiveDate::from_ymd(year - 1, 12, 31).num_days_from_ce() + 1
+            );
+        }
     }
 
     #[test]
-    fn test_date_from_yo() {
-        let yo_opt = |y, o| NaiveDate::from_yo_opt(y, o);
+    fn test_date_succ() {
         let ymd = |y, m, d| NaiveDate::from_ymd(y, m, d);
-
-        assert_eq!(yo_opt(2012, 0), None);
-        assert_eq!(yo_opt(2012, 1), Some(ymd(2012, 1, 1)));
-        assert_eq!(yo_opt(2012, 2), Some(ymd(2012, 1, 2)));
-        assert_eq!(yo_opt(2012, 32), Some(ymd(2012, 2, 1)));
-        assert_eq!(yo_opt(2012, 60), Some(ymd(2012, 2, 29)));
-        assert_eq!(yo_opt(2012, 61), Some(ymd(2012, 3, 1)));
-        assert_eq!(yo_opt(2012, 100), Some(ymd(2012, 4, 9)));
-        assert_eq!(yo_opt(2012, 200), Some(ymd(2012, 7, 18)));
-        assert_eq!(yo_opt(2012, 300), Some(ymd(2012, 10, 26)));
-        assert_eq!(yo_opt(2012, 366), Some(ymd(2012, 12, 31)));
-        assert_eq!(yo_opt(2012, 367), None);
-
-        assert_eq!(yo_opt(2014, 0), None);
-        assert_eq!(yo_opt(2014, 1), Some(ymd(2014, 1, 1)));
-        assert_eq!(yo_opt(2014, 2), Some(ymd(2014, 1, 2)));
-        assert_eq!(yo_opt(2014, 32), Some(ymd(2014, 2, 1)));
-        assert_eq!(yo_opt(2014, 59), Some(ymd(2014, 2, 28)));
-        assert_eq!(yo_opt(2014, 60), Some(ymd(2014, 3, 1)));
-        assert_eq!(yo_opt(2014, 100), Some(ymd(2014, 4, 10)));
-        assert_eq!(yo_opt(2014, 200), Some(ymd(2014, 7, 19)));
-        assert_eq!(yo_opt(2014, 300), Some(ymd(2014, 10, 27)));
-        assert_eq!(yo_opt(2014, 365), Some(ymd(2014, 12, 31)));
-        assert_eq!(yo_opt(2014, 366), None);
+        assert_eq!(ymd(2014, 5, 6).succ_opt(), Some(ymd(2014, 5, 7)));
+        assert_eq!(ymd(2014, 5, 31).succ_opt(), Some(ymd(2014, 6, 1)));
+        assert_eq!(ymd(2014, 12, 31).succ_opt(), Some(ymd(2015, 1, 1)));
+        assert_eq!(ymd(2016, 2, 28).succ_opt(), Some(ymd(2016, 2, 29)));
+        assert_eq!(ymd(MAX_DATE.year(), 12, 31).succ_opt(), None);
     }
 
     #[test]
-    fn test_date_from_isoywd() {
-        let isoywd_opt = |y, w, d| NaiveDate::from_isoywd_opt(y, w, d);
+    fn test_date_pred() {
         let ymd = |y, m, d| NaiveDate::from_ymd(y, m, d);
-
-        assert_eq!(isoywd_opt(2004, 0, Weekday::Sun), None);
-        assert_eq!(isoywd_opt(2004, 1, Weekday::Mon), Some(ymd(2003, 12, 29)));
-        assert_eq!(isoywd_opt(2004, 1, Weekday::Sun), Some(ymd(2004, 1, 4)));
-        assert_eq!(isoywd_opt(2004, 2, Weekday::Mon), Some(ymd(2004, 1, 5)));
-        assert_eq!(isoywd_opt(2004, 2, Weekday::Sun), Some(ymd(2004, 1, 11)));
-        assert_eq!(isoywd_opt(2004, 52, Weekday::Mon), Some(ymd(2004, 12, 20)));
-        assert_eq!(isoywd_opt(2004, 52, Weekday::Sun), Some(ymd(2004, 12, 26)));
-        assert_eq!(isoywd_opt(2004, 53, Weekday::Mon), Some(ymd(2004, 12, 27)));
-        assert_eq!(isoywd_opt(2004, 53, Weekday::Sun), Some(ymd(2005, 1, 2)));
-        assert_eq!(isoywd_opt(2004, 54, Weekday::Mon), None);
-
-        assert_eq!(isoywd_opt(2011, 0, Weekday::Sun), None);
-        assert_eq!(isoywd_opt(2011, 1, Weekday::Mon), Some(ymd(2011, 1, 3)));
-        assert_eq!(isoywd_opt(2011, 1, Weekday::Sun), Some(ymd(2011, 1, 9)));
-        assert_eq!(isoywd_opt(2011, 2, Weekday::Mon), Some(ymd(2011, 1, 10)));
-        assert_eq!(isoywd_opt(2011, 2, Weekday::Sun), Some(ymd(2011, 1, 16)));
-
-        assert_eq!(isoywd_opt(2018, 51, Weekday::Mon), Some(ymd(2018, 12, 17)));
-        assert_eq!(isoywd_opt(2018, 51, Weekday::Sun), Some(ymd(2018, 12, 23)));
-        assert_eq!(isoywd_opt(2018, 52, Weekday::Mon), Some(ymd(2018, 12, 24)));
-        assert_eq!(isoywd_opt(2018, 52, Weekday::Sun), Some(ymd(2018, 12, 30)));
-        assert_eq!(isoywd_opt(2018, 53, Weekday::Mon), None);
+        assert_eq!(ymd(2016, 3, 1).pred_opt(), Some(ymd(2016, 2, 29)));
+        assert_eq!(ymd(2015, 1, 1).pred_opt(), Some(ymd(2014, 12, 31)));
+        assert_eq!(ymd(2014, 6, 1).pred_opt(), Some(ymd(2014, 5, 31)));
+        assert_eq!(ymd(2014, 5, 7).pred_opt(), Some(ymd(2014, 5, 6)));
+        assert_eq!(ymd(MIN_DATE.year(), 1, 1).pred_opt(), None);
     }
 
     #[test]
-    fn test_date_from_isoywd_and_iso_week() {
-        for year in 2000..2401 {
-            for week in 1..54 {
-                for &weekday in [
-                    Weekday::Mon,
-                    Weekday::Tue,
-                    Weekday::Wed,
-                    Weekday::Thu,
-                    Weekday::Fri,
-                    Weekday::Sat,
-                    Weekday::Sun,
-                ]
-                .iter()
-                {
-                    let d = NaiveDate::from_isoywd_opt(year, week, weekday);
-                    if d.is_some() {
-                        let d = d.unwrap();
-                        assert_eq!(d.weekday(), weekday);
-                        let w = d.iso_week();
-                        assert_eq!(w.year(), year);
-                        assert_eq!(w.week(), week);
-                    }
-                }
-            }
+    fn test_date_week() {
+        let ymd = |y, m, d| NaiveDate::from_ymd(y, m, d);
+        for &(start, first, last) in &[
+            (Weekday::Mon, ymd(2019, 12, 30), ymd(2020, 1, 5)),
+            (Weekday::Wed, ymd(2020, 1, 1), ymd(2020, 1, 7)),
+            (Weekday::Thu, ymd(2019, 12, 26), ymd(2020, 1, 1)),
+            (Weekday::Sun, ymd(2019, 12, 29), ymd(2020, 1, 4)),
+        ] {
+            let week = ymd(2020, 1, 1).week(start);
+            assert_eq!(week.first_day(), first);
+            assert_eq!(week.last_day(), last);
+            assert_eq!(week.first_day().weekday(), start);
+            assert_eq!(last.week(start).first_day(), first);
         }
+    }
 
-        for year in 2000..2401 {
-            for month in 1..13 {
-                for day in 1..32 {
-                    let d = NaiveDate::from_ymd_opt(year, month, day);
-                    if d.is_some() {
-                        let d = d.unwrap();
-                        let w = d.iso_week();
-                        let d_ = NaiveDate::from_isoywd(w.year(), w.week(), d.weekday());
-                        assert_eq!(d, d_);
-                    }
-                }
+    #[test]
+    fn test_date_num_days_until_and_since_weekday() {
+        // 2019-12-30 is a Monday and the week spans two years
+        let monday = NaiveDate::from_ymd(2019, 12, 30);
+        for i in 0..7 {
+            let d = monday + Duration::days(i);
+            for j in 0..7 {
+                let weekday = (monday + Duration::days(j)).weekday();
+                let until = d.num_days_until(weekday);
+                let since = d.num_days_since(weekday);
+                assert_eq!(i64::from(until), (j - i + 7) % 7);
+                assert_eq!(i64::from(since), (i - j + 7) % 7);
+                assert_eq!((d + Duration::days(i64::from(until))).weekday(), weekday);
+                assert_eq!((d - Duration::days(i64::from(since))).weekday(), weekday);
+                assert_eq!(until == 0, since == 0);
             }
         }
+
+        // both MIN_DATE and MAX_DATE are Tuesdays
+        assert_eq!(MAX_DATE.num_days_until(Weekday::Mon), 6);
+        assert_eq!(MIN_DATE.num_days_since(Weekday::Sun), 2);
     }
 
     #[test]
-    fn test_date_from_num_days_from_ce() {
-        let from_ndays_from_ce = |days| NaiveDate::from_num_days_from_ce_opt(days);
-        assert_eq!(from_ndays_from_ce(1), Some(NaiveDate::from_ymd(1, 1, 1)));
-        assert_eq!(from_ndays_from_ce(2), Some(NaiveDate::from_ymd(1, 1, 2)));
-        assert_eq!(from_ndays_from_ce(31), Some(NaiveDate::from_ymd(1, 1, 31)));
-        assert_eq!(from_ndays_from_ce(32), Some(NaiveDate::from_ymd(1, 2, 1)));
-        assert_eq!(from_ndays_from_ce(59), Some(NaiveDate::from_ymd(1, 2, 28)));
-        assert_eq!(from_ndays_from_ce(60), Some(NaiveDate::from_ymd(1, 3, 1)));
-        assert_eq!(
-            from_ndays_from_ce(365),
-            Some(NaiveDate::from_ymd(1, 12, 31))
-        );
+    fn test_date_iter_days_until() {
+        let ymd = |y, m, d| NaiveDate::from_ymd(y, m, d);
+
+        let forward: Vec<NaiveDate> = ymd(2016, 2, 27).iter_days_until(ymd(2016, 3, 2)).collect();
         assert_eq!(
-            from_ndays_from_ce(365 * 1 + 1),
-            Some(NaiveDate::from_ymd(2, 1, 1))
+            forward,
+            vec![
+                ymd(2016, 2, 27),
+                ymd(2016, 2, 28),
+                ymd(2016, 2, 29),
+                ymd(2016, 3, 1)
+            ]
         );
+        let mut backward: Vec<NaiveDate> = ymd(2016, 2, 27)
+            .iter_days_until(ymd(2016, 3, 2))
+            .rev()
+            .collect();
+        backward.reverse();
+        assert_eq!(backward, forward);
+
+        let mut iter = ymd(2016, 2, 27).iter_days_until(ymd(2016, 3, 2));
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.next_back(), Some(ymd(2016, 3, 1)));
+        assert_eq!(iter.next(), Some(ymd(2016, 2, 27)));
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next_back(), Some(ymd(2016, 2, 29)));
+        assert_eq!(iter.next_back(), Some(ymd(2016, 2, 28)));
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(iter.next(), None);
+
         assert_eq!(
-            from_ndays_from_ce(365 * 2 + 1),
-            Some(NaiveDate::from_ymd(3, 1, 1))
+            ymd(2016, 2, 27).iter_days_until(ymd(2016, 2, 27)).count(),
+            0
         );
         assert_eq!(
-            from_ndays_from_ce(365 * 3 + 1),
-            Some(NaiveDate::from_ymd(4, 1, 1))
+            ymd(2016, 2, 27).iter_days_until(ymd(2016, 2, 26)).count(),
+            0
         );
+
+        // terminates at the bounds instead of panicking
+        let near_min = MIN_DATE.iter_days_until(MIN_DATE.succ().succ());
         assert_eq!(
-            from_ndays_from_ce(365 * 4 + 2),
-            Some(NaiveDate::from_ymd(5, 1, 1))
+            near_min.rev().collect::<Vec<_>>(),
+            vec![MIN_DATE.succ(), MIN_DATE]
         );
+        assert_eq!(MIN_DATE.iter_days_until(MIN_DATE).next_back(), None);
+        let near_max = MAX_DATE.pred().iter_days_until(MAX_DATE);
+        assert_eq!(near_max.collect::<Vec<_>>(), vec![MAX_DATE.pred()]);
+    }
+
+    #[test]
+    fn test_date_excel_serial() {
+        let ymd = |y, m, d| NaiveDate::from_ymd(y, m, d);
+        let from_excel = NaiveDate::from_excel_serial;
+
+        assert_eq!(from_excel(0), None);
+        assert_eq!(from_excel(1), Some(ymd(1900, 1, 1)));
+        assert_eq!(from_excel(59), Some(ymd(1900, 2, 28)));
+        assert_eq!(from_excel(60), None);
+        assert_eq!(from_excel(61), Some(ymd(1900, 3, 1)));
+        assert_eq!(from_excel(25_569), Some(ymd(1970, 1, 1)));
+        assert_eq!(from_excel(2_958_465), Some(ymd(9999, 12, 31)));
+        assert_eq!(from_excel(u32::MAX), None);
+
+        assert_eq!(ymd(1899, 12, 30).to_excel_serial(), None);
+        assert_eq!(ymd(1899, 12, 31).to_excel_serial(), None);
+        assert_eq!(MIN_DATE.to_excel_serial(), None);
+        assert_eq!(ymd(1900, 2, 28).to_excel_serial(), Some(59));
+        assert_eq!(ymd(1900, 3, 1).to_excel_serial(), Some(61));
+        assert_eq!(ymd(1970, 1, 1).to_excel_serial(), Some(25_569));
+
+        for serial in (1..100).chain(2_958_400..2_958_466) {
+            if let Some(date) = from_excel(serial) {
+                assert_eq!(date.to_excel_serial(), Some(serial));
+            }
+        }
         assert_eq!(
-            from_ndays_from_ce(146097 + 1),
-            Some(NaiveDate::from_ymd(401, 1, 1))
+            from_excel(MAX_DATE.to_excel_serial().unwrap()),
+            Some(MAX_DATE)
         );
+    }
+
+    #[test]
+    fn test_date_mjd() {
+        let ymd = |y, m, d| NaiveDate::from_ymd(y, m, d);
+
+        assert_eq!(NaiveDate::from_mjd(0.0), Some(ymd(1858, 11, 17)));
+        assert_eq!(NaiveDate::from_mjd(0.999), Some(ymd(1858, 11, 17)));
+        assert_eq!(NaiveDate::from_mjd(-0.001), Some(ymd(1858, 11, 16)));
+        assert_eq!(NaiveDate::from_mjd(40_587.0), Some(ymd(1970, 1, 1)));
+        assert_eq!(NaiveDate::from_mjd(51_544.5), Some(ymd(2000, 1, 1)));
+        assert_eq!(NaiveDate::from_mjd(::std::f64::NAN), None);
+        assert_eq!(NaiveDate::from_mjd(::std::f64::INFINITY), None);
+        assert_eq!(NaiveDate::from_mjd(::std::f64::NEG_INFINITY), None);
+        assert_eq!(NaiveDate::from_mjd(MAX_DATE.to_mjd() + 1.0), None);
+        assert_eq!(NaiveDate::from_mjd(MIN_DATE.to_mjd() - 1.0), None);
+
+        assert_eq!(ymd(1970, 1, 1).to_mjd(), 40_587.0);
+        assert_eq!(ymd(1858, 11, 16).to_mjd(), -1.0);
+        for &date in &[MIN_DATE, ymd(1858, 11, 17), ymd(2019, 12, 25), MAX_DATE] {
+            assert_eq!(NaiveDate::from_mjd(date.to_mjd()), Some(date));
+            assert_eq!(
+                f64::from(date.to_julian_day_number() - 2_400_001),
+                date.to_mjd()
+            );
+        }
+    }
+
+    #[test]
+    fn test_weekday_from_days() {
+        for days in -1_000..1_000 {
+            let date = NaiveDate::from_num_days_from_ce(days);
+            assert_eq!(weekday_from_days(days), date.weekday());
+        }
+        for &date in &[MIN_DATE, MAX_DATE, NaiveDate::from_ymd(2019, 12, 25)] {
+            assert_eq!(weekday_from_days(date.num_days_from_ce()), date.weekday());
+        }
         assert_eq!(
-            from_ndays_from_ce(146097 * 5 + 1),
-            Some(NaiveDate::from_ymd(2001, 1, 1))
+            weekday_from_days(i32::MIN),
+            weekday_from_days(i32::MIN % 7 + 7)
         );
+        assert_eq!(weekday_from_days(i32::MAX), weekday_from_days(i32::MAX % 7));
+    }
+
+    #[test]
+    fn test_days_in_year_and_month() {
+        for year in -500..2500 {
+            let ndays = NaiveDate::from_ymd(year, 12, 31).ordinal();
+            assert_eq!(days_in_year(year), ndays, "year {}", year);
+            for month in 1..13 {
+                let ndays = (1..32)
+                    .filter(|&d| NaiveDate::from_ymd_opt(year, month, d).is_some())
+                    .count() as u32;
+                assert_eq!(
+                    days_in_month(year, month),
+                    Some(ndays),
+                    "{}-{}",
+                    year,
+                    month
+                );
+            }
+        }
+        assert_eq!(days_in_month(2020, 0), None);
+        assert_eq!(days_in_month(2020, 13), None);
         assert_eq!(
-            from_ndays_from_ce(719163),
-            Some(NaiveDate::from_ymd(1970, 1, 1))
+            days_in_year(MIN_DATE.year()),
+            MIN_DATE
+                .with_month(12)
+                .unwrap()
+                .with_day(31)
+                .unwrap()
+                .ordinal()
         );
-        assert_eq!(from_ndays_from_ce(0), Some(NaiveDate::from_ymd(0, 12, 31))); // 1 BCE
-        assert_eq!(from_ndays_from_ce(-365), Some(NaiveDate::from_ymd(0, 1, 1)));
-        assert_eq!(
-            from_ndays_from_ce(-366),
-            Some(NaiveDate::from_ymd(-1, 12, 31))
-        ); // 2 BCE
+        assert_eq!(days_in_year(MAX_DATE.year()), MAX_DATE.ordinal());
+    }
 
-        for days in (-9999..10001).map(|x| x * 100) {
+    #[test]
+    fn test_date_easter() {
+        // days after March 21 for 1900 to 2100, from the published tables
+        const WESTERN: [u8; 201] = [
+            25, 17, 9, 22, 13, 33, 25, 10, 29, 21, 6, 26, 17, 2, 22, 14, 33, 18, 10, 30, 14, 6, 26,
+            11, 30, 22, 14, 27, 18, 10, 30, 15, 6, 26, 11, 31, 22, 7, 27, 19, 3, 23, 15, 35, 19,
+            11, 31, 16, 7, 27, 19, 4, 23, 15, 28, 20, 11, 31, 16, 8, 27, 12, 32, 24, 8, 28, 20, 5,
+            24, 16, 8, 21, 12, 32, 24, 9, 28, 20, 5, 25, 16, 29, 21, 13, 32, 17, 9, 29, 13, 5, 25,
+            10, 29, 21, 13, 26, 17, 9, 22, 14, 33, 25, 10, 30, 21, 6, 26, 18, 2, 22, 14, 34, 18,
+            10, 30, 15, 6, 26, 11, 31, 22, 14, 27, 19, 10, 30, 15, 7, 26, 11, 31, 23, 7, 27, 19, 4,
+            23, 15, 35, 20, 11, 31, 16, 8, 27, 19, 4, 24, 15, 28, 20, 12, 31, 16, 8, 28, 12, 32,
+            24, 9, 28, 20, 5, 25, 16, 8, 21, 13, 32, 24, 9, 29, 20, 5, 25, 17, 29, 21, 13, 33, 17,
+            9, 29, 14, 5, 25, 10, 30, 21, 13, 26, 18, 9, 22, 14, 34, 25, 10, 30, 22, 7,
+        ];
+        const ORTHODOX: [u8; 201] = [
+            32, 24, 37, 29, 20, 40, 25, 45, 36, 21, 41, 33, 17, 37, 29, 14, 33, 25, 45, 30, 21, 41,
+            26, 18, 37, 29, 42, 34, 25, 45, 30, 22, 41, 26, 18, 38, 22, 42, 34, 19, 38, 30, 15, 35,
+            26, 46, 31, 23, 42, 34, 19, 39, 30, 15, 35, 27, 46, 31, 23, 43, 27, 19, 39, 24, 43, 35,
+            20, 40, 31, 23, 36, 28, 19, 39, 24, 44, 35, 20, 40, 32, 16, 36, 28, 48, 32, 24, 44, 29,
+            20, 40, 25, 17, 36, 28, 41, 33, 24, 37, 29, 21, 40, 25, 45, 37, 21, 41, 33, 18, 37, 29,
+            14, 34, 25, 45, 30, 22, 41, 26, 18, 38, 29, 42, 34, 26, 45, 30, 22, 42, 26, 18, 38, 23,
+            42, 34, 19, 39, 30, 15, 35, 27, 46, 31, 23, 43, 34, 19, 39, 31, 15, 35, 27, 47, 31, 23,
+            43, 28, 19, 39, 24, 44, 35, 20, 40, 32, 23, 36, 28, 20, 39, 24, 44, 29, 20, 40, 32, 17,
+            36, 28, 48, 33, 24, 44, 29, 21, 40, 25, 17, 37, 28, 41, 33, 18, 37, 29, 21, 34, 25, 45,
+            37, 22, 42,
+        ];
+
+        for (i, (&western, &orthodox)) in WESTERN.iter().zip(ORTHODOX.iter()).enumerate() {
+            let year = 1900 + i as i32;
+            let march21 = NaiveDate::from_ymd(year, 3, 21);
+            let easter = march21 + Days(u64::from(western));
+            assert_eq!(NaiveDate::easter_gregorian(year), Some(easter), "{}", year);
             assert_eq!(
-                from_ndays_from_ce(days).map(|d| d.num_days_from_ce()),
-                Some(days)
+                NaiveDate::easter_julian_in_gregorian(year),
+                Some(march21 + Days(u64::from(orthodox))),
+                "{}",
+                year
+            );
+            assert_eq!(easter.weekday(), Weekday::Sun);
+            assert_eq!(NaiveDate::good_friday(year), Some(easter - Days(2)));
+            assert_eq!(
+                NaiveDate::good_friday(year).unwrap().weekday(),
+                Weekday::Fri
+            );
+            assert_eq!(NaiveDate::whit_monday(year), Some(easter + Days(50)));
+            assert_eq!(
+                NaiveDate::whit_monday(year).unwrap().weekday(),
+                Weekday::Mon
             );
         }
 
         assert_eq!(
-            from_ndays_from_ce(MIN_DATE.num_days_from_ce()),
-            Some(MIN_DATE)
+            NaiveDate::easter_gregorian(1583),
+            Some(NaiveDate::from_ymd(1583, 4, 10))
+        );
+        assert_eq!(NaiveDate::easter_gregorian(1582), None);
+        assert_eq!(NaiveDate::easter_julian_in_gregorian(1582), None);
+        assert_eq!(NaiveDate::good_friday(1000), None);
+        assert_eq!(NaiveDate::whit_monday(1000), None);
+        assert_eq!(NaiveDate::easter_gregorian(i32::MAX), None);
+        assert_eq!(NaiveDate::easter_julian_in_gregorian(i32::MAX), None);
+        assert!(NaiveDate::easter_gregorian(MAX_DATE.year()).is_some());
+    }
+
+    #[test]
+    fn test_date_business_days_with_calendar() {
+        use holiday::{FixedHolidays, HolidayCalendar, Weekends};
+
+        let ymd = NaiveDate::from_ymd;
+        let mut holidays = FixedHolidays::new();
+        holidays.insert(ymd(2019, 12, 25), "Christmas Day");
+        holidays.insert(ymd(2019, 12, 26), "Boxing Day");
+        holidays.insert(ymd(2020, 1, 1), "New Year's Day");
+        let cal = (&holidays).with_weekends();
+
+        assert!(ymd(2019, 12, 24).is_business_day(&cal));
+        assert!(!ymd(2019, 12, 25).is_business_day(&cal));
+        assert!(!ymd(2019, 12, 29).is_business_day(&cal));
+        assert!(ymd(2019, 12, 29).is_business_day(&holidays));
+
+        assert_eq!(
+            ymd(2019, 12, 24).next_business_day(&cal),
+            Some(ymd(2019, 12, 27))
         );
-        assert_eq!(from_ndays_from_ce(MIN_DATE.num_days_from_ce() - 1), None);
         assert_eq!(
-            from_ndays_from_ce(MAX_DATE.num_days_from_ce()),
-            Some(MAX_DATE)
+            ymd(2019, 12, 31).next_business_day(&cal),
+            Some(ymd(2020, 1, 2))
+        );
+        assert_eq!(
+            ymd(2019, 12, 24).next_business_day(&holidays),
+            Some(ymd(2019, 12, 27))
+        );
+        assert_eq!(MAX_DATE.next_business_day(&holidays), None);
+        assert_eq!(MAX_DATE.pred().next_business_day(&holidays), Some(MAX_DATE));
+        let all = Weekends::new(&[Weekday::Sat, Weekday::Sun]).union(Weekends::new(&[
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+        ]));
+        assert_eq!(MAX_DATE.pred().pred().next_business_day(&all), None);
+
+        // agrees with the slice-based counterpart when weekends are included
+        let dates = [ymd(2019, 12, 25), ymd(2019, 12, 26), ymd(2020, 1, 1)];
+        let slice_cal = (&dates[..]).with_weekends();
+        for &(from, to) in &[
+            (ymd(2019, 12, 23), ymd(2020, 1, 6)),
+            (ymd(2019, 12, 28), ymd(2019, 12, 29)),
+            (ymd(2019, 12, 1), ymd(2020, 2, 1)),
+            (ymd(2019, 12, 25), ymd(2019, 12, 25)),
+        ] {
+            let expected = from.business_days_until(to, &dates);
+            assert_eq!(from.business_days_between(&cal, to), expected);
+            assert_eq!(from.business_days_between(&slice_cal, to), expected);
+            assert_eq!(to.business_days_between(&cal, from), -expected);
+        }
+        assert_eq!(
+            ymd(2019, 12, 23).business_days_between(&holidays, ymd(2019, 12, 30)),
+            5
         );
-        assert_eq!(from_ndays_from_ce(MAX_DATE.num_days_from_ce() + 1), None);
     }
 
     #[test]
-    fn test_date_fields() {
-        fn check(year: i32, month: u32, day: u32, ordinal: u32) {
-            let d1 = NaiveDate::from_ymd(year, month, day);
-            assert_eq!(d1.year(), year);
-            assert_eq!(d1.month(), month);
-            assert_eq!(d1.day(), day);
-            assert_eq!(d1.ordinal(), ordinal);
-
-            let d2 = NaiveDate::from_yo(year, ordinal);
-            assert_eq!(d2.year(), year);
-            assert_eq!(d2.month(), month);
-            assert_eq!(d2.day(), day);
-            assert_eq!(d2.ordinal(), ordinal);
+    fn test_date_range() {
+        let ymd = NaiveDate::from_ymd;
+        let collect = |start, end, step| NaiveDate::range(start, end, step).collect::<Vec<_>>();
 
-            assert_eq!(d1, d2);
-        }
+        assert_eq!(
+            collect(ymd(2019, 12, 30), ymd(2020, 1, 2), Duration::days(1)),
+            [ymd(2019, 12, 30), ymd(2019, 12, 31), ymd(2020, 1, 1)]
+        );
+        assert_eq!(
+            collect(ymd(2019, 12, 2), ymd(2019, 12, 24), Duration::weeks(1)),
+            [
+                ymd(2019, 12, 2),
+                ymd(2019, 12, 9),
+                ymd(2019, 12, 16),
+                ymd(2019, 12, 23)
+            ]
+        );
+        assert_eq!(
+            collect(ymd(2020, 1, 2), ymd(2019, 12, 30), Duration::days(-1)),
+            [ymd(2020, 1, 2), ymd(2020, 1, 1), ymd(2019, 12, 31)]
+        );
+        // only the whole days of the step are used
+        assert_eq!(
+            collect(ymd(2020, 1, 1), ymd(2020, 1, 5), Duration::hours(50)),
+            [ymd(2020, 1, 1), ymd(2020, 1, 3)]
+        );
+        assert!(collect(ymd(2020, 1, 1), ymd(2020, 1, 1), Duration::days(1)).is_empty());
+        assert!(collect(ymd(2020, 1, 2), ymd(2020, 1, 1), Duration::days(1)).is_empty());
+        assert!(collect(ymd(2020, 1, 1), ymd(2020, 1, 2), Duration::days(-1)).is_empty());
 
-        check(2012, 1, 1, 1);
-        check(2012, 1, 2, 2);
-        check(2012, 2, 1, 32);
-        check(2012, 2, 29, 60);
-        check(2012, 3, 1, 61);
-        check(2012, 4, 9, 100);
-        check(2012, 7, 18, 200);
-        check(2012, 10, 26, 300);
-        check(2012, 12, 31, 366);
+        // stops at the bounds of the supported dates
+        assert!(collect(MAX_DATE, MIN_DATE, Duration::days(1)).is_empty());
+        assert_eq!(
+            collect(MAX_DATE.pred(), MAX_DATE, Duration::days(1)),
+            [MAX_DATE.pred()]
+        );
+        assert!(collect(MAX_DATE.pred(), MIN_DATE, Duration::days(1)).is_empty());
+        assert_eq!(
+            collect(MIN_DATE, MAX_DATE, Duration::days(i32::MAX as i64)),
+            [MIN_DATE]
+        );
+        assert_eq!(
+            collect(MIN_DATE.succ(), MIN_DATE, Duration::days(-3)),
+            [MIN_DATE.succ()]
+        );
 
-        check(2014, 1, 1, 1);
-        check(2014, 1, 2, 2);
-        check(2014, 2, 1, 32);
-        check(2014, 2, 28, 59);
-        check(2014, 3, 1, 60);
-        check(2014, 4, 10, 100);
-        check(2014, 7, 19, 200);
-        check(2014, 10, 27, 300);
-        check(2014, 12, 31, 365);
+        let range = NaiveDate::range(ymd(2019, 12, 2), ymd(2019, 12, 24), Duration::weeks(1));
+        assert_eq!(range.size_hint(), (4, Some(4)));
+        let range = NaiveDate::range(ymd(2019, 12, 23), ymd(2019, 12, 2), Duration::weeks(-1));
+        assert_eq!(range.size_hint(), (3, Some(3)));
     }
 
     #[test]
-    fn test_date_weekday() {
-        assert_eq!(NaiveDate::from_ymd(1582, 10, 15).weekday(), Weekday::Fri);
-        // May 20, 1875 = ISO 8601 reference date
-        assert_eq!(NaiveDate::from_ymd(1875, 5, 20).weekday(), Weekday::Thu);
-        assert_eq!(NaiveDate::from_ymd(2000, 1, 1).weekday(), Weekday::Sat);
+    #[should_panic]
+    fn test_date_range_zero_step() {
+        let _ = NaiveDate::range(MIN_DATE, MAX_DATE, Duration::hours(23));
     }
 
     #[test]
-    fn test_date_with_fields() {
-        let d = NaiveDate::from_ymd(2000, 2, 29);
-        assert_eq!(d.with_year(-400), Some(NaiveDate::from_ymd(-400, 2, 29)));
-        assert_eq!(d.with_year(-100), None);
-        assert_eq!(d.with_year(1600), Some(NaiveDate::from_ymd(1600, 2, 29)));
-        assert_eq!(d.with_year(1900), None);
-        assert_eq!(d.with_year(2000), Some(NaiveDate::from_ymd(2000, 2, 29)));
-        assert_eq!(d.with_year(2001), None);
-        assert_eq!(d.with_year(2004), Some(NaiveDate::from_ymd(2004, 2, 29)));
-        assert_eq!(d.with_year(i32::MAX), None);
+    fn test_date_range_step() {
+        let ymd = NaiveDate::from_ymd;
+        let collect = |start, end, step| {
+            let range = NaiveDate::range_step(start, end, step);
+            let len = range.len();
+            let forward = range.clone().collect::<Vec<_>>();
+            let mut backward = range.rev().collect::<Vec<_>>();
+            backward.reverse();
+            assert_eq!(forward, backward);
+            assert_eq!(forward.len(), len);
+            // same dates as `NaiveDate::range` with a whole number of days
+            assert_eq!(
+                forward,
+                NaiveDate::range(start, end, step).collect::<Vec<_>>()
+            );
+            forward
+        };
 
-        let d = NaiveDate::from_ymd(2000, 4, 30);
-        assert_eq!(d.with_month(0), None);
-        assert_eq!(d.with_month(1), Some(NaiveDate::from_ymd(2000, 1, 30)));
-        assert_eq!(d.with_month(2), None);
-        assert_eq!(d.with_month(3), Some(NaiveDate::from_ymd(2000, 3, 30)));
-        assert_eq!(d.with_month(4), Some(NaiveDate::from_ymd(2000, 4, 30)));
-        assert_eq!(d.with_month(12), Some(NaiveDate::from_ymd(2000, 12, 30)));
-        assert_eq!(d.with_month(13), None);
-        assert_eq!(d.with_month(u32::MAX), None);
+        assert_eq!(
+            collect(ymd(2019, 12, 30), ymd(2020, 1, 2), Duration::days(1)),
+            [ymd(2019, 12, 30), ymd(2019, 12, 31), ymd(2020, 1, 1)]
+        );
+        assert_eq!(
+            collect(ymd(2019, 12, 2), ymd(2019, 12, 24), Duration::weeks(1)),
+            [
+                ymd(2019, 12, 2),
+                ymd(2019, 12, 9),
+                ymd(2019, 12, 16),
+                ymd(2019, 12, 23)
+            ]
+        );
+        assert_eq!(
+            collect(ymd(2020, 1, 2), ymd(2019, 12, 29), Duration::days(-2)),
+            [ymd(2020, 1, 2), ymd(2019, 12, 31)]
+        );
+        assert!(collect(ymd(2020, 1, 1), ymd(2020, 1, 1), Duration::days(1)).is_empty());
+        assert!(collect(ymd(2020, 1, 2), ymd(2020, 1, 1), Duration::days(1)).is_empty());
+        assert!(collect(ymd(2020, 1, 1), ymd(2020, 1, 2), Duration::days(-1)).is_empty());
 
-        let d = NaiveDate::from_ymd(2000, 2, 8);
-        assert_eq!(d.with_day(0), None);
-        assert_eq!(d.with_day(1), Some(NaiveDate::from_ymd(2000, 2, 1)));
-        assert_eq!(d.with_day(29), Some(NaiveDate::from_ymd(2000, 2, 29)));
-        assert_eq!(d.with_day(30), None);
-        assert_eq!(d.with_day(u32::MAX), None);
+        // stops at the bounds of the supported dates
+        assert_eq!(
+            collect(MAX_DATE.pred(), MAX_DATE, Duration::days(1)),
+            [MAX_DATE.pred()]
+        );
+        assert_eq!(
+            collect(MIN_DATE, MAX_DATE, Duration::days(i32::MAX as i64)),
+            [MIN_DATE]
+        );
+        assert_eq!(
+            collect(MIN_DATE.succ(), MIN_DATE, Duration::days(-3)),
+            [MIN_DATE.succ()]
+        );
 
-        let d = NaiveDate::from_ymd(2000, 5, 5);
-        assert_eq!(d.with_ordinal(0), None);
-        assert_eq!(d.with_ordinal(1), Some(NaiveDate::from_ymd(2000, 1, 1)));
-        assert_eq!(d.with_ordinal(60), Some(NaiveDate::from_ymd(2000, 2, 29)));
-        assert_eq!(d.with_ordinal(61), Some(NaiveDate::from_ymd(2000, 3, 1)));
-        assert_eq!(d.with_ordinal(366), Some(NaiveDate::from_ymd(2000, 12, 31)));
-        assert_eq!(d.with_ordinal(367), None);
-        assert_eq!(d.with_ordinal(u32::MAX), None);
+        let days = MAX_DATE.signed_duration_since(MIN_DATE).num_days();
+        let mut range = NaiveDate::range_step(MIN_DATE, MAX_DATE, Duration::days(1));
+        assert_eq!(range.len() as i64, days);
+        assert_eq!(range.next_back(), Some(MAX_DATE.pred()));
+        assert_eq!(range.nth(1), Some(MIN_DATE.succ()));
+        assert_eq!(range.len() as i64, days - 3);
+        assert_eq!(range.nth(days as usize), None);
+        assert_eq!(range.len(), 0);
+        assert_eq!(range.next_back(), None);
     }
 
     #[test]
-    fn test_date_num_days_from_ce() {
-        assert_eq!(NaiveDate::from_ymd(1, 1, 1).num_days_from_ce(), 1);
-
-        for year in -9999..10001 {
-            assert_eq!(
-                NaiveDate::from_ymd(year, 1, 1).num_days_from_ce(),
-                NaiveDate::from_ymd(year - 1, 12, 31).num_days_from_ce() + 1
-            );
-        }
+    #[should_panic(expected = "whole number of days")]
+    fn test_date_range_step_partial_day() {
+        let _ = NaiveDate::range_step(MIN_DATE, MAX_DATE, Duration::hours(36));
     }
 
     #[test]
-    fn test_date_succ() {
-        let ymd = |y, m, d| NaiveDate::from_ymd(y, m, d);
-        assert_eq!(ymd(2014, 5, 6).succ_opt(), Some(ymd(2014, 5, 7)));
-        assert_eq!(ymd(2014, 5, 31).succ_opt(), Some(ymd(2014, 6, 1)));
-        assert_eq!(ymd(2014, 12, 31).succ_opt(), Some(ymd(2015, 1, 1)));
-        assert_eq!(ymd(2016, 2, 28).succ_opt(), Some(ymd(2016, 2, 29)));
-        assert_eq!(ymd(MAX_DATE.year(), 12, 31).succ_opt(), None);
+    #[should_panic(expected = "whole number of days")]
+    fn test_date_range_step_zero() {
+        let _ = NaiveDate::range_step(MIN_DATE, MAX_DATE, Duration::zero());
     }
 
     #[test]
-    fn test_date_pred() {
-        let ymd = |y, m, d| NaiveDate::from_ymd(y, m, d);
-        assert_eq!(ymd(2016, 3, 1).pred_opt(), Some(ymd(2016, 2, 29)));
-        assert_eq!(ymd(2015, 1, 1).pred_opt(), Some(ymd(2014, 12, 31)));
-        assert_eq!(ymd(2014, 6, 1).pred_opt(), Some(ymd(2014, 5, 31)));
-        assert_eq!(ymd(2014, 5, 7).pred_opt(), Some(ymd(2014, 5, 6)));
-        assert_eq!(ymd(MIN_DATE.year(), 1, 1).pred_opt(), None);
+    fn test_date_checked_add_sub_signed_extremes() {
+        let d = NaiveDate::from_ymd(2019, 12, 31);
+        for &rhs in &[
+            Duration::max_value(),
+            Duration::min_value(),
+            Duration::days(i64::from(i32::MAX) + 1),
+            Duration::days(-i64::from(i32::MAX) - 1),
+            Duration::days(MAX_DAYS_FROM_YEAR_0 as i64 * 2),
+            Duration::days(-(MAX_DAYS_FROM_YEAR_0 as i64) * 2),
+        ] {
+            assert_eq!(d.checked_add_signed(rhs), None, "{} + {}", d, rhs);
+            assert_eq!(d.checked_sub_signed(rhs), None, "{} - {}", d, rhs);
+        }
+
+        // a scheduler stepping far into the future stops cleanly at the end of the range
+        let mut date = d;
+        let mut steps = 0;
+        while let Some(next) = date.checked_add_signed(Duration::weeks(52 * 1000)) {
+            date = next;
+            steps += 1;
+        }
+        assert!(date.year() > MAX_YEAR - 1000);
+        assert_eq!(
+            date.checked_sub_signed(Duration::weeks(52 * 1000 * steps as i64)),
+            Some(d)
+        );
     }
 
     #[test]
@@ -2163,6 +4237,270 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_date_business_days_until() {
+        fn check((y1, m1, d1): (i32, u32, u32), (y2, m2, d2): (i32, u32, u32), days: i64) {
+            let lhs = NaiveDate::from_ymd(y1, m1, d1);
+            let rhs = NaiveDate::from_ymd(y2, m2, d2);
+            assert_eq!(lhs.business_days_until(rhs, &[]), days);
+            assert_eq!(rhs.business_days_until(lhs, &[]), -days);
+        }
+
+        check((2019, 12, 2), (2019, 12, 2), 0);
+        check((2019, 12, 2), (2019, 12, 3), 1); // Mon..Tue
+        check((2019, 12, 6), (2019, 12, 9), 1); // Fri..Mon
+        check((2019, 12, 7), (2019, 12, 9), 0); // Sat..Mon
+        check((2019, 12, 7), (2019, 12, 10), 1); // Sat..Tue
+        check((2019, 12, 4), (2019, 12, 14), 8); // Wed..Sat
+        check((2019, 12, 2), (2019, 12, 30), 20);
+        check((2019, 1, 1), (2020, 1, 1), 261);
+        check((2000, 1, 1), (2400, 1, 1), 146_097 / 7 * 5);
+
+        let ymd = NaiveDate::from_ymd;
+        let holidays = [
+            ymd(2019, 12, 21), // Saturday, ignored
+            ymd(2019, 12, 24),
+            ymd(2019, 12, 25),
+            ymd(2019, 12, 31),
+            ymd(2020, 1, 1),
+        ];
+        assert_eq!(
+            ymd(2019, 12, 16).business_days_until(ymd(2020, 1, 6), &holidays),
+            11
+        );
+        assert_eq!(
+            ymd(2020, 1, 6).business_days_until(ymd(2019, 12, 16), &holidays),
+            -11
+        );
+        assert_eq!(
+            ymd(2019, 12, 24).business_days_until(ymd(2019, 12, 26), &holidays),
+            0
+        );
+        assert_eq!(
+            ymd(2019, 12, 23).business_days_until(ymd(2019, 12, 24), &holidays),
+            1
+        );
+        assert_eq!(
+            ymd(2019, 12, 26).business_days_until(ymd(2019, 12, 31), &holidays),
+            3
+        );
+        assert_eq!(
+            ymd(2020, 1, 2).business_days_until(ymd(2020, 1, 3), &holidays),
+            1
+        );
+    }
+
+    #[test]
+    fn test_date_age_at() {
+        fn check(
+            (y1, m1, d1): (i32, u32, u32),
+            (y2, m2, d2): (i32, u32, u32),
+            age: (u32, u32, u32),
+        ) {
+            let from = NaiveDate::from_ymd(y1, m1, d1);
+            let to = NaiveDate::from_ymd(y2, m2, d2);
+            let (years, months, days) = age;
+            assert_eq!(
+                from.age_at(to),
+                Some(Age {
+                    years: years,
+                    months: months,
+                    days: days
+                })
+            );
+            assert_eq!(to.years_since(from), Some(years));
+            assert_eq!(
+                from + Months(years * 12 + months) + Days(u64::from(days)),
+                to
+            );
+        }
+
+        check((2000, 1, 1), (2000, 1, 1), (0, 0, 0));
+        check((2000, 1, 1), (2000, 1, 31), (0, 0, 30));
+        check((2000, 1, 1), (2000, 2, 1), (0, 1, 0));
+        check((2000, 1, 31), (2000, 2, 28), (0, 0, 28));
+        check((2000, 1, 31), (2000, 2, 29), (0, 1, 0));
+        check((2000, 1, 31), (2000, 3, 30), (0, 1, 30));
+        check((2000, 1, 31), (2000, 3, 31), (0, 2, 0));
+        check((1980, 12, 25), (2019, 12, 24), (38, 11, 29));
+        check((1980, 12, 25), (2019, 12, 25), (39, 0, 0));
+        check((-1, 6, 15), (1, 6, 14), (1, 11, 30));
+
+        // February 29
+        check((2000, 2, 29), (2001, 2, 27), (0, 11, 29));
+        check((2000, 2, 29), (2001, 2, 28), (1, 0, 0));
+        check((2000, 2, 29), (2001, 3, 1), (1, 0, 1));
+        check((2000, 2, 29), (2004, 2, 28), (3, 11, 30));
+        check((2000, 2, 29), (2004, 2, 29), (4, 0, 0));
+        check((2000, 2, 29), (2100, 2, 28), (100, 0, 0));
+
+        check(
+            (MIN_YEAR, 1, 1),
+            (MAX_YEAR, 12, 31),
+            ((MAX_YEAR - MIN_YEAR) as u32, 11, 30),
+        );
+
+        let ymd = NaiveDate::from_ymd;
+        assert_eq!(ymd(2000, 1, 2).age_at(ymd(2000, 1, 1)), None);
+        assert_eq!(ymd(2000, 1, 1).years_since(ymd(2000, 1, 2)), None);
+    }
+
+    #[test]
+    fn test_date_diff_calendar_months_and_years() {
+        let ymd = NaiveDate::from_ymd;
+        let check = |(y1, m1, d1), (y2, m2, d2), months: i32| {
+            let date = ymd(y1, m1, d1);
+            let other = ymd(y2, m2, d2);
+            assert_eq!(date.diff_calendar_months(other), months);
+            assert_eq!(other.diff_calendar_months(date), -months);
+            assert_eq!(date.diff_calendar_years(other), months / 12);
+            assert_eq!(other.diff_calendar_years(date), -months / 12);
+        };
+
+        check((2000, 1, 1), (2000, 1, 1), 0);
+        check((2000, 1, 31), (2000, 1, 1), 0);
+        check((2000, 2, 1), (2000, 1, 1), 1);
+        check((2000, 2, 28), (2000, 1, 31), 0);
+        check((2000, 2, 29), (2000, 1, 31), 1);
+        check((2000, 3, 30), (2000, 1, 31), 1);
+        check((2000, 3, 31), (2000, 1, 31), 2);
+        check((2001, 2, 28), (2000, 2, 29), 12);
+        check((2020, 1, 14), (2019, 12, 15), 0);
+        check((2020, 1, 15), (2019, 12, 15), 1);
+        check((2020, 12, 14), (2019, 12, 15), 11);
+        check((2020, 12, 15), (2019, 12, 15), 12);
+        check((1, 6, 14), (-1, 6, 15), 23);
+        check((1, 6, 15), (-1, 6, 15), 24);
+        check((2019, 12, 24), (1980, 12, 25), 38 * 12 + 11);
+        check((2019, 12, 25), (1980, 12, 25), 39 * 12);
+        check((2001, 2, 27), (2000, 2, 29), 11);
+        check((2004, 2, 28), (2000, 2, 29), 3 * 12 + 11);
+        check((2004, 2, 29), (2000, 2, 29), 4 * 12);
+        check(
+            (MAX_YEAR, 12, 31),
+            (MIN_YEAR, 1, 1),
+            (MAX_YEAR - MIN_YEAR) * 12 + 11,
+        );
+    }
+
+    #[test]
+    fn test_date_add_business_days() {
+        let ymd = NaiveDate::from_ymd;
+        let d = ymd(2019, 12, 4); // Wednesday
+        assert_eq!(d.add_business_days(0, &[]), d);
+        assert_eq!(d.add_business_days(2, &[]), ymd(2019, 12, 6));
+        assert_eq!(d.add_business_days(3, &[]), ymd(2019, 12, 9));
+        assert_eq!(d.add_business_days(10, &[]), ymd(2019, 12, 18));
+        assert_eq!(d.add_business_days(-2, &[]), ymd(2019, 12, 2));
+        assert_eq!(d.add_business_days(-3, &[]), ymd(2019, 11, 29));
+        assert_eq!(d.add_business_days(260, &[]), ymd(2020, 12, 2));
+
+        // weekends are never returned unless `days` is zero
+        let sat = ymd(2019, 12, 7);
+        assert_eq!(sat.add_business_days(0, &[]), sat);
+        assert_eq!(sat.add_business_days(1, &[]), ymd(2019, 12, 9));
+        assert_eq!(sat.add_business_days(-1, &[]), ymd(2019, 12, 6));
+
+        let holidays = [
+            ymd(2019, 12, 21), // Saturday, ignored
+            ymd(2019, 12, 24),
+            ymd(2019, 12, 25),
+            ymd(2019, 12, 31),
+            ymd(2020, 1, 1),
+        ];
+        assert_eq!(
+            ymd(2019, 12, 23).add_business_days(1, &holidays),
+            ymd(2019, 12, 26)
+        );
+        assert_eq!(
+            ymd(2019, 12, 26).add_business_days(-1, &holidays),
+            ymd(2019, 12, 23)
+        );
+        assert_eq!(
+            ymd(2019, 12, 16).add_business_days(11, &holidays),
+            ymd(2020, 1, 6)
+        );
+        assert_eq!(
+            ymd(2020, 1, 6).add_business_days(-11, &holidays),
+            ymd(2019, 12, 16)
+        );
+        for n in -30..30 {
+            let start = ymd(2019, 12, 16);
+            let end = start.add_business_days(n, &holidays);
+            assert_eq!(start.business_days_until(end, &holidays), i64::from(n));
+        }
+    }
+
+    #[test]
+    fn test_date_add_days() {
+        let ymd = NaiveDate::from_ymd;
+        assert_eq!(ymd(2014, 1, 1) + Days(0), ymd(2014, 1, 1));
+        assert_eq!(ymd(2014, 1, 1) + Days(364), ymd(2014, 12, 31));
+        assert_eq!(ymd(2014, 1, 1) + Days(365 * 400 + 97), ymd(2414, 1, 1));
+        assert_eq!(ymd(2014, 1, 1) - Days(1), ymd(2013, 12, 31));
+        assert_eq!(ymd(1, 1, 1) - Days(366), ymd(0, 1, 1));
+        assert_eq!(
+            MIN_DATE + Days((MAX_DAYS_FROM_YEAR_0 - MIN_DAYS_FROM_YEAR_0) as u64),
+            MAX_DATE
+        );
+
+        assert_eq!(MAX_DATE.checked_add_days(Days(1)), None);
+        assert_eq!(MIN_DATE.checked_sub_days(Days(1)), None);
+        assert_eq!(MIN_DATE.checked_sub_days(Days(0)), Some(MIN_DATE));
+        assert_eq!(MAX_DATE.checked_sub_days(Days(u64::from(u32::MAX))), None);
+        assert_eq!(MIN_DATE.checked_add_days(Days(u64::max_value())), None);
+        assert_eq!(MAX_DATE.checked_sub_days(Days(u64::max_value())), None);
+    }
+
+    #[test]
+    fn test_date_checked_days_overflow() {
+        let d = NaiveDate::from_ymd(2019, 1, 1);
+        for &days in &[u64::max_value(), i64::MAX as u64, i64::MAX as u64 + 1] {
+            assert_eq!(d.checked_add_days(Days(days)), None);
+            assert_eq!(d.checked_sub_days(Days(days)), None);
+        }
+    }
+
+    #[test]
+    fn test_date_add_months() {
+        fn check((y1, m1, d1): (i32, u32, u32), months: u32, (y2, m2, d2): (i32, u32, u32)) {
+            let lhs = NaiveDate::from_ymd(y1, m1, d1);
+            let rhs = NaiveDate::from_ymd(y2, m2, d2);
+            assert_eq!(lhs.checked_add_months(Months(months)), Some(rhs));
+            assert_eq!(lhs + Months(months), rhs);
+        }
+
+        check((2014, 1, 1), 0, (2014, 1, 1));
+        check((2014, 1, 31), 1, (2014, 2, 28));
+        check((2016, 1, 31), 1, (2016, 2, 29));
+        check((2014, 1, 31), 2, (2014, 3, 31));
+        check((2014, 1, 31), 3, (2014, 4, 30));
+        check((2014, 12, 15), 1, (2015, 1, 15));
+        check((2014, 12, 31), 14, (2016, 2, 29));
+        check((-1, 12, 31), 2, (0, 2, 29));
+        check((2000, 2, 29), 1200, (2100, 2, 28));
+        check(
+            (MIN_YEAR, 1, 1),
+            (MAX_YEAR - MIN_YEAR) as u32 * 12 + 11,
+            (MAX_YEAR, 12, 1),
+        );
+
+        let ymd = NaiveDate::from_ymd;
+        assert_eq!(
+            ymd(2014, 3, 31).checked_sub_months(Months(1)),
+            Some(ymd(2014, 2, 28))
+        );
+        assert_eq!(ymd(2014, 3, 31) - Months(13), ymd(2013, 2, 28));
+        assert_eq!(ymd(2014, 1, 15) - Months(1), ymd(2013, 12, 15));
+        assert_eq!(ymd(1, 1, 1) - Months(12), ymd(0, 1, 1));
+        assert_eq!(ymd(1, 1, 1) - Months(13), ymd(-1, 12, 1));
+
+        assert_eq!(MAX_DATE.checked_add_months(Months(1)), None);
+        assert_eq!(MIN_DATE.checked_sub_months(Months(1)), None);
+        assert_eq!(MAX_DATE.checked_sub_months(Months(u32::MAX)), None);
+        assert_eq!(MIN_DATE.checked_add_months(Months(u32::MAX)), None);
+    }
+
     #[test]
     fn test_date_addassignment() {
         let ymd = NaiveDate::from_ymd;
@@ -2265,6 +4603,15 @@ mod tests {
         assert!("2014-01-00".parse::<NaiveDate>().is_err());
         assert!("2014-13-57".parse::<NaiveDate>().is_err());
         assert!("9999999-9-9".parse::<NaiveDate>().is_err()); // out-of-bounds
+        assert!("2015-06-30x".parse::<NaiveDate>().is_err()); // trailing characters
+        assert!("2015-06-30T00:00:00".parse::<NaiveDate>().is_err());
+        assert!("2015-02-29".parse::<NaiveDate>().is_err());
+
+        // the canonical form
+        assert_eq!(
+            "2015-06-30".parse::<NaiveDate>(),
+            Ok(NaiveDate::from_ymd(2015, 6, 30))
+        );
     }
 
     #[test]
@@ -2282,11 +4629,100 @@ mod tests {
             NaiveDate::parse_from_str("Fri, 09 Aug 13", "%a, %d %b %y"),
             Ok(ymd(2013, 8, 9))
         );
+        assert_eq!(
+            NaiveDate::parse_from_str("March 3rd, 2015", "%B %-d%o, %Y"),
+            Ok(ymd(2015, 3, 3))
+        );
         assert!(NaiveDate::parse_from_str("Sat, 09 Aug 2013", "%a, %d %b %Y").is_err());
         assert!(NaiveDate::parse_from_str("2014-57", "%Y-%m-%d").is_err());
         assert!(NaiveDate::parse_from_str("2014", "%Y").is_err()); // insufficient
     }
 
+    #[test]
+    fn test_date_parse_century_and_ordinal() {
+        let parse = |s| NaiveDate::parse_from_str(s, "%y%j");
+        assert_eq!(parse("60123"), Ok(NaiveDate::from_yo(2060, 123)));
+        assert_eq!(parse("70123"), Ok(NaiveDate::from_yo(1970, 123)));
+        assert_eq!(parse("00366"), Ok(NaiveDate::from_ymd(2000, 12, 31)));
+        assert!(parse("01366").is_err()); // 2001 is not a leap year
+        assert!(parse("01000").is_err());
+        assert!(parse("01367").is_err());
+
+        // `%C` overrides the two-digit year pivot
+        let parse = |s| NaiveDate::parse_from_str(s, "%C%y%j");
+        assert_eq!(parse("1960123"), Ok(NaiveDate::from_yo(1960, 123)));
+        assert_eq!(parse("2070123"), Ok(NaiveDate::from_yo(2070, 123)));
+        assert_eq!(parse("1900365"), Ok(NaiveDate::from_ymd(1900, 12, 31)));
+        assert!(parse("1900366").is_err()); // 1900 is not a leap year
+        assert_eq!(parse("2000366"), Ok(NaiveDate::from_ymd(2000, 12, 31)));
+
+        for &(y, o) in &[(1960, 1), (1999, 365), (2024, 366), (2069, 200)] {
+            let d = NaiveDate::from_yo(y, o);
+            let s = d.format("%C%y%j").to_string();
+            assert_eq!(NaiveDate::parse_from_str(&s, "%C%y%j"), Ok(d));
+        }
+
+        // all given fields should be consistent
+        assert!(NaiveDate::parse_from_str("1960123 1960-05-02", "%C%y%j %Y-%m-%d").is_ok());
+        assert!(NaiveDate::parse_from_str("1960123 2060-05-02", "%C%y%j %Y-%m-%d").is_err());
+        assert!(NaiveDate::parse_from_str("1960123 05-03", "%C%y%j %m-%d").is_err());
+    }
+
+    #[test]
+    fn test_date_parse_week_from_sun_and_mon() {
+        // (date, "%Y %U %w", "%Y %W %u") as printed by GNU `date`
+        let cases = [
+            ((2000, 1, 1), "2000 00 6", "2000 00 6"),
+            ((2000, 1, 2), "2000 01 0", "2000 00 7"),
+            ((2005, 1, 1), "2005 00 6", "2005 00 6"),
+            ((2005, 1, 2), "2005 01 0", "2005 00 7"),
+            ((2005, 1, 3), "2005 01 1", "2005 01 1"),
+            ((2006, 1, 1), "2006 01 0", "2006 00 7"),
+            ((2007, 1, 1), "2007 00 1", "2007 01 1"),
+            ((2012, 12, 31), "2012 53 1", "2012 53 1"),
+            ((2017, 12, 31), "2017 53 0", "2017 52 7"),
+            ((2018, 12, 31), "2018 52 1", "2018 53 1"),
+            ((2020, 12, 31), "2020 52 4", "2020 52 4"),
+            ((2021, 1, 3), "2021 01 0", "2021 00 7"),
+        ];
+        for &((y, m, d), from_sun, from_mon) in &cases {
+            let date = NaiveDate::from_ymd(y, m, d);
+            assert_eq!(date.format("%Y %U %w").to_string(), from_sun);
+            assert_eq!(date.format("%Y %W %u").to_string(), from_mon);
+            assert_eq!(NaiveDate::parse_from_str(from_sun, "%Y %U %w"), Ok(date));
+            assert_eq!(NaiveDate::parse_from_str(from_mon, "%Y %W %u"), Ok(date));
+        }
+
+        // week 53 only exists when it stays within the year
+        assert!(NaiveDate::parse_from_str("2017 53 1", "%Y %U %w").is_err());
+        assert!(NaiveDate::parse_from_str("2018 53 2", "%Y %W %u").is_err());
+        assert!(NaiveDate::parse_from_str("2005 00 5", "%Y %U %w").is_err());
+        assert!(NaiveDate::parse_from_str("2007 00 1", "%Y %W %u").is_err());
+        assert!(NaiveDate::parse_from_str("2000 54 0", "%Y %U %w").is_err());
+
+        // inconsistent combinations
+        assert_eq!(
+            NaiveDate::parse_from_str("2000 01 00 0", "%Y %U %W %w"),
+            Ok(NaiveDate::from_ymd(2000, 1, 2))
+        );
+        assert!(NaiveDate::parse_from_str("2000 01 01 0", "%Y %U %W %w").is_err());
+        assert!(NaiveDate::parse_from_str("2000 01 0 01-03", "%Y %U %w %m-%d").is_err());
+    }
+
+    #[test]
+    fn test_date_month_and_weekday_names() {
+        for month in 1..13 {
+            let d = NaiveDate::from_ymd(2020, month, 1);
+            assert_eq!(d.month_name(), d.format("%B").to_string());
+        }
+        for day in 1..8 {
+            let d = NaiveDate::from_ymd(2020, 6, day);
+            assert_eq!(d.weekday_name(), d.format("%A").to_string());
+        }
+        assert_eq!(MIN_DATE.month_name(), "January");
+        assert_eq!(MAX_DATE.weekday_name(), MAX_DATE.format("%A").to_string());
+    }
+
     #[test]
     fn test_date_format() {
         let d = NaiveDate::from_ymd(2012, 3, 4);
@@ -2301,6 +4737,22 @@ mod tests {
         assert_eq!(d.format("%v").to_string(), " 4-Mar-2012");
         assert_eq!(d.format("%t%n%%%n%t").to_string(), "\t\n%\n\t");
 
+        // ordinal suffixes
+        let suffixed: Vec<String> = (1..32)
+            .map(|day| {
+                NaiveDate::from_ymd(2015, 1, day)
+                    .format("%-d%o")
+                    .to_string()
+            })
+            .collect();
+        assert_eq!(
+            suffixed.join(" "),
+            "1st 2nd 3rd 4th 5th 6th 7th 8th 9th 10th 11th 12th 13th 14th 15th 16th \
+             17th 18th 19th 20th 21st 22nd 23rd 24th 25th 26th 27th 28th 29th 30th 31st"
+        );
+        assert_eq!(d.format("%B %-d%o, %Y").to_string(), "March 4th, 2012");
+        assert_eq!(d.format("%o").to_string(), d.day_ordinal_string()[1..]);
+
         // non-four-digit years
         assert_eq!(
             NaiveDate::from_ymd(12345, 1, 1).format("%Y").to_string(),
@@ -2352,7 +4804,7 @@ mod tests {
             NaiveDate::from_ymd(2007, 12, 31)
                 .format("%G,%g,%U,%W,%V")
                 .to_string(),
-            "2008,08,53,53,01"
+            "2008,08,52,53,01"
         );
         assert_eq!(
             NaiveDate::from_ymd(2010, 1, 3)
@@ -12,7 +12,8 @@ use div::div_mod_floor;
 use format::{parse, DelayedFormat, ParseError, ParseResult, Parsed, StrftimeItems};
 use format::{Fixed, Item, Numeric, Pad};
 use naive::{IsoWeek, NaiveDate, NaiveTime};
-use {Datelike, Timelike, Weekday};
+use offset::{LocalResult, TimeZone, Utc};
+use {DateTime, Datelike, Days, Months, Timelike, Weekday};
 
 /// The tight upper bound guarantees that a duration with `|Duration| >= 2^MAX_SECS_BITS`
 /// will always overflow the addition with any date and time type.
@@ -22,6 +23,9 @@ use {Datelike, Timelike, Weekday};
 /// touching that call when we are already sure that it WILL overflow...
 const MAX_SECS_BITS: usize = 44;
 
+/// The Julian Date of the Modified Julian Date epoch, November 17, 1858 00:00 UTC.
+const MJD_EPOCH_IN_JD: f64 = 2_400_000.5;
+
 /// ISO 8601 combined date and time without timezone.
 ///
 /// # Example
@@ -151,6 +155,135 @@ impl NaiveDateTime {
         }
     }
 
+    /// Makes a new `NaiveDateTime` from the [Modified Julian Date][mjd] (MJD)
+    /// with a fraction of a day, the number of days since November 17, 1858 00:00 UTC.
+    ///
+    /// The fraction is rounded to the nearest nanosecond,
+    /// but note that an `f64` MJD in the modern era is only precise to about a microsecond.
+    /// Returns `None` when `mjd` is not finite or the date would be out of range.
+    ///
+    /// [mjd]: https://en.wikipedia.org/wiki/Julian_day#Variants
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDate, NaiveDateTime};
+    ///
+    /// assert_eq!(NaiveDateTime::from_mjd(58_842.75),
+    ///            Some(NaiveDate::from_ymd(2019, 12, 25).and_hms(18, 0, 0)));
+    /// assert_eq!(NaiveDateTime::from_mjd(-0.25),
+    ///            Some(NaiveDate::from_ymd(1858, 11, 16).and_hms(18, 0, 0)));
+    /// assert_eq!(NaiveDateTime::from_mjd(::std::f64::NAN), None);
+    /// ~~~~
+    pub fn from_mjd(mjd: f64) -> Option<NaiveDateTime> {
+        const NANOS_PER_DAY: f64 = 86_400_000_000_000.0;
+
+        let mut days = mjd.floor();
+        let mut nanos = ((mjd - days) * NANOS_PER_DAY).round();
+        if nanos >= NANOS_PER_DAY {
+            // rounded up to the next midnight
+            days += 1.0;
+            nanos = 0.0;
+        }
+        let date = try_opt!(NaiveDate::from_mjd(days));
+        let nanos = try_opt!(nanos.to_u64());
+        let time = try_opt!(NaiveTime::from_num_seconds_from_midnight_opt(
+            (nanos / 1_000_000_000) as u32,
+            (nanos % 1_000_000_000) as u32
+        ));
+        Some(NaiveDateTime {
+            date: date,
+            time: time,
+        })
+    }
+
+    /// Makes a new `NaiveDateTime` from the fractional [Julian Date][jd] (JD),
+    /// the number of days since January 1, 4713 BCE 12:00 UTC in the proleptic Julian calendar.
+    ///
+    /// Julian days start at noon, so the Julian Date of a midnight ends with `.5`
+    /// and the integral Julian Date is the noon of the date with that
+    /// [Julian Day Number](./struct.NaiveDate.html#method.from_julian_day_number).
+    /// The fraction is rounded as in [`from_mjd`](#method.from_mjd),
+    /// and an `f64` JD in the modern era is only precise to about ten microseconds.
+    /// Returns `None` when `jd` is not finite or the date would be out of range.
+    ///
+    /// [jd]: https://en.wikipedia.org/wiki/Julian_day
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDate, NaiveDateTime};
+    ///
+    /// assert_eq!(NaiveDateTime::from_julian_date(2_451_545.0),
+    ///            Some(NaiveDate::from_ymd(2000, 1, 1).and_hms(12, 0, 0)));
+    /// assert_eq!(NaiveDateTime::from_julian_date(2_451_544.5),
+    ///            Some(NaiveDate::from_ymd(2000, 1, 1).and_hms(0, 0, 0)));
+    /// assert_eq!(NaiveDateTime::from_julian_date(::std::f64::INFINITY), None);
+    /// ~~~~
+    #[inline]
+    pub fn from_julian_date(jd: f64) -> Option<NaiveDateTime> {
+        NaiveDateTime::from_mjd(jd - MJD_EPOCH_IN_JD)
+    }
+
+    /// Returns the fractional [Julian Date][jd] (JD) of the date and time,
+    /// the number of days since January 1, 4713 BCE 12:00 UTC in the proleptic Julian calendar.
+    ///
+    /// Julian days start at noon, so this is the
+    /// [Julian Day Number](./struct.NaiveDate.html#method.to_julian_day_number)
+    /// of the date minus a half day, plus the fraction of the day elapsed since midnight.
+    /// A leap second is counted as the part of the preceding second.
+    ///
+    /// [jd]: https://en.wikipedia.org/wiki/Julian_day
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::from_ymd(2000, 1, 1).and_hms(12, 0, 0).to_julian_date(), 2_451_545.0);
+    /// assert_eq!(NaiveDate::from_ymd(2000, 1, 1).and_hms(0, 0, 0).to_julian_date(), 2_451_544.5);
+    /// assert_eq!(NaiveDate::from_ymd(2000, 1, 1).and_hms(18, 0, 0).to_julian_date(), 2_451_545.25);
+    /// ~~~~
+    pub fn to_julian_date(&self) -> f64 {
+        let secs = f64::from(self.time.num_seconds_from_midnight());
+        let nanos = f64::from(self.time.nanosecond() % 1_000_000_000);
+        f64::from(self.date.to_julian_day_number()) - 0.5
+            + (secs + nanos / 1_000_000_000.0) / 86_400.0
+    }
+
+    /// Returns the earliest representable date and time,
+    /// i.e. the midnight of [`NaiveDate::min_value`](./struct.NaiveDate.html#method.min_value).
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+    ///
+    /// let min = NaiveDateTime::min_value();
+    /// assert_eq!(min, NaiveDate::min_value().and_time(NaiveTime::min_value()));
+    /// ~~~~
+    #[inline]
+    pub fn min_value() -> NaiveDateTime {
+        NaiveDateTime::new(NaiveDate::min_value(), NaiveTime::min_value())
+    }
+
+    /// Returns the latest representable date and time, i.e. the
+    /// [`NaiveTime::max_value`](./struct.NaiveTime.html#method.max_value) of
+    /// [`NaiveDate::max_value`](./struct.NaiveDate.html#method.max_value).
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+    ///
+    /// let max = NaiveDateTime::max_value();
+    /// assert_eq!(max, NaiveDate::max_value().and_time(NaiveTime::max_value()));
+    /// ~~~~
+    #[inline]
+    pub fn max_value() -> NaiveDateTime {
+        NaiveDateTime::new(NaiveDate::max_value(), NaiveTime::max_value())
+    }
+
     /// Parses a string with the specified format string and returns a new `NaiveDateTime`.
     /// See the [`format::strftime` module](../format/strftime/index.html)
     /// on the supported escape sequences.
@@ -248,6 +381,47 @@ impl NaiveDateTime {
         self.time
     }
 
+    /// Makes a new `DateTime<Utc>` taking the current date and time as UTC.
+    ///
+    /// This never fails and does no conversion, as every date and time is valid in UTC.
+    /// It is same to `DateTime::<Utc>::from(self)`.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    ///
+    /// let dt = NaiveDate::from_ymd(2016, 7, 8).and_hms(9, 10, 11);
+    /// assert_eq!(dt.and_utc().to_rfc3339(), "2016-07-08T09:10:11+00:00");
+    /// assert_eq!(dt.and_utc().naive_utc(), dt);
+    /// ~~~~
+    #[inline]
+    pub fn and_utc(&self) -> DateTime<Utc> {
+        DateTime::from_utc(*self, Utc)
+    }
+
+    /// Makes a new `DateTime` taking the current date and time as the local time
+    /// in given time zone.
+    ///
+    /// This is same to [`TimeZone::from_local_datetime`](../offset/trait.TimeZone.html#method.from_local_datetime),
+    /// so the result can be `LocalResult::None` or `LocalResult::Ambiguous`
+    /// around the transitions of the time zone.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{FixedOffset, NaiveDate};
+    ///
+    /// let dt = NaiveDate::from_ymd(2016, 7, 8).and_hms(9, 10, 11);
+    /// let kst = dt.and_local(&FixedOffset::east(9 * 3600)).unwrap();
+    /// assert_eq!(kst.to_rfc3339(), "2016-07-08T09:10:11+09:00");
+    /// assert_eq!(kst.naive_local(), dt);
+    /// ~~~~
+    #[inline]
+    pub fn and_local<Tz: TimeZone>(&self, tz: &Tz) -> LocalResult<DateTime<Tz>> {
+        tz.from_local_datetime(self)
+    }
+
     /// Returns the number of non-leap seconds since the midnight on January 1, 1970.
     ///
     /// Note that this does *not* account for the timezone!
@@ -323,6 +497,8 @@ impl NaiveDateTime {
     /// (If this is a problem, please file an issue to let me know what domain
     /// needs nanosecond precision over millenia, I'm curious.)
     ///
+    /// Use [`timestamp_nanos_opt`](#method.timestamp_nanos_opt) to detect the overflow.
+    ///
     /// # Example
     ///
     /// ~~~~
@@ -347,6 +523,38 @@ impl NaiveDateTime {
         as_ns + i64::from(self.timestamp_subsec_nanos())
     }
 
+    /// Returns the number of non-leap *nanoseconds* since midnight on January 1, 1970.
+    ///
+    /// Note that this does *not* account for the timezone!
+    /// The true "UNIX timestamp" would count seconds since the midnight *UTC* on the epoch.
+    ///
+    /// Returns `None` when the number doesn't fit in `i64`,
+    /// i.e. the date and time is not between 1677-09-21T00:12:43.145224192
+    /// and 2262-04-11T23:47:16.854775807 (both inclusive).
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{Duration, NaiveDate};
+    ///
+    /// let dt = NaiveDate::from_ymd(2001, 9, 9).and_hms_nano(1, 46, 40, 555);
+    /// assert_eq!(dt.timestamp_nanos_opt(), Some(1_000_000_000_000_000_555));
+    ///
+    /// let dt = NaiveDate::from_ymd(2262, 4, 11).and_hms_nano(23, 47, 16, 854_775_807);
+    /// assert_eq!(dt.timestamp_nanos_opt(), Some(i64::max_value()));
+    /// assert_eq!((dt + Duration::nanoseconds(1)).timestamp_nanos_opt(), None);
+    /// ~~~~
+    pub fn timestamp_nanos_opt(&self) -> Option<i64> {
+        let (secs, nanos) = (self.timestamp(), i64::from(self.timestamp_subsec_nanos()));
+        // borrow a second for the negative timestamps, so that the minimum is reachable
+        let (secs, nanos) = if secs < 0 && nanos > 0 {
+            (secs + 1, nanos - 1_000_000_000)
+        } else {
+            (secs, nanos)
+        };
+        try_opt!(secs.checked_mul(1_000_000_000)).checked_add(nanos)
+    }
+
     /// Returns the number of milliseconds since the last whole non-leap second.
     ///
     /// The return value ranges from 0 to 999,
@@ -584,6 +792,98 @@ impl NaiveDateTime {
         })
     }
 
+    /// Adds given number of calendar months to the current date and time.
+    /// The time is kept as is, and the day of month is clamped to the last day of the resulting month.
+    ///
+    /// Returns `None` when the resulting date would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{Months, NaiveDate};
+    ///
+    /// let dt = NaiveDate::from_ymd(2020, 1, 31).and_hms(12, 34, 56);
+    /// assert_eq!(dt.checked_add_months(Months(1)),
+    ///            Some(NaiveDate::from_ymd(2020, 2, 29).and_hms(12, 34, 56)));
+    /// assert_eq!(dt.checked_add_months(Months(u32::max_value())), None);
+    /// ~~~~
+    pub fn checked_add_months(self, months: Months) -> Option<NaiveDateTime> {
+        let date = try_opt!(self.date.checked_add_months(months));
+        Some(NaiveDateTime {
+            date: date,
+            time: self.time,
+        })
+    }
+
+    /// Subtracts given number of calendar months from the current date and time.
+    /// The time is kept as is, and the day of month is clamped to the last day of the resulting month.
+    ///
+    /// Returns `None` when the resulting date would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{Months, NaiveDate};
+    ///
+    /// let dt = NaiveDate::from_ymd(2020, 3, 31).and_hms(12, 34, 56);
+    /// assert_eq!(dt.checked_sub_months(Months(1)),
+    ///            Some(NaiveDate::from_ymd(2020, 2, 29).and_hms(12, 34, 56)));
+    /// assert_eq!(dt.checked_sub_months(Months(u32::max_value())), None);
+    /// ~~~~
+    pub fn checked_sub_months(self, months: Months) -> Option<NaiveDateTime> {
+        let date = try_opt!(self.date.checked_sub_months(months));
+        Some(NaiveDateTime {
+            date: date,
+            time: self.time,
+        })
+    }
+
+    /// Adds given number of days to the current date and time.
+    /// The time is kept as is.
+    ///
+    /// Returns `None` when the resulting date would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{Days, NaiveDate};
+    ///
+    /// let dt = NaiveDate::from_ymd(2019, 12, 25).and_hms(12, 34, 56);
+    /// assert_eq!(dt.checked_add_days(Days(10)),
+    ///            Some(NaiveDate::from_ymd(2020, 1, 4).and_hms(12, 34, 56)));
+    /// assert_eq!(dt.checked_add_days(Days(1 << 40)), None);
+    /// ~~~~
+    pub fn checked_add_days(self, days: Days) -> Option<NaiveDateTime> {
+        let date = try_opt!(self.date.checked_add_days(days));
+        Some(NaiveDateTime {
+            date: date,
+            time: self.time,
+        })
+    }
+
+    /// Subtracts given number of days from the current date and time.
+    /// The time is kept as is.
+    ///
+    /// Returns `None` when the resulting date would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{Days, NaiveDate};
+    ///
+    /// let dt = NaiveDate::from_ymd(2020, 1, 4).and_hms(12, 34, 56);
+    /// assert_eq!(dt.checked_sub_days(Days(10)),
+    ///            Some(NaiveDate::from_ymd(2019, 12, 25).and_hms(12, 34, 56)));
+    /// assert_eq!(dt.checked_sub_days(Days(1 << 40)), None);
+    /// ~~~~
+    pub fn checked_sub_days(self, days: Days) -> Option<NaiveDateTime> {
+        let date = try_opt!(self.date.checked_sub_days(days));
+        Some(NaiveDateTime {
+            date: date,
+            time: self.time,
+        })
+    }
+
     /// Subtracts another `NaiveDateTime` from the current date and time.
     /// This does not overflow or underflow at all.
     ///
@@ -1297,6 +1597,64 @@ impl AddAssign<OldDuration> for NaiveDateTime {
     }
 }
 
+/// An addition of `Months` to `NaiveDateTime` moves the date by calendar months,
+/// keeping the time and clamping the day of month to the last day of the resulting month.
+///
+/// Panics when the resulting date would be out of range.
+/// Use [`NaiveDateTime::checked_add_months`](#method.checked_add_months) to detect that.
+impl Add<Months> for NaiveDateTime {
+    type Output = NaiveDateTime;
+
+    #[inline]
+    fn add(self, rhs: Months) -> NaiveDateTime {
+        self.checked_add_months(rhs)
+            .expect("`NaiveDateTime + Months` out of range")
+    }
+}
+
+/// A subtraction of `Months` from `NaiveDateTime` moves the date by calendar months,
+/// keeping the time and clamping the day of month to the last day of the resulting month.
+///
+/// Panics when the resulting date would be out of range.
+/// Use [`NaiveDateTime::checked_sub_months`](#method.checked_sub_months) to detect that.
+impl Sub<Months> for NaiveDateTime {
+    type Output = NaiveDateTime;
+
+    #[inline]
+    fn sub(self, rhs: Months) -> NaiveDateTime {
+        self.checked_sub_months(rhs)
+            .expect("`NaiveDateTime - Months` out of range")
+    }
+}
+
+/// An addition of `Days` to `NaiveDateTime` moves the date, keeping the time.
+///
+/// Panics when the resulting date would be out of range.
+/// Use [`NaiveDateTime::checked_add_days`](#method.checked_add_days) to detect that.
+impl Add<Days> for NaiveDateTime {
+    type Output = NaiveDateTime;
+
+    #[inline]
+    fn add(self, rhs: Days) -> NaiveDateTime {
+        self.checked_add_days(rhs)
+            .expect("`NaiveDateTime + Days` out of range")
+    }
+}
+
+/// A subtraction of `Days` from `NaiveDateTime` moves the date, keeping the time.
+///
+/// Panics when the resulting date would be out of range.
+/// Use [`NaiveDateTime::checked_sub_days`](#method.checked_sub_days) to detect that.
+impl Sub<Days> for NaiveDateTime {
+    type Output = NaiveDateTime;
+
+    #[inline]
+    fn sub(self, rhs: Days) -> NaiveDateTime {
+        self.checked_sub_days(rhs)
+            .expect("`NaiveDateTime - Days` out of range")
+    }
+}
+
 /// A subtraction of `Duration` from `NaiveDateTime` yields another `NaiveDateTime`.
 /// It is same to the addition with a negated `Duration`.
 ///
@@ -1423,6 +1781,28 @@ impl Sub<NaiveDateTime> for NaiveDateTime {
     }
 }
 
+/// Converts a `NaiveDate` into a `NaiveDateTime` at midnight (00:00:00) of that date.
+///
+/// This is same to [`NaiveDate::and_hms(0, 0, 0)`](./struct.NaiveDate.html#method.and_hms).
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::{NaiveDate, NaiveDateTime};
+///
+/// let d = NaiveDate::from_ymd(2015, 6, 3);
+/// assert_eq!(NaiveDateTime::from(d), d.and_hms(0, 0, 0));
+///
+/// let dt: NaiveDateTime = d.into();
+/// assert_eq!(dt.to_string(), "2015-06-03 00:00:00");
+/// ~~~~
+impl From<NaiveDate> for NaiveDateTime {
+    #[inline]
+    fn from(date: NaiveDate) -> NaiveDateTime {
+        date.and_time(NaiveTime::from_hms(0, 0, 0))
+    }
+}
+
 /// The `Debug` output of the naive date and time `dt` is same to
 /// [`dt.format("%Y-%m-%dT%H:%M:%S%.f")`](../format/strftime/index.html).
 ///
@@ -2258,11 +2638,11 @@ pub mod serde {
     fn test_serde_bincode() {
         // Bincode is relevant to test separately from JSON because
         // it is not self-describing.
-        use self::bincode::{deserialize, serialize, Infinite};
+        use self::bincode::{deserialize, serialize};
         use naive::NaiveDate;
 
         let dt = NaiveDate::from_ymd(2016, 7, 8).and_hms_milli(9, 10, 48, 90);
-        let encoded = serialize(&dt, Infinite).unwrap();
+        let encoded = serialize(&dt).unwrap();
         let decoded: NaiveDateTime = deserialize(&encoded).unwrap();
         assert_eq!(dt, decoded);
     }
@@ -2271,10 +2651,196 @@ pub mod serde {
 #[cfg(test)]
 mod tests {
     use super::NaiveDateTime;
-    use naive::{NaiveDate, MAX_DATE, MIN_DATE};
+    use naive::{NaiveDate, NaiveTime, MAX_DATE, MIN_DATE};
     use oldtime::Duration;
+    use std::i32;
     use std::i64;
-    use Datelike;
+    use {Datelike, Timelike};
+
+    #[test]
+    fn test_datetime_date_and_time() {
+        let d = NaiveDate::from_ymd(2015, 6, 30);
+        let t = NaiveTime::from_hms_milli(23, 59, 59, 1_500); // leap second
+        let dt = NaiveDateTime::new(d, t);
+        assert_eq!(dt.date(), d);
+        assert_eq!(dt.time(), t);
+        assert_eq!(NaiveDateTime::new(dt.date(), dt.time()), dt);
+        assert_eq!(MAX_DATE.and_hms(23, 59, 59).date(), MAX_DATE);
+        assert_eq!(
+            MIN_DATE.and_hms(0, 0, 0).time(),
+            NaiveTime::from_hms(0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_datetime_and_utc_and_local() {
+        use offset::{FixedOffset, LocalResult, TimeZone, Utc};
+        use DateTime;
+
+        let leap = NaiveDate::from_ymd(2015, 6, 30).and_hms_milli(23, 59, 59, 1_500);
+        for &dt in &[
+            NaiveDate::from_ymd(2016, 7, 8).and_hms(9, 10, 11),
+            leap,
+            MIN_DATE.and_hms(0, 0, 0),
+            NaiveDateTime::max_value(),
+        ] {
+            assert_eq!(dt.and_utc().naive_utc(), dt);
+            assert_eq!(dt.and_utc(), DateTime::<Utc>::from(dt));
+            assert_eq!(dt.and_utc(), Utc.from_utc_datetime(&dt));
+            assert_eq!(
+                dt.and_local(&Utc).map(|dt| dt.naive_local()),
+                LocalResult::Single(dt)
+            );
+        }
+
+        let kst = FixedOffset::east(9 * 3600);
+        let dt = leap.and_local(&kst).unwrap();
+        assert_eq!(dt.naive_local(), leap);
+        assert_eq!(
+            dt.naive_utc(),
+            NaiveDate::from_ymd(2015, 6, 30).and_hms_milli(14, 59, 59, 1_500)
+        );
+        assert_eq!(dt.offset(), &kst);
+    }
+
+    #[test]
+    fn test_datetime_from_naive_date() {
+        let d = NaiveDate::from_ymd(2019, 10, 3);
+        assert_eq!(NaiveDateTime::from(d), d.and_hms(0, 0, 0));
+        assert_eq!(NaiveDateTime::from(MIN_DATE), MIN_DATE.and_hms(0, 0, 0));
+        assert_eq!(NaiveDateTime::from(MAX_DATE).date(), MAX_DATE);
+        let dt: NaiveDateTime = d.into();
+        assert_eq!(dt.date(), d);
+        assert_eq!(dt.time(), NaiveTime::from_hms(0, 0, 0));
+    }
+
+    #[test]
+    fn test_datetime_checked_add_sub_signed_extremes() {
+        let dt = NaiveDate::from_ymd(2019, 12, 31).and_hms_milli(23, 59, 59, 1_500);
+        let deltas = [
+            Duration::max_value(),
+            Duration::min_value(),
+            Duration::days(i64::from(i32::MAX)),
+            Duration::days(-i64::from(i32::MAX)),
+            Duration::seconds(i64::MAX / 1000),
+            Duration::seconds(-i64::MAX / 1000),
+        ];
+        for &rhs in &deltas {
+            assert_eq!(dt.checked_add_signed(rhs), None, "{} + {}", dt, rhs);
+            assert_eq!(dt.checked_sub_signed(rhs), None, "{} - {}", dt, rhs);
+        }
+
+        // just within the range, from either end
+        // (`max_value` is a leap second, which no addition can land on)
+        let min = NaiveDateTime::min_value();
+        let max = MAX_DATE.and_hms_nano(23, 59, 59, 999_999_999);
+        let span = max.signed_duration_since(min);
+        assert_eq!(min.checked_add_signed(span), Some(max));
+        assert_eq!(max.checked_sub_signed(span), Some(min));
+        assert_eq!(
+            min.checked_add_signed(span + Duration::nanoseconds(1)),
+            None
+        );
+        assert_eq!(
+            max.checked_sub_signed(span + Duration::nanoseconds(1)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_datetime_min_max_value() {
+        let (min, max) = (NaiveDateTime::min_value(), NaiveDateTime::max_value());
+        assert_eq!(min.date(), MIN_DATE);
+        assert_eq!(max.date(), MAX_DATE);
+        assert_eq!(min.checked_sub_signed(Duration::nanoseconds(1)), None);
+        assert_eq!(max.checked_add_signed(Duration::nanoseconds(1)), None);
+        assert!(min.checked_add_signed(Duration::nanoseconds(1)).is_some());
+        assert!(max.checked_sub_signed(Duration::nanoseconds(1)).is_some());
+
+        let times = [
+            NaiveTime::from_hms(0, 0, 0),
+            NaiveTime::from_hms_nano(12, 34, 56, 789),
+            NaiveTime::from_hms_nano(23, 59, 59, 999_999_999),
+            NaiveTime::from_hms_nano(23, 59, 59, 1_999_999_999),
+        ];
+        for &t in &times {
+            assert!(NaiveTime::min_value() <= t && t <= NaiveTime::max_value());
+            assert!(min <= MIN_DATE.and_time(t) && MAX_DATE.and_time(t) <= max);
+        }
+    }
+
+    #[test]
+    fn test_datetime_julian_date() {
+        let ymdhms = |y, m, d, h, n, s| NaiveDate::from_ymd(y, m, d).and_hms(h, n, s);
+        let cases = [
+            (ymdhms(2000, 1, 1, 12, 0, 0), 2_451_545.0),
+            (ymdhms(2000, 1, 1, 0, 0, 0), 2_451_544.5),
+            (ymdhms(1999, 12, 31, 18, 0, 0), 2_451_544.25),
+            (ymdhms(1858, 11, 17, 0, 0, 0), 2_400_000.5),
+            (ymdhms(1970, 1, 1, 0, 0, 0), 2_440_587.5),
+            (ymdhms(-4713, 11, 24, 12, 0, 0), 0.0),
+            (ymdhms(-4713, 11, 24, 6, 0, 0), -0.25),
+        ];
+        for &(dt, jd) in &cases {
+            assert_eq!(dt.to_julian_date(), jd, "{}", dt);
+            assert_eq!(NaiveDateTime::from_julian_date(jd), Some(dt), "{}", jd);
+        }
+
+        // a leap second is folded into the preceding second
+        let leap = NaiveDate::from_ymd(2016, 12, 31).and_hms_milli(23, 59, 59, 1_000);
+        assert_eq!(
+            leap.to_julian_date(),
+            ymdhms(2016, 12, 31, 23, 59, 59).to_julian_date()
+        );
+
+        // precise to some tens of microseconds in the modern era
+        let dt = NaiveDate::from_ymd(2019, 12, 25).and_hms_micro(7, 8, 9, 123_456);
+        let roundtrip = NaiveDateTime::from_julian_date(dt.to_julian_date()).unwrap();
+        assert!((roundtrip - dt).num_microseconds().unwrap().abs() < 50);
+
+        assert_eq!(NaiveDateTime::from_julian_date(::std::f64::NAN), None);
+        assert_eq!(NaiveDateTime::from_julian_date(1.0e12), None);
+    }
+
+    #[test]
+    fn test_datetime_from_mjd() {
+        let ymdhms = |y, m, d, h, n, s| NaiveDate::from_ymd(y, m, d).and_hms(h, n, s);
+        assert_eq!(
+            NaiveDateTime::from_mjd(0.0),
+            Some(ymdhms(1858, 11, 17, 0, 0, 0))
+        );
+        assert_eq!(
+            NaiveDateTime::from_mjd(40_587.5),
+            Some(ymdhms(1970, 1, 1, 12, 0, 0))
+        );
+        assert_eq!(
+            NaiveDateTime::from_mjd(-0.75),
+            Some(ymdhms(1858, 11, 16, 6, 0, 0))
+        );
+        assert_eq!(
+            NaiveDateTime::from_mjd(1.0 / 86_400.0),
+            Some(ymdhms(1858, 11, 17, 0, 0, 1))
+        );
+        // rounds to the next midnight
+        assert_eq!(
+            NaiveDateTime::from_mjd(58_842.0 - 1.0e-12),
+            Some(ymdhms(2019, 12, 25, 0, 0, 0))
+        );
+        assert_eq!(NaiveDateTime::from_mjd(::std::f64::NAN), None);
+        assert_eq!(NaiveDateTime::from_mjd(::std::f64::INFINITY), None);
+        assert_eq!(NaiveDateTime::from_mjd(MAX_DATE.to_mjd() + 1.0), None);
+        assert_eq!(
+            NaiveDateTime::from_mjd(MIN_DATE.to_mjd()),
+            Some(MIN_DATE.and_hms(0, 0, 0))
+        );
+
+        let dt = NaiveDate::from_ymd(2019, 12, 25).and_hms_milli(12, 34, 56, 789);
+        let mjd = 58_842.0 + (12.0 * 3600.0 + 34.0 * 60.0 + 56.789) / 86_400.0;
+        let diff = NaiveDateTime::from_mjd(mjd)
+            .unwrap()
+            .signed_duration_since(dt);
+        assert!(diff.num_microseconds().unwrap().abs() <= 1);
+    }
 
     #[test]
     fn test_datetime_from_timestamp() {
@@ -2476,6 +3042,20 @@ mod tests {
         assert!("2012-123-12T12:12:12".parse::<NaiveDateTime>().is_err());
         assert!("+ 82701-123-12T12:12:12".parse::<NaiveDateTime>().is_err());
         assert!("+802701-123-12T12:12:12".parse::<NaiveDateTime>().is_err()); // out-of-bound
+        assert!("2015-06-30T24:00:00".parse::<NaiveDateTime>().is_err());
+        assert!("2015-06-30T23:59:59.123x".parse::<NaiveDateTime>().is_err());
+        assert!("2015-06-30".parse::<NaiveDateTime>().is_err());
+
+        // the canonical forms
+        let ymd = NaiveDate::from_ymd;
+        assert_eq!(
+            "2015-06-30T23:59:59".parse::<NaiveDateTime>(),
+            Ok(ymd(2015, 6, 30).and_hms(23, 59, 59))
+        );
+        assert_eq!(
+            "2015-06-30T23:59:59.125".parse::<NaiveDateTime>(),
+            Ok(ymd(2015, 6, 30).and_hms_milli(23, 59, 59, 125))
+        );
     }
 
     #[test]
@@ -2552,6 +3132,90 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_datetime_checked_add_sub_roundtrip() {
+        // a simple linear congruential generator, enough for scattering the values
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        let mut next = move || {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            state >> 11
+        };
+
+        let (min, max) = (
+            MIN_DATE.and_hms(0, 0, 0),
+            MAX_DATE.and_hms_nano(23, 59, 59, 999_999_999),
+        );
+        let span = max.signed_duration_since(min).num_seconds() as u64;
+        for i in 0..10_000 {
+            let secs = (next() % span) as i64;
+            let nanos = (next() % 2_000_000_000) as u32;
+            // may be a leap second
+            let a = (min + Duration::seconds(secs))
+                .with_nanosecond(nanos)
+                .unwrap();
+            let d = match i % 3 {
+                0 => Duration::nanoseconds(next() as i64 % 1_000_000_000_000),
+                1 => Duration::seconds(next() as i64 % (span as i64) - (span as i64) / 2),
+                _ => Duration::milliseconds(-(next() as i64 % 86_400_000)),
+            };
+            if let Some(sum) = a.checked_add_signed(d) {
+                assert!(min <= sum && sum <= max);
+                if let Some(back) = sum.checked_sub_signed(d) {
+                    if nanos < 1_000_000_000 {
+                        assert_eq!(back, a, "({:?} + {}) - {}", a, d, d);
+                    } else {
+                        // the leap second is lost once moved away from it, so unless it stayed
+                        // within the leap second it comes back as a neighboring non-leap second
+                        let prior = a.with_nanosecond(nanos - 1_000_000_000).unwrap();
+                        let following = prior + Duration::seconds(1);
+                        assert!(
+                            back == a || back == prior || back == following,
+                            "({:?} + {}) - {}",
+                            a,
+                            d,
+                            d
+                        );
+                    }
+                }
+            }
+        }
+
+        // the time overflow is carried into the date and fails cleanly at the bounds
+        let leap = MAX_DATE.and_hms_milli(23, 59, 59, 1_500);
+        assert_eq!(max.checked_add_signed(Duration::nanoseconds(1)), None);
+        assert_eq!(
+            leap.checked_add_signed(Duration::milliseconds(499)),
+            Some(leap + Duration::milliseconds(499))
+        );
+        assert_eq!(leap.checked_add_signed(Duration::milliseconds(500)), None);
+        assert_eq!(min.checked_sub_signed(Duration::nanoseconds(1)), None);
+        assert_eq!(min.checked_add_signed(Duration::nanoseconds(-1)), None);
+        assert_eq!(max.checked_sub_signed(max - min), Some(min));
+        assert_eq!(min.checked_add_signed(max - min), Some(max));
+        assert_eq!(min.checked_add_signed(Duration::max_value()), None);
+        assert_eq!(max.checked_sub_signed(Duration::max_value()), None);
+    }
+
+    #[test]
+    fn test_datetime_ord_leap_second() {
+        let ymdhmsn =
+            |y, m, d, h, n, s, nano| NaiveDate::from_ymd(y, m, d).and_hms_nano(h, n, s, nano);
+
+        let before = ymdhmsn(2016, 12, 31, 23, 59, 59, 999_999_999);
+        let leap = ymdhmsn(2016, 12, 31, 23, 59, 59, 1_000_000_000);
+        let leap_end = ymdhmsn(2016, 12, 31, 23, 59, 59, 1_999_999_999);
+        let next_day = ymdhmsn(2017, 1, 1, 0, 0, 0, 0);
+        assert!(before < leap);
+        assert!(leap < leap_end);
+        assert!(leap_end < next_day);
+
+        let mut events = vec![next_day, leap_end, before, leap];
+        events.sort();
+        assert_eq!(events, vec![before, leap, leap_end, next_day]);
+    }
+
     #[test]
     fn test_nanosecond_range() {
         const A_BILLION: i64 = 1_000_000_000;
@@ -2571,4 +3235,33 @@ mod tests {
             NaiveDateTime::from_timestamp(nanos / A_BILLION, (nanos % A_BILLION) as u32)
         );
     }
+
+    #[test]
+    fn test_datetime_timestamp_nanos_opt() {
+        let ymdhmsn =
+            |y, m, d, h, n, s, nano| NaiveDate::from_ymd(y, m, d).and_hms_nano(h, n, s, nano);
+        let one = Duration::nanoseconds(1);
+
+        let max = ymdhmsn(2262, 4, 11, 23, 47, 16, 854_775_807);
+        assert_eq!(max.timestamp_nanos_opt(), Some(i64::MAX));
+        assert_eq!((max - one).timestamp_nanos_opt(), Some(i64::MAX - 1));
+        assert_eq!((max + one).timestamp_nanos_opt(), None);
+
+        let min = ymdhmsn(1677, 9, 21, 0, 12, 43, 145_224_192);
+        assert_eq!(min.timestamp_nanos_opt(), Some(i64::MIN));
+        assert_eq!((min + one).timestamp_nanos_opt(), Some(i64::MIN + 1));
+        assert_eq!((min - one).timestamp_nanos_opt(), None);
+
+        for &dt in &[
+            ymdhmsn(1969, 12, 31, 23, 59, 59, 999_999_999),
+            ymdhmsn(1970, 1, 1, 0, 0, 0, 0),
+            ymdhmsn(1970, 1, 1, 0, 0, 0, 1),
+            ymdhmsn(1677, 9, 21, 0, 12, 44, 0),
+            ymdhmsn(2262, 4, 11, 23, 47, 16, 854_775_804),
+        ] {
+            assert_eq!(dt.timestamp_nanos_opt(), Some(dt.timestamp_nanos()));
+        }
+        assert_eq!(MIN_DATE.and_hms(0, 0, 0).timestamp_nanos_opt(), None);
+        assert_eq!(MAX_DATE.and_hms(23, 59, 59).timestamp_nanos_opt(), None);
+    }
 }
@@ -12,7 +12,7 @@ use super::internals::{DateImpl, Of, YearFlags};
 /// This type, combined with [`Weekday`](../enum.Weekday.html),
 /// constitues the ISO 8601 [week date](./struct.NaiveDate.html#week-date).
 /// One can retrieve this type from the existing [`Datelike`](../trait.Datelike.html) types
-/// via the [`Datelike::iso_week`](../trait.Datelike.html#tymethod.iso_week) method.
+/// via the [`Datelike::iso_week`](../trait.Datelike.html#method.iso_week) method.
 #[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
 pub struct IsoWeek {
     // note that this allows for larger year range than `NaiveDate`.
@@ -106,6 +106,28 @@ impl IsoWeek {
     pub fn week0(&self) -> u32 {
         ((self.ywf >> 4) & 0x3f) as u32 - 1
     }
+
+    /// Returns the number of ISO weeks in given ISO week-based year, either 52 or 53.
+    ///
+    /// A year has 53 weeks when it starts on Thursday,
+    /// or when it is a leap year starting on Wednesday.
+    /// This is consistent with [`week`](#method.week) and
+    /// [`NaiveDate::from_isoywd_opt`](./struct.NaiveDate.html#method.from_isoywd_opt).
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::IsoWeek;
+    ///
+    /// assert_eq!(IsoWeek::weeks_in_year(2015), 53); // starts on Thursday
+    /// assert_eq!(IsoWeek::weeks_in_year(2019), 52);
+    /// assert_eq!(IsoWeek::weeks_in_year(2020), 53); // leap year starting on Wednesday
+    /// assert_eq!(IsoWeek::weeks_in_year(2024), 52); // leap year starting on Monday
+    /// ~~~~
+    #[inline]
+    pub fn weeks_in_year(year: i32) -> u32 {
+        YearFlags::from_year(year).nisoweeks()
+    }
 }
 
 /// The `Debug` output of the ISO week `w` is same to
@@ -144,8 +166,9 @@ impl fmt::Debug for IsoWeek {
 
 #[cfg(test)]
 mod tests {
-    use naive::{internals, MAX_DATE, MIN_DATE};
-    use Datelike;
+    use super::IsoWeek;
+    use naive::{days_in_year, internals, NaiveDate, MAX_DATE, MIN_DATE};
+    use {Datelike, Weekday};
 
     #[test]
     fn test_iso_week_extremes() {
@@ -168,4 +191,35 @@ mod tests {
             MAX_DATE.format("%G-W%V").to_string()
         );
     }
+
+    #[test]
+    fn test_iso_weeks_in_year() {
+        // several 400-year cycles, which repeat the same pattern
+        for year in -1000..3000 {
+            let weeks = IsoWeek::weeks_in_year(year);
+            let jan1 = NaiveDate::from_ymd(year, 1, 1).weekday();
+            let long = jan1 == Weekday::Thu || (days_in_year(year) == 366 && jan1 == Weekday::Wed);
+            assert_eq!(weeks, if long { 53 } else { 52 }, "year {}", year);
+
+            let last = NaiveDate::from_isoywd_opt(year, weeks, Weekday::Sun).unwrap();
+            assert_eq!(last.iso_week().year(), year);
+            assert_eq!(last.iso_week().week(), weeks);
+            assert_eq!(last.succ().iso_week().week(), 1);
+            assert_eq!(
+                NaiveDate::from_isoywd_opt(year, weeks + 1, Weekday::Mon),
+                None
+            );
+            assert_eq!(last.is_in_long_iso_year(), weeks == 53);
+        }
+
+        // 71 long years in each 400-year cycle
+        let long = (2000..2400)
+            .filter(|&y| IsoWeek::weeks_in_year(y) == 53)
+            .count();
+        assert_eq!(long, 71);
+        assert_eq!(
+            IsoWeek::weeks_in_year(internals::MIN_YEAR),
+            IsoWeek::weeks_in_year(2000)
+        );
+    }
 }
@@ -4,7 +4,7 @@
 //! ISO 8601 time without timezone.
 
 use oldtime::Duration as OldDuration;
-use std::ops::{Add, AddAssign, Sub, SubAssign};
+use std::ops::{Add, AddAssign, Rem, Sub, SubAssign};
 use std::{fmt, hash, str};
 
 use div::div_mod_floor;
@@ -12,6 +12,9 @@ use format::{parse, DelayedFormat, ParseError, ParseResult, Parsed, StrftimeItem
 use format::{Fixed, Item, Numeric, Pad};
 use Timelike;
 
+/// The number of nanoseconds in a day without leap seconds.
+const NANOS_PER_DAY: i64 = 86_400 * 1_000_000_000;
+
 /// ISO 8601 time without timezone.
 /// Allows for the nanosecond precision and optional leap second representation.
 ///
@@ -56,6 +59,9 @@ use Timelike;
 /// You should use the proper formatting to get the raw leap second.
 ///
 /// All methods accepting fractional seconds will accept such values.
+/// This representation also keeps the ordering intact:
+/// a leap second sorts after every other time in the prior second
+/// and before the next non-leap second.
 ///
 /// ~~~~
 /// use chrono::{NaiveDate, NaiveTime, Utc, TimeZone};
@@ -441,6 +447,39 @@ impl NaiveTime {
         })
     }
 
+    /// Returns the earliest time of day, i.e. midnight.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveTime;
+    ///
+    /// assert_eq!(NaiveTime::min_value(), NaiveTime::from_hms(0, 0, 0));
+    /// ~~~~
+    #[inline]
+    pub fn min_value() -> NaiveTime {
+        NaiveTime { secs: 0, frac: 0 }
+    }
+
+    /// Returns the latest time of day,
+    /// which is the last nanosecond of a [leap second](#leap-second-handling) after 23:59:59.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveTime;
+    ///
+    /// assert_eq!(NaiveTime::max_value(), NaiveTime::from_hms_nano(23, 59, 59, 1_999_999_999));
+    /// assert!(NaiveTime::max_value() > NaiveTime::from_hms_nano(23, 59, 59, 999_999_999));
+    /// ~~~~
+    #[inline]
+    pub fn max_value() -> NaiveTime {
+        NaiveTime {
+            secs: 86_399,
+            frac: 1_999_999_999,
+        }
+    }
+
     /// Parses a string with the specified format string and returns a new `NaiveTime`.
     /// See the [`format::strftime` module](../format/strftime/index.html)
     /// on the supported escape sequences.
@@ -640,9 +679,13 @@ impl NaiveTime {
     }
 
     /// Subtracts another `NaiveTime` from the current time.
-    /// Returns a `Duration` within +/- 1 day.
+    /// Returns a `Duration` within +/- 1 day, both operands being in the same day;
+    /// the result is negative when the current time is earlier than `rhs`.
     /// This does not overflow or underflow at all.
     ///
+    /// When either operand is a leap second, the day has one more second
+    /// and the magnitude of the result may reach up to (but excluding) 86,401 seconds.
+    ///
     /// As a part of Chrono's [leap second handling](#leap-second-handling),
     /// the subtraction assumes that **there is no leap second ever**,
     /// except when any of the `NaiveTime`s themselves represents a leap second
@@ -737,6 +780,88 @@ impl NaiveTime {
         OldDuration::seconds(secs + adjust) + OldDuration::nanoseconds(frac)
     }
 
+    /// Rounds the time down to a multiple of `period` counted from midnight.
+    ///
+    /// The `period` doesn't have to divide a day evenly;
+    /// the multiples are always counted from the midnight of the same day.
+    /// A leap second is treated as if it coincides with the preceding second,
+    /// so the result is never a leap second.
+    ///
+    /// Panics when `period` is zero or negative.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// # extern crate chrono; extern crate time; fn main() {
+    /// use chrono::NaiveTime;
+    /// use time::Duration;
+    ///
+    /// let from_hms = NaiveTime::from_hms;
+    ///
+    /// assert_eq!(from_hms(12, 34, 56).floor_to(Duration::minutes(15)), from_hms(12, 30, 0));
+    /// assert_eq!(from_hms(12, 30, 0).floor_to(Duration::minutes(15)), from_hms(12, 30, 0));
+    /// assert_eq!(from_hms(12, 34, 56).floor_to(Duration::hours(5)), from_hms(10, 0, 0));
+    /// # }
+    /// ~~~~
+    pub fn floor_to(&self, period: OldDuration) -> NaiveTime {
+        let rem = (*self % period).num_nanoseconds().unwrap();
+        NaiveTime::from_nanos_of_day(self.nanos_of_day() - rem)
+    }
+
+    /// Rounds the time up to a multiple of `period` counted from midnight.
+    ///
+    /// The `period` doesn't have to divide a day evenly;
+    /// the multiples are always counted from the midnight of the same day.
+    /// When there is no such multiple before the end of the day,
+    /// this wraps around to the midnight (`00:00:00`).
+    /// A leap second is treated as if it coincides with the preceding second,
+    /// so the result is never a leap second.
+    ///
+    /// Panics when `period` is zero or negative.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// # extern crate chrono; extern crate time; fn main() {
+    /// use chrono::NaiveTime;
+    /// use time::Duration;
+    ///
+    /// let from_hms = NaiveTime::from_hms;
+    ///
+    /// assert_eq!(from_hms(12, 34, 56).ceil_to(Duration::minutes(15)), from_hms(12, 45, 0));
+    /// assert_eq!(from_hms(12, 30, 0).ceil_to(Duration::minutes(15)), from_hms(12, 30, 0));
+    /// assert_eq!(from_hms(23, 50, 0).ceil_to(Duration::minutes(15)), from_hms(0, 0, 0));
+    /// # }
+    /// ~~~~
+    pub fn ceil_to(&self, period: OldDuration) -> NaiveTime {
+        let rem = (*self % period).num_nanoseconds().unwrap();
+        let nanos = self.nanos_of_day();
+        if rem == 0 {
+            return NaiveTime::from_nanos_of_day(nanos);
+        }
+        match period.num_nanoseconds() {
+            Some(period) if nanos - rem + period < NANOS_PER_DAY => {
+                NaiveTime::from_nanos_of_day(nanos - rem + period)
+            }
+            _ => NaiveTime::from_nanos_of_day(0),
+        }
+    }
+
+    /// Returns the number of nanoseconds since the midnight,
+    /// treating a leap second as if it coincides with the preceding second.
+    fn nanos_of_day(&self) -> i64 {
+        i64::from(self.secs) * 1_000_000_000 + i64::from(self.frac % 1_000_000_000)
+    }
+
+    /// Makes a new `NaiveTime` from the number of nanoseconds since the midnight,
+    /// which should be in `0..NANOS_PER_DAY`.
+    fn from_nanos_of_day(nanos: i64) -> NaiveTime {
+        NaiveTime {
+            secs: (nanos / 1_000_000_000) as u32,
+            frac: (nanos % 1_000_000_000) as u32,
+        }
+    }
+
     /// Formats the time with the specified formatting items.
     /// Otherwise it is same to the ordinary [`format`](#method.format) method.
     ///
@@ -812,11 +937,45 @@ impl NaiveTime {
     }
 
     /// Returns a triple of the hour, minute and second numbers.
-    fn hms(&self) -> (u32, u32, u32) {
+    ///
+    /// As with the [`second`](#method.second) method,
+    /// the second number never exceeds 59 even for [leap seconds](#leap-second-handling).
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveTime;
+    ///
+    /// assert_eq!(NaiveTime::from_hms_nano(23, 56, 4, 12_345_678).hms(), (23, 56, 4));
+    /// assert_eq!(NaiveTime::from_hms_milli(23, 59, 59, 1_000).hms(), (23, 59, 59));
+    /// ~~~~
+    #[inline]
+    pub fn hms(&self) -> (u32, u32, u32) {
         let (mins, sec) = div_mod_floor(self.secs, 60);
         let (hour, min) = div_mod_floor(mins, 60);
         (hour, min, sec)
     }
+
+    /// Returns a quadruple of the hour, minute, second and nanosecond numbers.
+    ///
+    /// As with the [`nanosecond`](#method.nanosecond) method,
+    /// the nanosecond number can exceed 1,000,000,000 for [leap seconds](#leap-second-handling).
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::NaiveTime;
+    ///
+    /// assert_eq!(NaiveTime::from_hms_nano(23, 56, 4, 12_345_678).hms_nano(),
+    ///            (23, 56, 4, 12_345_678));
+    /// assert_eq!(NaiveTime::from_hms_milli(23, 59, 59, 1_000).hms_nano(),
+    ///            (23, 59, 59, 1_000_000_000));
+    /// ~~~~
+    #[inline]
+    pub fn hms_nano(&self) -> (u32, u32, u32, u32) {
+        let (hour, min, sec) = self.hms();
+        (hour, min, sec, self.frac)
+    }
 }
 
 impl Timelike for NaiveTime {
@@ -1256,6 +1415,51 @@ impl Sub<NaiveTime> for NaiveTime {
     }
 }
 
+/// The remainder of the time of day divided by given `Duration`,
+/// i.e. how far the time is past the last multiple of the `Duration` counted from midnight.
+/// This can be used to align the time to fixed periods;
+/// see also [`floor_to`](#method.floor_to) and [`ceil_to`](#method.ceil_to).
+///
+/// A leap second is treated as if it coincides with the preceding second.
+///
+/// Panics when the `Duration` is zero or negative.
+///
+/// # Example
+///
+/// ~~~~
+/// # extern crate chrono; extern crate time; fn main() {
+/// use chrono::NaiveTime;
+/// use time::Duration;
+///
+/// let from_hmsm = NaiveTime::from_hms_milli;
+///
+/// assert_eq!(from_hmsm(12, 34, 56, 0) % Duration::minutes(15),
+///            Duration::seconds(4 * 60 + 56));
+/// assert_eq!(from_hmsm(12, 34, 56, 789) % Duration::seconds(1),
+///            Duration::milliseconds(789));
+/// assert_eq!(from_hmsm(12, 34, 56, 0) % Duration::days(2),
+///            Duration::seconds(12 * 3600 + 34 * 60 + 56));
+/// assert_eq!(from_hmsm(23, 59, 59, 1_500) % Duration::seconds(1),
+///            Duration::milliseconds(500));
+/// # }
+/// ~~~~
+impl Rem<OldDuration> for NaiveTime {
+    type Output = OldDuration;
+
+    fn rem(self, rhs: OldDuration) -> OldDuration {
+        assert!(
+            rhs > OldDuration::zero(),
+            "`NaiveTime % Duration` with non-positive duration"
+        );
+        let nanos = self.nanos_of_day();
+        match rhs.num_nanoseconds() {
+            Some(period) => OldDuration::nanoseconds(nanos % period),
+            // much longer than a day
+            None => OldDuration::nanoseconds(nanos),
+        }
+    }
+}
+
 /// The `Debug` output of the naive time `t` is same to
 /// [`t.format("%H:%M:%S%.f")`](../format/strftime/index.html).
 ///
@@ -1309,7 +1513,14 @@ impl fmt::Debug for NaiveTime {
 /// The `Display` output of the naive time `t` is same to
 /// [`t.format("%H:%M:%S%.f")`](../format/strftime/index.html).
 ///
-/// The string printed can be readily parsed via the `parse` method on `str`.
+/// The fractional seconds are omitted when zero,
+/// and otherwise printed with the fewest of 3, 6 or 9 digits that represent them exactly
+/// (e.g. `.500`, `.123456` and `.123456789`).
+/// This depends only on the value, not on how it was made,
+/// and the `Display` of `NaiveDateTime`, `DateTime` and the serialized forms follow it.
+///
+/// The string printed can be readily parsed via the `parse` method on `str`,
+/// which accepts any number of fractional digits.
 ///
 /// It should be noted that, for leap seconds not on the minute boundary,
 /// it may print a representation not distinguishable from non-leap seconds.
@@ -1586,10 +1797,10 @@ mod serde {
     fn test_serde_bincode() {
         // Bincode is relevant to test separately from JSON because
         // it is not self-describing.
-        use self::bincode::{deserialize, serialize, Infinite};
+        use self::bincode::{deserialize, serialize};
 
         let t = NaiveTime::from_hms_nano(3, 5, 7, 98765432);
-        let encoded = serialize(&t, Infinite).unwrap();
+        let encoded = serialize(&t).unwrap();
         let decoded: NaiveTime = deserialize(&encoded).unwrap();
         assert_eq!(t, decoded);
     }
@@ -1681,6 +1892,56 @@ mod tests {
         );
         assert_eq!(NaiveTime::from_hms(3, 5, 7).with_second(60), None);
         assert_eq!(NaiveTime::from_hms(3, 5, 7).with_second(u32::MAX), None);
+
+        assert_eq!(NaiveTime::from_hms(3, 5, 7).hms(), (3, 5, 7));
+        assert_eq!(
+            NaiveTime::from_hms_nano(3, 5, 7, 9).hms_nano(),
+            (3, 5, 7, 9)
+        );
+        assert_eq!(
+            NaiveTime::from_hms_nano(23, 59, 59, 1_500_000_000).hms_nano(),
+            (23, 59, 59, 1_500_000_000)
+        );
+    }
+
+    #[test]
+    fn test_time_ord_leap_second() {
+        let hmsn = |h, m, s, n| NaiveTime::from_hms_nano(h, m, s, n);
+
+        let before = hmsn(23, 59, 59, 999_999_999);
+        let leap = hmsn(23, 59, 59, 1_000_000_000);
+        let leap_end = hmsn(23, 59, 59, 1_999_999_999);
+        assert!(hmsn(23, 59, 59, 0) < before);
+        assert!(before < leap);
+        assert!(leap < leap_end);
+
+        // a leap second not on the minute boundary still sorts before the next second
+        let odd_leap = hmsn(3, 5, 7, 1_500_000_000);
+        assert!(hmsn(3, 5, 7, 999_999_999) < odd_leap);
+        assert!(odd_leap < hmsn(3, 5, 8, 0));
+
+        let mut times = vec![
+            leap_end,
+            hmsn(3, 5, 8, 0),
+            leap,
+            hmsn(0, 0, 0, 0),
+            odd_leap,
+            before,
+            hmsn(3, 5, 7, 999_999_999),
+        ];
+        times.sort();
+        assert_eq!(
+            times,
+            vec![
+                hmsn(0, 0, 0, 0),
+                hmsn(3, 5, 7, 999_999_999),
+                odd_leap,
+                hmsn(3, 5, 8, 0),
+                before,
+                leap,
+                leap_end,
+            ]
+        );
     }
 
     #[test]
@@ -1809,6 +2070,133 @@ mod tests {
         assert_eq!(time, hms(16, 12, 12));
     }
 
+    #[test]
+    fn test_time_rem() {
+        let hmsm = |h, m, s, mi| NaiveTime::from_hms_milli(h, m, s, mi);
+
+        assert_eq!(hmsm(0, 0, 0, 0) % Duration::minutes(15), Duration::zero());
+        assert_eq!(
+            hmsm(3, 5, 7, 900) % Duration::nanoseconds(1),
+            Duration::zero()
+        );
+        assert_eq!(
+            hmsm(3, 5, 7, 900) % Duration::seconds(1),
+            Duration::milliseconds(900)
+        );
+        assert_eq!(
+            hmsm(3, 5, 7, 900) % Duration::minutes(1),
+            Duration::milliseconds(7_900)
+        );
+        assert_eq!(
+            hmsm(3, 5, 7, 900) % Duration::hours(1),
+            Duration::milliseconds(307_900)
+        );
+        assert_eq!(
+            hmsm(3, 5, 7, 900) % Duration::hours(2),
+            Duration::milliseconds(3_907_900)
+        );
+        assert_eq!(
+            hmsm(23, 59, 59, 999) % Duration::days(1),
+            Duration::milliseconds(86_399_999)
+        );
+        assert_eq!(
+            hmsm(23, 59, 59, 999) % Duration::max_value(),
+            Duration::milliseconds(86_399_999)
+        );
+        assert_eq!(
+            hmsm(23, 59, 59, 1_000) % Duration::seconds(1),
+            Duration::zero()
+        );
+        assert_eq!(
+            hmsm(23, 59, 59, 1_999) % Duration::minutes(1),
+            Duration::milliseconds(59_999)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_time_rem_zero() {
+        let _ = NaiveTime::from_hms(3, 5, 7) % Duration::zero();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_time_rem_negative() {
+        let _ = NaiveTime::from_hms(3, 5, 7) % Duration::seconds(-1);
+    }
+
+    #[test]
+    fn test_time_floor_ceil_to() {
+        let hmsm = |h, m, s, mi| NaiveTime::from_hms_milli(h, m, s, mi);
+
+        let check = |t: NaiveTime, period: Duration, floor: NaiveTime, ceil: NaiveTime| {
+            assert_eq!(t.floor_to(period), floor);
+            assert_eq!(t.ceil_to(period), ceil);
+        };
+
+        let fifteen = Duration::minutes(15);
+        check(
+            hmsm(0, 0, 0, 0),
+            fifteen,
+            hmsm(0, 0, 0, 0),
+            hmsm(0, 0, 0, 0),
+        );
+        check(
+            hmsm(0, 0, 0, 1),
+            fifteen,
+            hmsm(0, 0, 0, 0),
+            hmsm(0, 15, 0, 0),
+        );
+        check(
+            hmsm(12, 14, 59, 999),
+            fifteen,
+            hmsm(12, 0, 0, 0),
+            hmsm(12, 15, 0, 0),
+        );
+        check(
+            hmsm(12, 15, 0, 0),
+            fifteen,
+            hmsm(12, 15, 0, 0),
+            hmsm(12, 15, 0, 0),
+        );
+        check(
+            hmsm(23, 45, 0, 1),
+            fifteen,
+            hmsm(23, 45, 0, 0),
+            hmsm(0, 0, 0, 0),
+        );
+
+        // periods not dividing a day are counted from midnight and wrap around
+        let seven = Duration::hours(7);
+        check(hmsm(6, 0, 0, 0), seven, hmsm(0, 0, 0, 0), hmsm(7, 0, 0, 0));
+        check(
+            hmsm(22, 0, 0, 0),
+            seven,
+            hmsm(21, 0, 0, 0),
+            hmsm(0, 0, 0, 0),
+        );
+        check(
+            hmsm(22, 0, 0, 0),
+            Duration::days(3),
+            hmsm(0, 0, 0, 0),
+            hmsm(0, 0, 0, 0),
+        );
+
+        // leap seconds
+        check(
+            hmsm(23, 59, 59, 1_500),
+            Duration::seconds(1),
+            hmsm(23, 59, 59, 0),
+            hmsm(0, 0, 0, 0),
+        );
+        check(
+            hmsm(3, 5, 59, 1_200),
+            Duration::milliseconds(500),
+            hmsm(3, 5, 59, 0),
+            hmsm(3, 5, 59, 500),
+        );
+    }
+
     #[test]
     fn test_time_sub() {
         macro_rules! check {
@@ -1868,6 +2256,69 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_time_sub_within_a_day() {
+        let hmsn = |h, m, s, n| NaiveTime::from_hms_nano(h, m, s, n);
+        let day = Duration::days(1);
+
+        let latest = hmsn(23, 59, 59, 999_999_999);
+        assert_eq!(
+            latest.signed_duration_since(hmsn(0, 0, 0, 0)),
+            day - Duration::nanoseconds(1)
+        );
+        assert_eq!(
+            hmsn(0, 0, 0, 0).signed_duration_since(latest),
+            Duration::nanoseconds(1) - day
+        );
+        assert!(latest.signed_duration_since(hmsn(0, 0, 0, 0)) < day);
+
+        let latest_leap = hmsn(23, 59, 59, 1_999_999_999);
+        assert_eq!(
+            latest_leap.signed_duration_since(hmsn(0, 0, 0, 0)),
+            day + Duration::seconds(1) - Duration::nanoseconds(1)
+        );
+        assert_eq!(
+            hmsn(0, 0, 0, 0).signed_duration_since(latest_leap),
+            Duration::nanoseconds(1) - day - Duration::seconds(1)
+        );
+
+        // clock times only, e.g. from the end of a night shift to the start of a day shift
+        assert_eq!(
+            hmsn(6, 0, 0, 0).signed_duration_since(hmsn(22, 0, 0, 0)),
+            Duration::hours(-16)
+        );
+    }
+
+    #[test]
+    fn test_time_sub_leap_second_triple() {
+        let hmsm = |h, m, s, mi| NaiveTime::from_hms_milli(h, m, s, mi);
+        let (t59, t60, t00) = (
+            hmsm(23, 59, 59, 0),
+            hmsm(23, 59, 59, 1_000),
+            hmsm(0, 0, 0, 0),
+        );
+
+        // the stored leap second occupies its own real second
+        assert_eq!(t60.signed_duration_since(t59), Duration::seconds(1));
+        assert_eq!(t59.signed_duration_since(t60), Duration::seconds(-1));
+        assert_eq!(t60 - t59, Duration::seconds(1));
+        assert_eq!(t60.signed_duration_since(t00), Duration::seconds(86_400));
+        assert_eq!(t00.signed_duration_since(t60), Duration::seconds(-86_400));
+        assert_eq!(t00 - t60, Duration::seconds(-86_400));
+        // ...but no leap second is assumed between non-leap operands
+        assert_eq!(t59.signed_duration_since(t00), Duration::seconds(86_399));
+        assert_eq!(t59 - t00, Duration::seconds(86_399));
+
+        assert_eq!(
+            hmsm(23, 59, 59, 1_500).signed_duration_since(hmsm(23, 59, 59, 500)),
+            Duration::seconds(1)
+        );
+        assert_eq!(
+            hmsm(23, 59, 59, 1_999).signed_duration_since(t59),
+            Duration::milliseconds(1_999)
+        );
+    }
+
     #[test]
     fn test_time_fmt() {
         assert_eq!(
@@ -1898,6 +2349,76 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_time_fmt_fraction_snapshot() {
+        use naive::NaiveDate;
+        use offset::{FixedOffset, TimeZone, Utc};
+
+        let snapshots = [
+            (0, "23:59:59"),
+            (1, "23:59:59.000000001"),
+            (10, "23:59:59.000000010"),
+            (100, "23:59:59.000000100"),
+            (1_000, "23:59:59.000001"),
+            (10_000, "23:59:59.000010"),
+            (100_000, "23:59:59.000100"),
+            (1_000_000, "23:59:59.001"),
+            (10_000_000, "23:59:59.010"),
+            (100_000_000, "23:59:59.100"),
+            (500_000_000, "23:59:59.500"),
+            (120_000_000, "23:59:59.120"),
+            (123_000_000, "23:59:59.123"),
+            (123_400_000, "23:59:59.123400"),
+            (123_450_000, "23:59:59.123450"),
+            (123_456_000, "23:59:59.123456"),
+            (123_456_700, "23:59:59.123456700"),
+            (123_456_780, "23:59:59.123456780"),
+            (123_456_789, "23:59:59.123456789"),
+            (999_999_999, "23:59:59.999999999"),
+            (1_000_000_000, "23:59:60"),
+            (1_500_000_000, "23:59:60.500"),
+            (1_999_999_999, "23:59:60.999999999"),
+        ];
+        let date = NaiveDate::from_ymd(2019, 12, 25);
+        let kst = FixedOffset::east(9 * 3600);
+        for &(nano, expected) in &snapshots {
+            let t = NaiveTime::from_hms_nano(23, 59, 59, nano);
+            assert_eq!(t.to_string(), expected);
+            assert_eq!(format!("{:?}", t), expected);
+            assert_eq!(expected.parse::<NaiveTime>(), Ok(t));
+
+            let dt = date.and_time(t);
+            assert_eq!(dt.to_string(), format!("2019-12-25 {}", expected));
+            assert_eq!(
+                Utc.from_utc_datetime(&dt).to_string(),
+                format!("2019-12-25 {} UTC", expected)
+            );
+            assert_eq!(
+                kst.from_local_datetime(&dt).unwrap().to_string(),
+                format!("2019-12-25 {} +09:00", expected)
+            );
+        }
+
+        // the same value prints the same however it was made
+        let t = NaiveTime::from_hms_nano(12, 34, 56, 500_000_000);
+        assert_eq!(NaiveTime::from_hms_milli(12, 34, 56, 500), t);
+        assert_eq!(NaiveTime::from_hms_micro(12, 34, 56, 500_000), t);
+        assert_eq!(
+            NaiveTime::from_hms(12, 34, 56) + Duration::milliseconds(500),
+            t
+        );
+        assert_eq!("12:34:56.5".parse::<NaiveTime>(), Ok(t));
+        assert_eq!("12:34:56.5000000000".parse::<NaiveTime>(), Ok(t));
+        assert_eq!(
+            "12:34:56.5".parse::<NaiveTime>().unwrap().to_string(),
+            "12:34:56.500"
+        );
+        assert_eq!(
+            "12:34:56.1234567".parse::<NaiveTime>().unwrap().to_string(),
+            "12:34:56.123456700"
+        );
+    }
+
     #[test]
     fn test_date_from_str() {
         // valid cases
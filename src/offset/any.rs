@@ -0,0 +1,251 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! The time zone chosen at runtime among the time zones provided by Chrono.
+
+use std::fmt;
+
+use super::fixed::FixedOffset;
+#[cfg(feature = "clock")]
+use super::local::Local;
+use super::utc::Utc;
+use super::{LocalResult, Offset, TimeZone};
+use naive::{NaiveDate, NaiveDateTime};
+
+/// The time zone chosen at runtime: UTC, a fixed offset or the local time zone.
+///
+/// This allows a single `DateTime<AnyTimeZone>` type to be used
+/// when the time zone is only known at runtime (e.g. from a configuration),
+/// instead of making every function generic over the time zone.
+/// The `Local` variant is only available with the `clock` feature.
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::{DateTime, FixedOffset, TimeZone, Utc};
+/// use chrono::offset::AnyTimeZone;
+///
+/// fn zone_from_config(name: &str) -> AnyTimeZone {
+///     match name {
+///         "utc" => AnyTimeZone::Utc,
+///         _ => AnyTimeZone::Fixed(FixedOffset::east(9 * 3600)),
+///     }
+/// }
+///
+/// let dt = zone_from_config("utc").ymd(2014, 11, 28).and_hms(12, 0, 9);
+/// assert_eq!(dt.to_string(), "2014-11-28 12:00:09 UTC");
+///
+/// let dt = zone_from_config("kst").ymd(2014, 11, 28).and_hms(21, 0, 9);
+/// assert_eq!(dt.to_string(), "2014-11-28 21:00:09 +09:00");
+/// assert_eq!(dt, Utc.ymd(2014, 11, 28).and_hms(12, 0, 9));
+///
+/// let fixed: DateTime<FixedOffset> = dt.into();
+/// assert_eq!(fixed.to_rfc3339(), "2014-11-28T21:00:09+09:00");
+/// ~~~~
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum AnyTimeZone {
+    /// The UTC time zone.
+    Utc,
+    /// A time zone with the given fixed offset.
+    Fixed(FixedOffset),
+    /// The local time zone of the system.
+    #[cfg(feature = "clock")]
+    Local,
+}
+
+/// The offset of [`AnyTimeZone`](./enum.AnyTimeZone.html),
+/// which also remembers the kind of the time zone it came from.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub enum AnyOffset {
+    /// The offset of the UTC time zone.
+    Utc,
+    /// The offset of a time zone with a fixed offset.
+    Fixed(FixedOffset),
+    /// An offset of the local time zone, which can change over time.
+    #[cfg(feature = "clock")]
+    Local(FixedOffset),
+}
+
+impl From<Utc> for AnyTimeZone {
+    fn from(_: Utc) -> AnyTimeZone {
+        AnyTimeZone::Utc
+    }
+}
+
+impl From<FixedOffset> for AnyTimeZone {
+    fn from(offset: FixedOffset) -> AnyTimeZone {
+        AnyTimeZone::Fixed(offset)
+    }
+}
+
+#[cfg(feature = "clock")]
+impl From<Local> for AnyTimeZone {
+    fn from(_: Local) -> AnyTimeZone {
+        AnyTimeZone::Local
+    }
+}
+
+// the local date and time are only needed for the `Local` variant
+#[cfg_attr(not(feature = "clock"), allow(unused_variables))]
+impl TimeZone for AnyTimeZone {
+    type Offset = AnyOffset;
+
+    fn from_offset(offset: &AnyOffset) -> AnyTimeZone {
+        match *offset {
+            AnyOffset::Utc => AnyTimeZone::Utc,
+            AnyOffset::Fixed(offset) => AnyTimeZone::Fixed(offset),
+            #[cfg(feature = "clock")]
+            AnyOffset::Local(_) => AnyTimeZone::Local,
+        }
+    }
+
+    fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<AnyOffset> {
+        match *self {
+            AnyTimeZone::Utc => LocalResult::Single(AnyOffset::Utc),
+            AnyTimeZone::Fixed(offset) => LocalResult::Single(AnyOffset::Fixed(offset)),
+            #[cfg(feature = "clock")]
+            AnyTimeZone::Local => Local.offset_from_local_date(local).map(AnyOffset::Local),
+        }
+    }
+
+    fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<AnyOffset> {
+        match *self {
+            AnyTimeZone::Utc => LocalResult::Single(AnyOffset::Utc),
+            AnyTimeZone::Fixed(offset) => LocalResult::Single(AnyOffset::Fixed(offset)),
+            #[cfg(feature = "clock")]
+            AnyTimeZone::Local => Local
+                .offset_from_local_datetime(local)
+                .map(AnyOffset::Local),
+        }
+    }
+
+    fn offset_from_utc_date(&self, utc: &NaiveDate) -> AnyOffset {
+        match *self {
+            AnyTimeZone::Utc => AnyOffset::Utc,
+            AnyTimeZone::Fixed(offset) => AnyOffset::Fixed(offset),
+            #[cfg(feature = "clock")]
+            AnyTimeZone::Local => AnyOffset::Local(Local.offset_from_utc_date(utc)),
+        }
+    }
+
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> AnyOffset {
+        match *self {
+            AnyTimeZone::Utc => AnyOffset::Utc,
+            AnyTimeZone::Fixed(offset) => AnyOffset::Fixed(offset),
+            #[cfg(feature = "clock")]
+            AnyTimeZone::Local => AnyOffset::Local(Local.offset_from_utc_datetime(utc)),
+        }
+    }
+}
+
+impl Offset for AnyOffset {
+    fn fix(&self) -> FixedOffset {
+        match *self {
+            AnyOffset::Utc => FixedOffset::east(0),
+            AnyOffset::Fixed(offset) => offset,
+            #[cfg(feature = "clock")]
+            AnyOffset::Local(offset) => offset,
+        }
+    }
+
+    fn abbreviation(&self) -> Option<&str> {
+        match *self {
+            AnyOffset::Utc => Utc.abbreviation(),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Debug for AnyOffset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            AnyOffset::Utc => fmt::Debug::fmt(&Utc, f),
+            _ => fmt::Debug::fmt(&self.fix(), f),
+        }
+    }
+}
+
+impl fmt::Display for AnyOffset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            AnyOffset::Utc => fmt::Display::fmt(&Utc, f),
+            _ => fmt::Display::fmt(&self.fix(), f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AnyOffset, AnyTimeZone};
+    use naive::NaiveDate;
+    use offset::{FixedOffset, TimeZone, Utc};
+    use DateTime;
+
+    #[test]
+    fn test_any_time_zone_utc_and_fixed() {
+        let local = NaiveDate::from_ymd(2019, 3, 31).and_hms(2, 30, 0);
+
+        let dt = AnyTimeZone::Utc.from_local_datetime(&local).unwrap();
+        assert_eq!(*dt.offset(), AnyOffset::Utc);
+        assert_eq!(dt.timezone(), AnyTimeZone::Utc);
+        assert_eq!(dt.naive_utc(), local);
+        assert_eq!(format!("{:?}", dt), "2019-03-31T02:30:00Z");
+
+        let kst = FixedOffset::east(9 * 3600);
+        let dt = AnyTimeZone::from(kst).from_local_datetime(&local).unwrap();
+        assert_eq!(*dt.offset(), AnyOffset::Fixed(kst));
+        assert_eq!(dt.timezone(), AnyTimeZone::Fixed(kst));
+        assert_eq!(dt.naive_local(), local);
+        assert_eq!(dt, kst.from_local_datetime(&local).unwrap());
+        assert_eq!(format!("{:?}", dt), "2019-03-31T02:30:00+09:00");
+
+        let utc = dt.with_timezone(&AnyTimeZone::from(Utc));
+        assert_eq!(utc.to_string(), "2019-03-30 17:30:00 UTC");
+        let fixed: DateTime<FixedOffset> = utc.into();
+        assert_eq!(fixed, dt);
+        assert_eq!(fixed.offset(), &FixedOffset::east(0));
+
+        let dt: DateTime<AnyTimeZone> = Utc.ymd(2019, 3, 31).and_hms(0, 0, 0).into();
+        assert_eq!(*dt.offset(), AnyOffset::Utc);
+        let dt: DateTime<AnyTimeZone> = kst.ymd(2019, 3, 31).and_hms(0, 0, 0).into();
+        assert_eq!(*dt.offset(), AnyOffset::Fixed(kst));
+        assert_eq!(
+            dt.with_timezone(&Utc),
+            Utc.ymd(2019, 3, 30).and_hms(15, 0, 0)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "clock")]
+    fn test_any_time_zone_local() {
+        use offset::{Local, LocalResult};
+
+        for &(h, m) in &[(0, 0), (1, 30), (2, 30), (12, 0), (23, 59)] {
+            for &(month, day) in &[(1, 1), (3, 10), (3, 31), (10, 27), (11, 3)] {
+                let local = NaiveDate::from_ymd(2019, month, day).and_hms(h, m, 0);
+                let expected = Local.from_local_datetime(&local);
+                let actual = AnyTimeZone::Local.from_local_datetime(&local);
+                match (expected, actual) {
+                    (LocalResult::None, LocalResult::None) => {}
+                    (LocalResult::Single(e), LocalResult::Single(a)) => {
+                        assert_eq!(*a.offset(), AnyOffset::Local(*e.offset()));
+                        assert_eq!(a.timezone(), AnyTimeZone::Local);
+                        assert_eq!(a, e);
+                    }
+                    (LocalResult::Ambiguous(e1, e2), LocalResult::Ambiguous(a1, a2)) => {
+                        assert_eq!(
+                            (a1.naive_utc(), a2.naive_utc()),
+                            (e1.naive_utc(), e2.naive_utc())
+                        );
+                    }
+                    (e, a) => panic!("{:?} != {:?}", a, e),
+                }
+            }
+        }
+
+        let now = Local::now();
+        let dt: DateTime<AnyTimeZone> = now.into();
+        assert_eq!(*dt.offset(), AnyOffset::Local(*now.offset()));
+        assert_eq!(dt.naive_local(), now.naive_local());
+    }
+}
@@ -4,11 +4,14 @@
 //! The time zone which has a fixed offset from UTC.
 
 use oldtime::Duration as OldDuration;
+use std::error::Error;
 use std::fmt;
 use std::ops::{Add, Sub};
+use std::str;
 
 use super::{LocalResult, Offset, TimeZone};
 use div::div_mod_floor;
+use format::{parse, Fixed, Item, Parsed};
 use naive::{NaiveDate, NaiveDateTime, NaiveTime};
 use DateTime;
 use Timelike;
@@ -105,26 +108,32 @@ impl FixedOffset {
 impl TimeZone for FixedOffset {
     type Offset = FixedOffset;
 
+    #[inline]
     fn from_offset(offset: &FixedOffset) -> FixedOffset {
         *offset
     }
 
+    #[inline]
     fn offset_from_local_date(&self, _local: &NaiveDate) -> LocalResult<FixedOffset> {
         LocalResult::Single(*self)
     }
+    #[inline]
     fn offset_from_local_datetime(&self, _local: &NaiveDateTime) -> LocalResult<FixedOffset> {
         LocalResult::Single(*self)
     }
 
+    #[inline]
     fn offset_from_utc_date(&self, _utc: &NaiveDate) -> FixedOffset {
         *self
     }
+    #[inline]
     fn offset_from_utc_datetime(&self, _utc: &NaiveDateTime) -> FixedOffset {
         *self
     }
 }
 
 impl Offset for FixedOffset {
+    #[inline]
     fn fix(&self) -> FixedOffset {
         *self
     }
@@ -154,6 +163,59 @@ impl fmt::Display for FixedOffset {
     }
 }
 
+/// Parsing a `str` into a `FixedOffset` accepts the offsets in RFC 3339 and HTTP headers:
+/// `+hh:mm`, `-hh:mm`, `+hhmm`, `-hhmm`, and `Z` (or `z`) for UTC.
+///
+/// The offset should be less than 24 hours, and can't have seconds.
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::FixedOffset;
+///
+/// assert_eq!("+09:00".parse::<FixedOffset>(), Ok(FixedOffset::east(9 * 3600)));
+/// assert_eq!("-0430".parse::<FixedOffset>(), Ok(FixedOffset::west(4 * 3600 + 30 * 60)));
+/// assert_eq!("Z".parse::<FixedOffset>(), Ok(FixedOffset::east(0)));
+/// assert!("+24:00".parse::<FixedOffset>().is_err());
+/// assert!("09:00".parse::<FixedOffset>().is_err());
+/// ~~~~
+impl str::FromStr for FixedOffset {
+    type Err = OffsetParseError;
+
+    fn from_str(s: &str) -> Result<FixedOffset, OffsetParseError> {
+        const ITEMS: &'static [Item<'static>] = &[Item::Fixed(Fixed::TimezoneOffsetZ)];
+
+        let mut parsed = Parsed::new();
+        parse(&mut parsed, s, ITEMS.iter().cloned())
+            .and_then(|()| parsed.to_fixed_offset())
+            .map_err(|_| OffsetParseError { _dummy: () })
+    }
+}
+
+/// An error resulting from reading `FixedOffset` value with `FromStr`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct OffsetParseError {
+    _dummy: (),
+}
+
+impl fmt::Debug for OffsetParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "OffsetParseError {{ .. }}")
+    }
+}
+
+impl fmt::Display for OffsetParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.description().fmt(f)
+    }
+}
+
+impl Error for OffsetParseError {
+    fn description(&self) -> &str {
+        "invalid or out-of-range offset"
+    }
+}
+
 // addition or subtraction of FixedOffset to/from Timelike values is same to
 // adding or subtracting the offset's local_minus_utc value
 // but keep keeps the leap second information.
@@ -227,7 +289,7 @@ impl<Tz: TimeZone> Sub<FixedOffset> for DateTime<Tz> {
 
 #[cfg(test)]
 mod tests {
-    use super::FixedOffset;
+    use super::{FixedOffset, OffsetParseError};
     use offset::TimeZone;
 
     #[test]
@@ -257,4 +319,56 @@ mod tests {
             "2012-03-04T05:06:07-23:59:59".to_string()
         );
     }
+
+    #[test]
+    fn test_fixed_offset_from_str() {
+        let east = |h, m| Ok(FixedOffset::east(h * 3600 + m * 60));
+        let west = |h, m| Ok(FixedOffset::west(h * 3600 + m * 60));
+
+        assert_eq!("+09:00".parse(), east(9, 0));
+        assert_eq!("+0900".parse(), east(9, 0));
+        assert_eq!("-04:30".parse(), west(4, 30));
+        assert_eq!("-0430".parse(), west(4, 30));
+        assert_eq!("+00:00".parse(), east(0, 0));
+        assert_eq!("-00:00".parse(), east(0, 0));
+        assert_eq!("Z".parse(), east(0, 0));
+        assert_eq!("z".parse(), east(0, 0));
+        assert_eq!("+23:59".parse(), east(23, 59));
+        assert_eq!("-23:59".parse(), west(23, 59));
+
+        let err = Err::<FixedOffset, _>(OffsetParseError { _dummy: () });
+        for &s in &[
+            "",
+            "+",
+            "+09",
+            "+9:00",
+            "+09:0",
+            "09:00",
+            "0900",
+            "+09:00Z",
+            "+09:00:00",
+            "+09:60",
+            "+24:00",
+            "-24:00",
+            "UTC",
+            "ZZ",
+            "+09:00 ",
+        ] {
+            assert_eq!(s.parse(), err, "{:?} should be rejected", s);
+        }
+
+        // round trips the `Display` output without seconds
+        for &secs in &[0, 3600, -3600, 9 * 3600 + 30 * 60, -(23 * 3600 + 59 * 60)] {
+            let offset = FixedOffset::east(secs);
+            assert_eq!(offset.to_string().parse(), Ok(offset));
+        }
+        assert_eq!(
+            "+09:00".parse::<FixedOffset>().map(|off| off.to_string()),
+            Ok("+09:00".to_owned())
+        );
+        assert_eq!(
+            OffsetParseError { _dummy: () }.to_string(),
+            "invalid or out-of-range offset"
+        );
+    }
 }
@@ -22,8 +22,10 @@ use std::fmt;
 
 use format::{parse, ParseResult, Parsed, StrftimeItems};
 use naive::{NaiveDate, NaiveDateTime, NaiveTime};
+#[cfg(feature = "clock")]
+use SystemClock;
 use Weekday;
-use {Date, DateTime};
+use {Clock, Date, DateTime};
 
 /// The conversion result from the local time to the timezone-aware datetime types.
 #[derive(Clone, PartialEq, Debug, Copy, Eq, Hash)]
@@ -63,6 +65,44 @@ impl<T> LocalResult<T> {
         }
     }
 
+    /// Returns `Some` only when the conversion result is unique, or `None` otherwise.
+    ///
+    /// This is the same as [`single`](#method.single):
+    /// an `Ambiguous` result is *not* a success, because picking either value silently
+    /// would hide the ambiguity. Use [`earliest`](#method.earliest) or
+    /// [`latest`](#method.latest) to resolve it explicitly.
+    #[inline]
+    pub fn ok(self) -> Option<T> {
+        self.single()
+    }
+
+    /// Returns `Ok` only when the conversion result is unique, or `Err(err)` otherwise.
+    ///
+    /// Like [`ok`](#method.ok), both `None` and `Ambiguous` results are errors.
+    /// This makes it easy to propagate a failed conversion with `try!`.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{DateTime, FixedOffset, NaiveDate, TimeZone};
+    ///
+    /// fn localize(tz: FixedOffset, y: i32, m: u32, d: u32) -> Result<DateTime<FixedOffset>, String> {
+    ///     let date = try!(NaiveDate::from_ymd_opt(y, m, d).ok_or("invalid date".to_owned()));
+    ///     tz.from_local_datetime(&date.and_hms(0, 0, 0)).ok_or("invalid local time".to_owned())
+    /// }
+    ///
+    /// let tz = FixedOffset::east(9 * 3600);
+    /// assert_eq!(localize(tz, 2019, 1, 1), Ok(tz.ymd(2019, 1, 1).and_hms(0, 0, 0)));
+    /// assert_eq!(localize(tz, 2019, 2, 29), Err("invalid date".to_owned()));
+    /// ~~~~
+    #[inline]
+    pub fn ok_or<E>(self, err: E) -> Result<T, E> {
+        match self {
+            LocalResult::Single(t) => Ok(t),
+            _ => Err(err),
+        }
+    }
+
     /// Maps a `LocalResult<T>` into `LocalResult<U>` with given function.
     pub fn map<U, F: FnMut(T) -> U>(self, mut f: F) -> LocalResult<U> {
         match self {
@@ -179,10 +219,52 @@ impl<T: fmt::Debug> LocalResult<T> {
     }
 }
 
+/// Returns the single local date from `TimeZone::from_local_date`,
+/// or panics with the requested `local` date and the offsets in question.
+fn expect_local_date<Tz: TimeZone>(
+    result: LocalResult<Date<Tz>>,
+    method: &str,
+    local: &NaiveDate,
+) -> Date<Tz> {
+    match result {
+        LocalResult::Single(d) => d,
+        LocalResult::None => panic!(
+            "TimeZone::{}: local date {} does not exist in the time zone",
+            method, local
+        ),
+        LocalResult::Ambiguous(d1, d2) => panic!(
+            "TimeZone::{}: local date {} is ambiguous in the time zone, with offsets {:?} and {:?}",
+            method,
+            local,
+            d1.offset(),
+            d2.offset()
+        ),
+    }
+}
+
 /// The offset from the local time to UTC.
 pub trait Offset: Sized + Clone + fmt::Debug {
     /// Returns the fixed offset from UTC to the local time stored.
     fn fix(&self) -> FixedOffset;
+
+    /// Returns the abbreviated name of the time zone for this offset, like `UTC` or `CEST`,
+    /// if known.
+    ///
+    /// The default implementation returns `None`, which is also the case for
+    /// [`FixedOffset`](./struct.FixedOffset.html) as a bare offset has no name.
+    /// Time zone implementations knowing their names should override this.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{FixedOffset, Offset, Utc};
+    ///
+    /// assert_eq!(Utc.abbreviation(), Some("UTC"));
+    /// assert_eq!(FixedOffset::east(3600).abbreviation(), None);
+    /// ~~~~
+    fn abbreviation(&self) -> Option<&str> {
+        None
+    }
 }
 
 /// The time zone.
@@ -201,7 +283,9 @@ pub trait TimeZone: Sized + Clone {
     /// The time zone normally does not affect the date (unless it is between UTC-24 and UTC+24),
     /// but it will propagate to the `DateTime` values constructed via this date.
     ///
-    /// Panics on the out-of-range date, invalid month and/or day.
+    /// Panics on the out-of-range date, invalid month and/or day,
+    /// or when the local date doesn't exist or is ambiguous in the time zone;
+    /// for a non-panicking version see [`ymd_opt`](#method.ymd_opt).
     ///
     /// # Example
     ///
@@ -211,7 +295,18 @@ pub trait TimeZone: Sized + Clone {
     /// assert_eq!(Utc.ymd(2015, 5, 15).to_string(), "2015-05-15UTC");
     /// ~~~~
     fn ymd(&self, year: i32, month: u32, day: u32) -> Date<Self> {
-        self.ymd_opt(year, month, day).unwrap()
+        let result = self.ymd_opt(year, month, day);
+        if let LocalResult::Single(d) = result {
+            return d;
+        }
+        // only look at the naive date to tell why there is no single result
+        match NaiveDate::from_ymd_opt(year, month, day) {
+            Some(d) => expect_local_date(result, "ymd", &d),
+            None => panic!(
+                "TimeZone::ymd: invalid or out-of-range date {}-{:02}-{:02}",
+                year, month, day
+            ),
+        }
     }
 
     /// Makes a new `Date` from year, month, day and the current time zone.
@@ -243,7 +338,9 @@ pub trait TimeZone: Sized + Clone {
     /// The time zone normally does not affect the date (unless it is between UTC-24 and UTC+24),
     /// but it will propagate to the `DateTime` values constructed via this date.
     ///
-    /// Panics on the out-of-range date and/or invalid DOY.
+    /// Panics on the out-of-range date and/or invalid DOY,
+    /// or when the local date doesn't exist or is ambiguous in the time zone;
+    /// for a non-panicking version see [`yo_opt`](#method.yo_opt).
     ///
     /// # Example
     ///
@@ -253,7 +350,17 @@ pub trait TimeZone: Sized + Clone {
     /// assert_eq!(Utc.yo(2015, 135).to_string(), "2015-05-15UTC");
     /// ~~~~
     fn yo(&self, year: i32, ordinal: u32) -> Date<Self> {
-        self.yo_opt(year, ordinal).unwrap()
+        let result = self.yo_opt(year, ordinal);
+        if let LocalResult::Single(d) = result {
+            return d;
+        }
+        match NaiveDate::from_yo_opt(year, ordinal) {
+            Some(d) => expect_local_date(result, "yo", &d),
+            None => panic!(
+                "TimeZone::yo: invalid or out-of-range date {}-{:03}",
+                year, ordinal
+            ),
+        }
     }
 
     /// Makes a new `Date` from year, day of year (DOY or "ordinal") and the current time zone.
@@ -278,7 +385,9 @@ pub trait TimeZone: Sized + Clone {
     /// The time zone normally does not affect the date (unless it is between UTC-24 and UTC+24),
     /// but it will propagate to the `DateTime` values constructed via this date.
     ///
-    /// Panics on the out-of-range date and/or invalid week number.
+    /// Panics on the out-of-range date and/or invalid week number,
+    /// or when the local date doesn't exist or is ambiguous in the time zone;
+    /// for a non-panicking version see [`isoywd_opt`](#method.isoywd_opt).
     ///
     /// # Example
     ///
@@ -288,7 +397,17 @@ pub trait TimeZone: Sized + Clone {
     /// assert_eq!(Utc.isoywd(2015, 20, Weekday::Fri).to_string(), "2015-05-15UTC");
     /// ~~~~
     fn isoywd(&self, year: i32, week: u32, weekday: Weekday) -> Date<Self> {
-        self.isoywd_opt(year, week, weekday).unwrap()
+        let result = self.isoywd_opt(year, week, weekday);
+        if let LocalResult::Single(d) = result {
+            return d;
+        }
+        match NaiveDate::from_isoywd_opt(year, week, weekday) {
+            Some(d) => expect_local_date(result, "isoywd", &d),
+            None => panic!(
+                "TimeZone::isoywd: invalid or out-of-range date {}-W{:02}-{:?}",
+                year, week, weekday
+            ),
+        }
     }
 
     /// Makes a new `Date` from ISO week date (year and week number), day of the week (DOW) and
@@ -307,6 +426,33 @@ pub trait TimeZone: Sized + Clone {
         }
     }
 
+    /// Makes a new `Date` from the number of days since January 1, 1 (Day 1)
+    /// and the current time zone.
+    /// This is the inverse of [`Datelike::num_days_from_ce`](../trait.Datelike.html#method.num_days_from_ce)
+    /// as in [`NaiveDate::from_num_days_from_ce_opt`](../naive/struct.NaiveDate.html#method.from_num_days_from_ce_opt).
+    ///
+    /// The time zone normally does not affect the date (unless it is between UTC-24 and UTC+24),
+    /// but it will propagate to the `DateTime` values constructed via this date.
+    ///
+    /// Returns `None` on the out-of-range number of days.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{Datelike, LocalResult, Utc, TimeZone};
+    ///
+    /// let d = Utc.num_days_from_ce_opt(735_671).unwrap();
+    /// assert_eq!(d, Utc.ymd(2015, 3, 14));
+    /// assert_eq!(d.num_days_from_ce(), 735_671);
+    /// assert_eq!(Utc.num_days_from_ce_opt(100_000_000), LocalResult::None);
+    /// ~~~~
+    fn num_days_from_ce_opt(&self, days: i32) -> LocalResult<Date<Self>> {
+        match NaiveDate::from_num_days_from_ce_opt(days) {
+            Some(d) => self.from_local_date(&d),
+            None => LocalResult::None,
+        }
+    }
+
     /// Makes a new `DateTime` from the number of non-leap seconds
     /// since January 1, 1970 0:00:00 UTC (aka "UNIX timestamp")
     /// and the number of nanoseconds since the last whole non-leap second.
@@ -459,14 +605,54 @@ pub trait TimeZone: Sized + Clone {
     fn from_utc_datetime(&self, utc: &NaiveDateTime) -> DateTime<Self> {
         DateTime::from_utc(*utc, self.offset_from_utc_datetime(utc))
     }
+
+    /// Returns the current date and time in this time zone.
+    ///
+    /// This is the current instant from [`Utc::now`](./struct.Utc.html#method.now)
+    /// converted to the time zone, so generic code can get the current time in any time zone.
+    /// Use [`now_with_clock`](#method.now_with_clock) to make it testable.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{DateTime, FixedOffset, TimeZone};
+    ///
+    /// fn now_in<Tz: TimeZone>(tz: &Tz) -> DateTime<Tz> {
+    ///     tz.now()
+    /// }
+    ///
+    /// let tz = FixedOffset::east(9 * 3600);
+    /// assert_eq!(now_in(&tz).offset(), &tz);
+    /// ~~~~
+    #[cfg(feature = "clock")]
+    fn now(&self) -> DateTime<Self> {
+        self.now_with_clock(&SystemClock)
+    }
+
+    /// Returns the current date and time in this time zone, as given by `clock`.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{FixedClock, FixedOffset, TimeZone, Utc};
+    ///
+    /// let clock = FixedClock(Utc.ymd(2019, 12, 25).and_hms(9, 0, 0));
+    /// let tz = FixedOffset::east(9 * 3600);
+    /// assert_eq!(tz.now_with_clock(&clock), tz.ymd(2019, 12, 25).and_hms(18, 0, 0));
+    /// ~~~~
+    fn now_with_clock<C: Clock>(&self, clock: &C) -> DateTime<Self> {
+        self.from_utc_datetime(&clock.now().naive_utc())
+    }
 }
 
+mod any;
 mod fixed;
 #[cfg(feature = "clock")]
 mod local;
 mod utc;
 
-pub use self::fixed::FixedOffset;
+pub use self::any::{AnyOffset, AnyTimeZone};
+pub use self::fixed::{FixedOffset, OffsetParseError};
 #[cfg(feature = "clock")]
 pub use self::local::Local;
 pub use self::utc::Utc;
@@ -474,6 +660,173 @@ pub use self::utc::Utc;
 #[cfg(test)]
 mod tests {
     use super::*;
+    use test_zones::MidnightShift;
+
+    #[test]
+    #[cfg(feature = "clock")]
+    fn test_timezone_now() {
+        let tz = FixedOffset::west(5 * 3600);
+        let before = Utc::now();
+        let now = tz.now();
+        let after = Utc::now();
+        assert_eq!(now.offset(), &tz);
+        assert!(before.naive_utc() <= now.naive_utc() && now.naive_utc() <= after.naive_utc());
+
+        let now = Utc.now();
+        assert!(after <= now && now <= Utc::now());
+    }
+
+    #[test]
+    fn test_timezone_now_with_clock() {
+        use FixedClock;
+
+        let clock = FixedClock(Utc.ymd(2019, 12, 31).and_hms_milli(23, 59, 59, 999));
+        let tz = FixedOffset::west(5 * 3600);
+        assert_eq!(
+            tz.now_with_clock(&clock),
+            tz.ymd(2019, 12, 31).and_hms_milli(18, 59, 59, 999)
+        );
+        assert_eq!(tz.now_with_clock(&clock).offset(), &tz);
+        assert_eq!(Utc.now_with_clock(&&clock), clock.0);
+        assert_eq!(clock.now(), clock.now());
+    }
+
+    #[test]
+    fn test_local_result_ok() {
+        assert_eq!(LocalResult::Single(1).ok(), Some(1));
+        assert_eq!(LocalResult::Ambiguous(1, 2).ok(), None);
+        assert_eq!(LocalResult::None::<i32>.ok(), None);
+
+        assert_eq!(LocalResult::Single(1).ok_or("error"), Ok(1));
+        assert_eq!(LocalResult::Ambiguous(1, 2).ok_or("error"), Err("error"));
+        assert_eq!(LocalResult::None::<i32>.ok_or("error"), Err("error"));
+
+        let local = NaiveDate::from_ymd(2019, 3, 31).and_hms(2, 30, 0);
+        let tz = FixedOffset::east(3600);
+        assert_eq!(
+            tz.from_local_datetime(&local).ok_or(()),
+            Ok(tz.ymd(2019, 3, 31).and_hms(2, 30, 0))
+        );
+    }
+
+    #[test]
+    fn test_timezone_ymd_opt_across_midnight_shift() {
+        let tz = MidnightShift;
+        assert_eq!(tz.ymd_opt(2019, 3, 31), LocalResult::None);
+        assert_eq!(tz.yo_opt(2019, 90), LocalResult::None);
+        assert_eq!(tz.isoywd_opt(2019, 13, Weekday::Sun), LocalResult::None);
+        assert_eq!(tz.num_days_from_ce_opt(737_149), LocalResult::None);
+        match tz.ymd_opt(2019, 10, 27) {
+            LocalResult::Ambiguous(d1, d2) => {
+                assert_eq!(d1.offset(), &FixedOffset::east(3600));
+                assert_eq!(d2.offset(), &FixedOffset::east(0));
+            }
+            r => panic!("expected an ambiguous date, got {:?}", r),
+        }
+        assert_eq!(tz.ymd(2019, 6, 1).offset(), &FixedOffset::east(3600));
+        assert_eq!(tz.yo(2019, 1), tz.ymd(2019, 1, 1));
+    }
+
+    #[test]
+    fn test_timezone_num_days_from_ce_opt() {
+        use naive::{MAX_DATE, MIN_DATE};
+        use Datelike;
+
+        let kst = FixedOffset::east(9 * 3600);
+        for &date in &[MIN_DATE, NaiveDate::from_ymd(1, 1, 1), MAX_DATE] {
+            let days = date.num_days_from_ce();
+            assert_eq!(Utc.num_days_from_ce_opt(days), Utc.from_local_date(&date));
+            let d = kst.num_days_from_ce_opt(days).unwrap();
+            assert_eq!(d.naive_local(), date);
+            assert_eq!(d.offset(), &kst);
+            assert_eq!(d.num_days_from_ce(), days);
+        }
+        let min = MIN_DATE.num_days_from_ce();
+        let max = MAX_DATE.num_days_from_ce();
+        assert_eq!(Utc.num_days_from_ce_opt(min - 1), LocalResult::None);
+        assert_eq!(Utc.num_days_from_ce_opt(max + 1), LocalResult::None);
+    }
+
+    #[test]
+    #[should_panic(expected = "TimeZone::ymd: invalid or out-of-range date 2019-02-29")]
+    fn test_timezone_ymd_invalid() {
+        Utc.ymd(2019, 2, 29);
+    }
+
+    #[test]
+    #[should_panic(expected = "TimeZone::ymd: local date 2019-03-31 does not exist")]
+    fn test_timezone_ymd_nonexistent() {
+        MidnightShift.ymd(2019, 3, 31);
+    }
+
+    #[test]
+    #[should_panic(expected = "TimeZone::yo: local date 2019-10-27 is ambiguous \
+                               in the time zone, with offsets +01:00 and +00:00")]
+    fn test_timezone_yo_ambiguous() {
+        MidnightShift.yo(2019, 300);
+    }
+
+    #[test]
+    #[should_panic(expected = "TimeZone::isoywd: invalid or out-of-range date 2019-W53-Mon")]
+    fn test_timezone_isoywd_invalid() {
+        Utc.isoywd(2019, 53, Weekday::Mon);
+    }
+
+    #[test]
+    fn test_offset_abbreviation() {
+        use format::DelayedFormat;
+
+        #[derive(Clone, Debug)]
+        struct Est;
+
+        impl Offset for Est {
+            fn fix(&self) -> FixedOffset {
+                FixedOffset::west(5 * 3600)
+            }
+
+            fn abbreviation(&self) -> Option<&str> {
+                Some("EST")
+            }
+        }
+
+        impl fmt::Display for Est {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Display::fmt(&self.fix(), f)
+            }
+        }
+
+        assert_eq!(Utc.abbreviation(), Some("UTC"));
+        assert_eq!(
+            Utc.ymd(2019, 12, 25)
+                .and_hms(0, 0, 0)
+                .offset()
+                .abbreviation(),
+            Some("UTC")
+        );
+        assert_eq!(FixedOffset::east(0).abbreviation(), None);
+        assert_eq!(FixedOffset::west(5 * 3600).abbreviation(), None);
+        assert_eq!(Est.abbreviation(), Some("EST"));
+        assert_eq!(Est.fix().abbreviation(), None);
+
+        // `%Z` prints the abbreviation if any, and the offset otherwise
+        fn format<Off: Offset + fmt::Display>(off: &Off) -> String {
+            let date = NaiveDate::from_ymd(2019, 12, 25);
+            DelayedFormat::new_with_offset(Some(date), None, off, StrftimeItems::new("%Z %:z"))
+                .to_string()
+        }
+        assert_eq!(format(&Est), "EST -05:00");
+        assert_eq!(format(&Est.fix()), "-05:00 -05:00");
+        assert_eq!(format(&Utc), "UTC +00:00");
+        assert_eq!(Utc.ymd(2019, 12, 25).format("%Z").to_string(), "UTC");
+        assert_eq!(
+            FixedOffset::east(9 * 3600)
+                .ymd(2019, 12, 25)
+                .and_hms(0, 0, 0)
+                .format("%Z")
+                .to_string(),
+            "+09:00"
+        );
+    }
 
     #[test]
     fn test_negative_millis() {
@@ -65,29 +65,40 @@ impl Utc {
 impl TimeZone for Utc {
     type Offset = Utc;
 
+    #[inline]
     fn from_offset(_state: &Utc) -> Utc {
         Utc
     }
 
+    #[inline]
     fn offset_from_local_date(&self, _local: &NaiveDate) -> LocalResult<Utc> {
         LocalResult::Single(Utc)
     }
+    #[inline]
     fn offset_from_local_datetime(&self, _local: &NaiveDateTime) -> LocalResult<Utc> {
         LocalResult::Single(Utc)
     }
 
+    #[inline]
     fn offset_from_utc_date(&self, _utc: &NaiveDate) -> Utc {
         Utc
     }
+    #[inline]
     fn offset_from_utc_datetime(&self, _utc: &NaiveDateTime) -> Utc {
         Utc
     }
 }
 
 impl Offset for Utc {
+    #[inline]
     fn fix(&self) -> FixedOffset {
         FixedOffset::east(0)
     }
+
+    #[inline]
+    fn abbreviation(&self) -> Option<&str> {
+        Some("UTC")
+    }
 }
 
 impl fmt::Debug for Utc {
@@ -14,6 +14,7 @@ use std::error::Error;
 use std::ops::{Add, Div, Mul, Neg, Sub};
 use std::time::Duration as StdDuration;
 use std::{fmt, i64};
+use DurationExt;
 
 /// The number of nanoseconds in a microsecond.
 const NANOS_PER_MICRO: i32 = 1000;
@@ -199,7 +200,10 @@ impl Duration {
         }
     }
 
-    /// Returns the total number of whole milliseconds in the duration,
+    /// Returns the total number of whole milliseconds in the duration.
+    ///
+    /// This never overflows, as [`min_value`](#method.min_value) and
+    /// [`max_value`](#method.max_value) are exactly `i64::MIN` and `i64::MAX` milliseconds.
     pub fn num_milliseconds(&self) -> i64 {
         // A proper Duration will not overflow, because MIN and MAX are defined
         // such that the range is exactly i64 milliseconds.
@@ -321,6 +325,11 @@ impl Duration {
     }
 }
 
+/// Negating [`Duration::min_value`](#method.min_value) gives a duration
+/// one millisecond longer than [`Duration::max_value`](#method.max_value),
+/// which is only good for an immediate arithmetic as in `time::Duration`;
+/// use [`DurationExt::checked_neg`](trait.DurationExt.html#tymethod.checked_neg)
+/// to detect it.
 impl Neg for Duration {
     type Output = Duration;
 
@@ -374,41 +383,27 @@ impl Sub for Duration {
     }
 }
 
+/// Panics on overflow;
+/// use [`DurationExt::checked_mul`](trait.DurationExt.html#tymethod.checked_mul)
+/// for the safe path.
 impl Mul<i32> for Duration {
     type Output = Duration;
 
     fn mul(self, rhs: i32) -> Duration {
-        // Multiply nanoseconds as i64, because it cannot overflow that way.
-        let total_nanos = self.nanos as i64 * rhs as i64;
-        let (extra_secs, nanos) = div_mod_floor_64(total_nanos, NANOS_PER_SEC as i64);
-        let secs = self.secs * rhs as i64 + extra_secs;
-        Duration {
-            secs: secs,
-            nanos: nanos as i32,
-        }
+        DurationExt::checked_mul(&self, i64::from(rhs)).expect("`Duration * i32` overflowed")
     }
 }
 
+/// Rounds toward zero to the nanosecond, discarding the remainder.
+/// Panics when dividing by zero or on overflow;
+/// use [`DurationExt::checked_div`](trait.DurationExt.html#tymethod.checked_div)
+/// for the safe path.
 impl Div<i32> for Duration {
     type Output = Duration;
 
     fn div(self, rhs: i32) -> Duration {
-        let mut secs = self.secs / rhs as i64;
-        let carry = self.secs - secs * rhs as i64;
-        let extra_nanos = carry * NANOS_PER_SEC as i64 / rhs as i64;
-        let mut nanos = self.nanos / rhs + extra_nanos as i32;
-        if nanos >= NANOS_PER_SEC {
-            nanos -= NANOS_PER_SEC;
-            secs += 1;
-        }
-        if nanos < 0 {
-            nanos += NANOS_PER_SEC;
-            secs -= 1;
-        }
-        Duration {
-            secs: secs,
-            nanos: nanos,
-        }
+        DurationExt::checked_div(&self, i64::from(rhs))
+            .expect("`Duration / i32` overflowed or divided by zero")
     }
 }
 
@@ -739,6 +734,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_duration_ordering() {
+        let mut durations = vec![
+            Duration::seconds(1),
+            MAX,
+            -Duration::nanoseconds(1),
+            Duration::zero(),
+            MIN,
+            Duration::milliseconds(-1500),
+            Duration::nanoseconds(1),
+        ];
+        durations.sort();
+        assert_eq!(
+            durations,
+            [
+                MIN,
+                Duration::milliseconds(-1500),
+                -Duration::nanoseconds(1),
+                Duration::zero(),
+                Duration::nanoseconds(1),
+                Duration::seconds(1),
+                MAX,
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_duration_mul_overflow() {
+        let _ = MAX * 2;
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_duration_div_by_zero() {
+        let _ = Duration::seconds(1) / 0;
+    }
+
     #[test]
     fn test_duration_fmt() {
         assert_eq!(Duration::zero().to_string(), "PT0S");
@@ -0,0 +1,292 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Ranges of date and times stepped by a fixed duration.
+
+use std::i64;
+
+use offset::TimeZone;
+use oldtime::Duration as OldDuration;
+use {DateTime, DurationExt};
+
+/// A half-open range of date and times, from `start` (inclusive) until `end` (exclusive).
+///
+/// Use [`step`](#method.step) to iterate over the range at a fixed interval.
+///
+/// # Example
+///
+/// ~~~~
+/// use chrono::{DateTimeRange, Duration, TimeZone, Utc};
+///
+/// let start = Utc.ymd(2019, 12, 25).and_hms(9, 0, 0);
+/// let range = DateTimeRange::new(start, start + Duration::hours(1));
+///
+/// let slots: Vec<String> = range.step(Duration::minutes(15)).map(|dt| dt.format("%H:%M").to_string()).collect();
+/// assert_eq!(slots, ["09:00", "09:15", "09:30", "09:45"]);
+/// ~~~~
+#[derive(Clone, Debug)]
+pub struct DateTimeRange<Tz: TimeZone> {
+    start: DateTime<Tz>,
+    end: DateTime<Tz>,
+}
+
+impl<Tz: TimeZone> DateTimeRange<Tz> {
+    /// Makes a new range from `start` (inclusive) until `end` (exclusive).
+    ///
+    /// The range is empty when `end` is not later than `start`.
+    #[inline]
+    pub fn new(start: DateTime<Tz>, end: DateTime<Tz>) -> DateTimeRange<Tz> {
+        DateTimeRange {
+            start: start,
+            end: end,
+        }
+    }
+
+    /// Returns the start of the range, which is included in the range.
+    #[inline]
+    pub fn start(&self) -> &DateTime<Tz> {
+        &self.start
+    }
+
+    /// Returns the end of the range, which is excluded from the range.
+    #[inline]
+    pub fn end(&self) -> &DateTime<Tz> {
+        &self.end
+    }
+
+    /// Returns an iterator over `start`, `start + step`, `start + 2 * step` and so on,
+    /// stopping before reaching `end`.
+    ///
+    /// Each value is computed from `start` directly rather than by repeated addition,
+    /// so the values stay exact however many steps are taken.
+    /// The iterator yields only `start` when `step` is longer than the range,
+    /// and can be reversed to go from the latest value back to `start`.
+    ///
+    /// Panics when `step` is zero or negative.
+    ///
+    /// # Example
+    ///
+    /// ~~~~
+    /// use chrono::{DateTimeRange, Duration, TimeZone, Utc};
+    ///
+    /// let start = Utc.ymd(2019, 12, 25).and_hms(9, 0, 0);
+    /// let range = DateTimeRange::new(start, start + Duration::minutes(50));
+    ///
+    /// let latest_first: Vec<_> = range.clone().step(Duration::minutes(20)).rev().collect();
+    /// assert_eq!(latest_first, [start + Duration::minutes(40), start + Duration::minutes(20), start]);
+    ///
+    /// assert_eq!(range.step(Duration::days(1)).collect::<Vec<_>>(), [start]);
+    /// ~~~~
+    pub fn step(self, step: OldDuration) -> DateTimeSteps<Tz> {
+        assert!(
+            step > OldDuration::zero(),
+            "DateTimeRange::step: the step should be positive"
+        );
+        let span = self.end.clone().signed_duration_since(self.start.clone());
+        let len = if span > OldDuration::zero() {
+            // the smallest `n` such that `step * n` reaches `span`, which is the number of steps
+            // (capped at `i64::MAX`, far more than can ever be iterated)
+            let (mut lo, mut hi) = (0, i64::MAX);
+            while hi - lo > 1 {
+                let mid = lo + (hi - lo) / 2;
+                match step.checked_mul(mid) {
+                    Some(d) if d < span => lo = mid,
+                    _ => hi = mid,
+                }
+            }
+            // `span` counts a leap second in `end`, which `start + step * n` always skips,
+            // so the last step can land on or after `end`
+            let last = step
+                .checked_mul(hi - 1)
+                .and_then(|d| self.start.clone().checked_add_signed(d));
+            match last {
+                Some(ref last) if *last < self.end => hi,
+                _ => hi - 1,
+            }
+        } else {
+            0
+        };
+        DateTimeSteps {
+            start: self.start,
+            step: step,
+            front: 0,
+            back: len,
+        }
+    }
+}
+
+/// An iterator over date and times at a fixed interval,
+/// made by [`DateTimeRange::step`](./struct.DateTimeRange.html#method.step).
+#[derive(Clone, Debug)]
+pub struct DateTimeSteps<Tz: TimeZone> {
+    start: DateTime<Tz>,
+    step: OldDuration,
+    // the indices of the first and past the last values remaining
+    front: i64,
+    back: i64,
+}
+
+impl<Tz: TimeZone> DateTimeSteps<Tz> {
+    fn get(&self, index: i64) -> DateTime<Tz> {
+        // `start + step * index` is before the end of the range, so this can't overflow
+        self.start.clone() + self.step.checked_mul(index).unwrap()
+    }
+}
+
+impl<Tz: TimeZone> Iterator for DateTimeSteps<Tz> {
+    type Item = DateTime<Tz>;
+
+    fn next(&mut self) -> Option<DateTime<Tz>> {
+        if self.front < self.back {
+            let dt = self.get(self.front);
+            self.front += 1;
+            Some(dt)
+        } else {
+            None
+        }
+    }
+
+    fn nth(&mut self, n: usize) -> Option<DateTime<Tz>> {
+        let remaining = (self.back - self.front) as u64;
+        if n as u64 >= remaining {
+            self.front = self.back;
+            return None;
+        }
+        self.front += n as i64;
+        self.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.back - self.front) as u64;
+        if len <= usize::max_value() as u64 {
+            (len as usize, Some(len as usize))
+        } else {
+            (usize::max_value(), None)
+        }
+    }
+}
+
+impl<Tz: TimeZone> DoubleEndedIterator for DateTimeSteps<Tz> {
+    fn next_back(&mut self) -> Option<DateTime<Tz>> {
+        if self.front < self.back {
+            self.back -= 1;
+            Some(self.get(self.back))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DateTimeRange;
+    use naive::NaiveDate;
+    use offset::{FixedOffset, TimeZone, Utc};
+    use oldtime::Duration;
+    use DateTime;
+
+    #[test]
+    fn test_datetime_range_step() {
+        let start = Utc.ymd(2019, 12, 25).and_hms(9, 0, 0);
+        let steps = |end: DateTime<Utc>, step: Duration| {
+            DateTimeRange::new(start, end)
+                .step(step)
+                .map(|dt| dt.signed_duration_since(start))
+                .collect::<Vec<_>>()
+        };
+        let min = Duration::minutes;
+
+        assert_eq!(
+            steps(start + min(60), min(15)),
+            [min(0), min(15), min(30), min(45)]
+        );
+        assert_eq!(
+            steps(start + min(61), min(15)),
+            [min(0), min(15), min(30), min(45), min(60)]
+        );
+        assert_eq!(
+            steps(start + min(59), min(15)),
+            [min(0), min(15), min(30), min(45)]
+        );
+        assert_eq!(steps(start + min(1), min(15)), [min(0)]);
+        assert_eq!(
+            steps(start + Duration::nanoseconds(1), Duration::max_value()),
+            [min(0)]
+        );
+        assert_eq!(steps(start, min(15)), []);
+        assert_eq!(steps(start - min(15), min(15)), []);
+
+        // no error accumulates over many steps of a fractional second
+        let third = Duration::nanoseconds(333_333_333);
+        let end = start + Duration::days(1);
+        let mut iter = DateTimeRange::new(start, end).step(third);
+        assert_eq!(iter.size_hint(), (259_201, Some(259_201)));
+        assert_eq!(iter.next_back(), Some(start + third * 259_200));
+        assert_eq!(iter.nth(259_199), Some(start + third * 259_199));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    fn test_datetime_range_step_rev() {
+        let tz = FixedOffset::east(9 * 3600);
+        let start = tz.ymd(2019, 12, 31).and_hms(23, 0, 0);
+        let range = DateTimeRange::new(start, start + Duration::hours(3));
+        assert_eq!(range.start(), &start);
+        assert_eq!(range.end(), &tz.ymd(2020, 1, 1).and_hms(2, 0, 0));
+
+        let forward: Vec<_> = range.clone().step(Duration::minutes(50)).collect();
+        let mut backward: Vec<_> = range.step(Duration::minutes(50)).rev().collect();
+        backward.reverse();
+        assert_eq!(forward, backward);
+        assert_eq!(forward.len(), 4);
+        assert_eq!(forward[3], tz.ymd(2020, 1, 1).and_hms(1, 30, 0));
+        assert!(forward.iter().all(|dt| dt.offset() == &tz));
+    }
+
+    #[test]
+    fn test_datetime_range_step_whole_range() {
+        let start = DateTime::<Utc>::min_value();
+        let end = DateTime::<Utc>::max_value();
+        // `end` is in a leap second, which is not counted here
+        let days = end.date().signed_duration_since(start.date()).num_days() + 1;
+
+        let mut iter = DateTimeRange::new(start, end).step(Duration::days(1));
+        assert_eq!(iter.size_hint().0 as i64, days);
+        assert_eq!(iter.next(), Some(start));
+        let last = iter.next_back().unwrap();
+        assert_eq!(
+            last.date().naive_utc(),
+            NaiveDate::from_ymd(262_143, 12, 31)
+        );
+        assert_eq!(last.date().naive_utc(), end.date().naive_utc());
+
+        let mut iter = DateTimeRange::new(start, end).step(Duration::nanoseconds(1));
+        assert_eq!(iter.nth(999), Some(start + Duration::nanoseconds(999)));
+    }
+
+    #[test]
+    fn test_datetime_range_step_leap_second() {
+        let start = Utc.ymd(2015, 6, 30).and_hms(23, 59, 59);
+        let end = Utc.ymd(2015, 6, 30).and_hms_milli(23, 59, 59, 1_500);
+        let steps: Vec<_> = DateTimeRange::new(start, end)
+            .step(Duration::seconds(1))
+            .collect();
+        assert_eq!(steps, [start]);
+
+        let end = Utc.ymd(2015, 7, 1).and_hms(0, 0, 1);
+        let steps: Vec<_> = DateTimeRange::new(start, end)
+            .step(Duration::seconds(1))
+            .rev()
+            .collect();
+        assert_eq!(steps, [Utc.ymd(2015, 7, 1).and_hms(0, 0, 0), start]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_datetime_range_step_zero() {
+        let start = Utc.ymd(2019, 12, 25).and_hms(9, 0, 0);
+        DateTimeRange::new(start, start + Duration::hours(1)).step(Duration::zero());
+    }
+}
@@ -0,0 +1,99 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Time zones with daylight saving time transitions, shared by the tests.
+
+use naive::{NaiveDate, NaiveDateTime};
+use offset::{FixedOffset, LocalResult, TimeZone};
+
+/// A time zone at UTC+01:00 with the daylight saving time at UTC+02:00
+/// from 2019-03-31 01:00 UTC to 2019-10-27 01:00 UTC.
+///
+/// The local times from 02:00 to 03:00 on 2019-03-31 do not exist,
+/// and those from 02:00 to 03:00 on 2019-10-27 happen twice.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Dst;
+
+impl TimeZone for Dst {
+    type Offset = FixedOffset;
+
+    fn from_offset(_: &FixedOffset) -> Dst {
+        Dst
+    }
+
+    fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<FixedOffset> {
+        self.offset_from_local_datetime(&local.and_hms(12, 0, 0))
+    }
+
+    fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<FixedOffset> {
+        let summer = FixedOffset::east(2 * 3600);
+        let winter = FixedOffset::east(3600);
+        let valid = |off: FixedOffset| self.offset_from_utc_datetime(&(*local - off)) == off;
+        match (valid(summer), valid(winter)) {
+            (true, true) => LocalResult::Ambiguous(summer, winter),
+            (true, false) => LocalResult::Single(summer),
+            (false, true) => LocalResult::Single(winter),
+            (false, false) => LocalResult::None,
+        }
+    }
+
+    fn offset_from_utc_date(&self, utc: &NaiveDate) -> FixedOffset {
+        self.offset_from_utc_datetime(&utc.and_hms(0, 0, 0))
+    }
+
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> FixedOffset {
+        let start = NaiveDate::from_ymd(2019, 3, 31).and_hms(1, 0, 0);
+        let end = NaiveDate::from_ymd(2019, 10, 27).and_hms(1, 0, 0);
+        if start <= *utc && *utc < end {
+            FixedOffset::east(2 * 3600)
+        } else {
+            FixedOffset::east(3600)
+        }
+    }
+}
+
+/// A time zone which moves the clock forward by an hour at 2019-03-31 00:00
+/// and back at 2019-10-27 00:00, from UTC+00:00 to UTC+01:00 and back.
+///
+/// The local date 2019-03-31 starts an hour late and 2019-10-27 starts twice,
+/// so they do not exist and are ambiguous as a whole respectively.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct MidnightShift;
+
+impl MidnightShift {
+    fn offset(summer: bool) -> FixedOffset {
+        FixedOffset::east(if summer { 3600 } else { 0 })
+    }
+}
+
+impl TimeZone for MidnightShift {
+    type Offset = FixedOffset;
+
+    fn from_offset(_: &FixedOffset) -> MidnightShift {
+        MidnightShift
+    }
+
+    fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<FixedOffset> {
+        if *local == NaiveDate::from_ymd(2019, 3, 31) {
+            LocalResult::None
+        } else if *local == NaiveDate::from_ymd(2019, 10, 27) {
+            LocalResult::Ambiguous(MidnightShift::offset(true), MidnightShift::offset(false))
+        } else {
+            self.offset_from_local_datetime(&local.and_hms(12, 0, 0))
+        }
+    }
+
+    fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<FixedOffset> {
+        let summer = local.date() > NaiveDate::from_ymd(2019, 3, 31)
+            && local.date() < NaiveDate::from_ymd(2019, 10, 27);
+        LocalResult::Single(MidnightShift::offset(summer))
+    }
+
+    fn offset_from_utc_date(&self, utc: &NaiveDate) -> FixedOffset {
+        self.offset_from_utc_datetime(&utc.and_hms(12, 0, 0))
+    }
+
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> FixedOffset {
+        self.offset_from_local_datetime(utc).unwrap()
+    }
+}